@@ -0,0 +1,95 @@
+use std::process::ExitCode;
+
+use bevy::asset::io::file::FileAssetReader;
+
+use crate::{
+    loader::{Answers, CustomSizes, InputSource, RawInput},
+    scenes::{solver, Progress, Settings, SettingsFile, LATEST_YEAR},
+};
+
+const USAGE: &str = "Usage: aoc2024_vis --headless [--year YEAR] [--example|--custom] [DAY...]";
+
+pub fn run(args: impl Iterator<Item = String>) -> ExitCode {
+    let mut args = args.peekable();
+    let year = match args.next_if(|arg| arg == "--year") {
+        Some(_) => match args.next().and_then(|year| year.parse::<u16>().ok()) {
+            Some(year) if solver(year, 1).is_some() => year,
+            _ => {
+                eprintln!("{USAGE}");
+                eprintln!("The year must be an event with visualizations.");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => LATEST_YEAR,
+    };
+    let source = match args.next_if(|arg| arg == "--example" || arg == "--custom") {
+        Some(arg) if arg == "--example" => InputSource::Example,
+        Some(_) => InputSource::Custom,
+        None => InputSource::Real,
+    };
+
+    let days = match args
+        .map(|arg| arg.parse::<u8>().ok().filter(|day| (1..=25).contains(day)))
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(days) if days.is_empty() => (1..=25).collect(),
+        Some(days) => days,
+        None => {
+            eprintln!("{USAGE}");
+            eprintln!("Days must be between 1 and 25.");
+            return ExitCode::FAILURE;
+        }
+    };
+    let all_days = days.len() == 25;
+
+    let assets = FileAssetReader::get_base_path().join("assets");
+    // Same inputs as the app reads
+    let settings = Settings::read(&SettingsFile::default().0);
+    let inputs = settings.input_directory;
+    let sizes = source.sizes(&CustomSizes(settings.custom_sizes));
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    let answers = Answers::read(source, &inputs, year).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        exit_code = ExitCode::FAILURE;
+        Answers::default()
+    });
+
+    for day in days {
+        let path = assets.join(source.path(&inputs, year, day));
+        let Ok(data) = std::fs::read(&path) else {
+            eprintln!("Day {day}: input file not found: {}", path.display());
+            if !all_days {
+                exit_code = ExitCode::FAILURE;
+            }
+            continue;
+        };
+
+        let Some(solve) = solver(year, day) else {
+            continue;
+        };
+        let results = match solve(&RawInput(data), sizes, &Progress::default()) {
+            Ok(results) => results,
+            Err(error) => {
+                eprintln!("Day {day}: {}: {error}", path.display());
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        println!("Day {day}");
+        for (part, result) in (1..=2).zip(results) {
+            let verdict = match answers.check(day, part, &result) {
+                Some(true) => " (correct)",
+                Some(false) => {
+                    exit_code = ExitCode::FAILURE;
+                    " (incorrect)"
+                }
+                None => "",
+            };
+            println!("  Part {part}: {result}{verdict}");
+        }
+    }
+
+    exit_code
+}
@@ -1,8 +1,359 @@
-use bevy::{asset::Asset, prelude::Deref, reflect::Reflect};
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Asset, Reflect, Deref)]
+use bevy::{
+    asset::{io::file::FileAssetReader, Asset},
+    prelude::{Deref, Resource},
+    reflect::Reflect,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Asset, Clone, Reflect, Deref)]
 pub struct RawInput(pub Vec<u8>);
 
+impl RawInput {
+    /// Splits the input on `'\n'`, numbering the lines from 1.
+    pub fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.0
+            .split(|c| *c == b'\n')
+            .enumerate()
+            .map(|(i, bytes)| Line {
+                number: i + 1,
+                bytes: bytes.strip_suffix(b"\r").unwrap_or(bytes),
+            })
+    }
+
+    /// Splits the input into the rows of a rectangular grid, rejecting tiles
+    /// that do not pass `valid_tile`.
+    pub fn grid(&self, valid_tile: impl Fn(u8) -> bool) -> Result<Vec<&[u8]>, ParseError> {
+        let lines = self
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+
+        let Some(width) = lines.first().map(|line| line.len()) else {
+            return Err(ParseError::new(
+                1,
+                1,
+                "expected a grid, found an empty input",
+            ));
+        };
+
+        for line in lines.iter() {
+            if line.len() != width {
+                return Err(line.error(
+                    line.len().min(width),
+                    format!("expected {width} tiles, found {}", line.len()),
+                ));
+            }
+            if let Some(column) = line.iter().position(|tile| !valid_tile(*tile)) {
+                return Err(line.error(
+                    column,
+                    format!("unexpected tile `{}`", char::from(line[column])),
+                ));
+            }
+        }
+
+        Ok(lines.into_iter().map(|line| line.bytes).collect())
+    }
+
+    /// Decodes the whole input as UTF-8.
+    pub fn text(&self) -> Result<String, ParseError> {
+        String::from_utf8(self.0.clone()).map_err(|error| {
+            let valid = &self.0[..error.utf8_error().valid_up_to()];
+            let line_start = valid
+                .iter()
+                .rposition(|c| *c == b'\n')
+                .map_or(0, |newline| newline + 1);
+            ParseError::new(
+                valid.iter().filter(|c| **c == b'\n').count() + 1,
+                valid.len() - line_start + 1,
+                "invalid UTF-8",
+            )
+        })
+    }
+
+    /// Error for when the input ends before the parser was done with it.
+    pub fn unexpected_end(&self) -> ParseError {
+        ParseError::new(self.lines().count(), 1, "unexpected end of input")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deref)]
+pub struct Line<'a> {
+    pub number: usize,
+    #[deref]
+    pub bytes: &'a [u8],
+}
+
+impl<'a> Line<'a> {
+    /// Error at the 0-based `column` of this line.
+    pub fn error(&self, column: usize, reason: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column + 1, reason)
+    }
+
+    /// Error at the start of `field`, which must be a subslice of this line.
+    pub fn error_at(&self, field: &[u8], reason: impl Into<String>) -> ParseError {
+        self.error(self.column_of(field), reason)
+    }
+
+    /// Parses `field`, a subslice of this line, as a number.
+    pub fn number<T: FromStr>(&self, field: &[u8]) -> Result<T, ParseError> {
+        std::str::from_utf8(field.trim_ascii())
+            .ok()
+            .and_then(|field| field.parse().ok())
+            .ok_or_else(|| {
+                self.error_at(
+                    field,
+                    format!(
+                        "expected a number, found `{}`",
+                        String::from_utf8_lossy(field)
+                    ),
+                )
+            })
+    }
+
+    /// Splits `field`, a subslice of this line, on the first occurrence of `separator`.
+    pub fn split_field<'b>(
+        &self,
+        field: &'b [u8],
+        separator: &[u8],
+    ) -> Result<(&'b [u8], &'b [u8]), ParseError> {
+        field
+            .windows(separator.len())
+            .position(|window| window == separator)
+            .map(|i| (&field[..i], &field[(i + separator.len())..]))
+            .ok_or_else(|| {
+                self.error(
+                    self.column_of(field) + field.len(),
+                    format!("expected `{}`", String::from_utf8_lossy(separator)),
+                )
+            })
+    }
+
+    /// Strips `prefix` from `field`, a subslice of this line.
+    pub fn strip_prefix<'b>(&self, field: &'b [u8], prefix: &[u8]) -> Result<&'b [u8], ParseError> {
+        field.strip_prefix(prefix).ok_or_else(|| {
+            self.error_at(
+                field,
+                format!("expected `{}`", String::from_utf8_lossy(prefix)),
+            )
+        })
+    }
+
+    fn column_of(&self, field: &[u8]) -> usize {
+        let start = self.bytes.as_ptr() as usize;
+        (field.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= self.bytes.len())
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Which file a day reads its input from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource, Serialize, Deserialize)]
+pub enum InputSource {
+    #[default]
+    Real,
+    Example,
+    Custom,
+}
+
+/// Folder of the real and custom inputs, relative to the `assets` folder. The examples are
+/// part of the repository and always read from `examples`.
+#[derive(Debug, Clone, PartialEq, Eq, Resource, Serialize, Deserialize)]
+pub struct InputDirectory(pub String);
+
+impl Default for InputDirectory {
+    fn default() -> Self {
+        Self(String::from("inputs"))
+    }
+}
+
+/// Sizes of the puzzles that are given by their text rather than their input, which differ
+/// between the examples and the real inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PuzzleSizes {
+    /// Width and height of the area the robots of day 14 move in
+    pub robot_area: (i64, i64),
+    /// Width and height of the memory space of day 18
+    pub memory_space: (usize, usize),
+    /// Bytes fallen before part 1 of day 18
+    pub fallen_bytes: usize,
+    /// Picoseconds a cheat of day 20 has to save to be counted, in each part
+    pub min_cheat_saving: [usize; 2],
+}
+
+impl PuzzleSizes {
+    pub const REAL: Self = Self {
+        robot_area: (101, 103),
+        memory_space: (71, 71),
+        fallen_bytes: 1024,
+        min_cheat_saving: [100, 100],
+    };
+    pub const EXAMPLE: Self = Self {
+        robot_area: (11, 7),
+        memory_space: (7, 7),
+        fallen_bytes: 12,
+        min_cheat_saving: [20, 50],
+    };
+}
+
+impl Default for PuzzleSizes {
+    fn default() -> Self {
+        Self::REAL
+    }
+}
+
+/// Sizes of the custom inputs, those of the real inputs unless set in the settings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct CustomSizes(pub PuzzleSizes);
+
+impl InputSource {
+    pub const ALL: [Self; 3] = [Self::Real, Self::Example, Self::Custom];
+
+    /// Sizes of the puzzles whose input is read from this source.
+    pub fn sizes(self, custom: &CustomSizes) -> PuzzleSizes {
+        match self {
+            Self::Real => PuzzleSizes::REAL,
+            Self::Example => PuzzleSizes::EXAMPLE,
+            Self::Custom => custom.0,
+        }
+    }
+
+    /// Folder of the inputs of the `year` event, relative to the `assets` folder.
+    fn directory(self, inputs: &InputDirectory, year: u16) -> String {
+        let inputs = inputs.0.trim_end_matches('/');
+        match self {
+            Self::Real => format!("{inputs}/{year}"),
+            Self::Example => format!("examples/{year}"),
+            Self::Custom => format!("{inputs}/custom/{year}"),
+        }
+    }
+
+    /// Path of the input of `day` of the `year` event, relative to the `assets` folder.
+    pub fn path(self, inputs: &InputDirectory, year: u16, day: u8) -> String {
+        format!("{}/day{day}.txt", self.directory(inputs, year))
+    }
+
+    /// Path of the confirmed answers of the `year` event, relative to the `assets` folder.
+    pub fn answers_path(self, inputs: &InputDirectory, year: u16) -> String {
+        format!("{}/answers.txt", self.directory(inputs, year))
+    }
+
+    /// Whether the input of `day` of the `year` event is on disk.
+    pub fn exists(self, inputs: &InputDirectory, year: u16, day: u8) -> bool {
+        FileAssetReader::get_base_path()
+            .join("assets")
+            .join(self.path(inputs, year, day))
+            .is_file()
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Real => "Real",
+            Self::Example => "Example",
+            Self::Custom => "Custom",
+        }
+    }
+}
+
+/// Result of a part that is only solved through its visualization
+pub const NO_SOLVER: &str = "no solver, see the visualization";
+
+/// Confirmed answers of both parts of each day.
+///
+/// Each line of an answers file reads `DAY PART ANSWER`, lines starting with `#` are comments.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct Answers([[Option<String>; 2]; 25]);
+
+impl Answers {
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let mut answers = Self::default();
+
+        for line in input
+            .lines()
+            .filter(|line| !line.trim_ascii().is_empty() && !line.starts_with(b"#"))
+        {
+            let (day, rest) = line.split_field(line.trim_ascii(), b" ")?;
+            let (part, answer) = line.split_field(rest.trim_ascii_start(), b" ")?;
+
+            let day = line
+                .number::<usize>(day)
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| line.error_at(day, "expected a day between 1 and 25"))?;
+            let part = line
+                .number::<usize>(part)
+                .ok()
+                .filter(|part| (1..=2).contains(part))
+                .ok_or_else(|| line.error_at(part, "expected part 1 or 2"))?;
+            let answer = std::str::from_utf8(answer.trim_ascii())
+                .map_err(|_| line.error_at(answer, "invalid UTF-8"))?;
+
+            answers.0[day - 1][part - 1] = Some(answer.to_string());
+        }
+
+        Ok(answers)
+    }
+
+    /// Reads the answers of `source` for the `year` event, there are none when it has no
+    /// answers file.
+    pub fn read(source: InputSource, inputs: &InputDirectory, year: u16) -> Result<Self, String> {
+        let path = FileAssetReader::get_base_path()
+            .join("assets")
+            .join(source.answers_path(inputs, year));
+
+        match std::fs::read(&path) {
+            Ok(data) => {
+                Self::parse(&RawInput(data)).map_err(|error| format!("{}: {error}", path.display()))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("could not read {}: {error}", path.display())),
+        }
+    }
+
+    /// Whether `answer` is the confirmed answer of `part` (1 or 2) of `day`, `None` when it is
+    /// not known or the part has no solver.
+    pub fn check(&self, day: u8, part: usize, answer: &str) -> Option<bool> {
+        if answer == NO_SOLVER {
+            return None;
+        }
+        self.0[usize::from(day - 1)][part - 1]
+            .as_ref()
+            .map(|expected| expected == answer.trim())
+    }
+}
+
 pub struct AssetLoader;
 
 impl bevy::asset::AssetLoader for AssetLoader {
@@ -26,3 +377,67 @@ impl bevy::asset::AssetLoader for AssetLoader {
         &["txt"]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, CustomSizes, InputDirectory, InputSource, PuzzleSizes, RawInput};
+
+    #[test]
+    fn answers() {
+        let answers = Answers::parse(&RawInput(
+            b"# DAY PART ANSWER\n1 1 11\n17 2 5,7,3,0\n25 2 Merry Christmas!\r\n".to_vec(),
+        ))
+        .unwrap();
+
+        assert_eq!(answers.check(1, 1, "11"), Some(true));
+        assert_eq!(answers.check(1, 1, "12"), Some(false));
+        assert_eq!(answers.check(1, 2, "31"), None);
+        assert_eq!(answers.check(17, 2, "5,7,3,0"), Some(true));
+        assert_eq!(answers.check(25, 2, "Merry Christmas!"), Some(true));
+        assert_eq!(answers.check(2, 1, ""), None);
+
+        let error = Answers::parse(&RawInput(b"1 1 11\n26 1 3\n".to_vec())).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Answers::parse(&RawInput(b"1 3 11\n".to_vec())).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert!(Answers::parse(&RawInput(b"1 1\n".to_vec())).is_err());
+    }
+
+    #[test]
+    fn sizes() {
+        let custom = CustomSizes(PuzzleSizes {
+            robot_area: (5, 5),
+            ..PuzzleSizes::EXAMPLE
+        });
+        assert_eq!(InputSource::Real.sizes(&custom), PuzzleSizes::REAL);
+        assert_eq!(InputSource::Example.sizes(&custom), PuzzleSizes::EXAMPLE);
+        assert_eq!(InputSource::Custom.sizes(&custom), custom.0);
+    }
+
+    #[test]
+    fn paths() {
+        let inputs = InputDirectory::default();
+        assert_eq!(
+            InputSource::Real.path(&inputs, 2024, 7),
+            "inputs/2024/day7.txt"
+        );
+        assert_eq!(
+            InputSource::Example.path(&inputs, 2023, 25),
+            "examples/2023/day25.txt"
+        );
+        assert_eq!(
+            InputSource::Custom.answers_path(&inputs, 2024),
+            "inputs/custom/2024/answers.txt"
+        );
+
+        let inputs = InputDirectory(String::from("puzzles/"));
+        assert_eq!(
+            InputSource::Real.path(&inputs, 2024, 7),
+            "puzzles/2024/day7.txt"
+        );
+        assert_eq!(
+            InputSource::Example.path(&inputs, 2024, 7),
+            "examples/2024/day7.txt"
+        );
+    }
+}
@@ -1,8 +1,11 @@
+mod headless;
 mod loader;
 mod scenes;
 pub mod scroll_controls;
 mod tools;
 
+use std::process::ExitCode;
+
 #[cfg(feature = "with_inspector")]
 use bevy::remote::{http::RemoteHttpPlugin, RemotePlugin};
 use bevy::{
@@ -15,7 +18,22 @@ use bevy::{
     DefaultPlugins,
 };
 
-fn main() {
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.next_if(|arg| arg == "--headless").is_some() {
+        return headless::run(args);
+    }
+    let capture_directory = match args.next_if(|arg| arg == "--captures") {
+        Some(_) => match args.next() {
+            Some(directory) => Some(scenes::CaptureDirectory(directory.into())),
+            None => {
+                eprintln!("Usage: aoc2024_vis [--captures DIR]");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
     let mut app = App::new();
 
     app.add_plugins((
@@ -23,7 +41,7 @@ fn main() {
             .build()
             .set(WindowPlugin {
                 primary_window: Some(Window {
-                    title: "Advent of Code 2024".to_string(),
+                    title: "Advent of Code".to_string(),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -32,6 +50,10 @@ fn main() {
         scenes::Plugin,
         scroll_controls::Plugin,
     ));
+    if let Some(capture_directory) = capture_directory {
+        app.insert_resource(capture_directory);
+    }
+    app.insert_resource(scenes::SettingsFile::default());
     #[cfg(feature = "with_inspector")]
     app.add_plugins((RemotePlugin::default(), RemoteHttpPlugin::default()));
 
@@ -47,4 +69,6 @@ fn main() {
     default.render_layers = RenderLayers::from_layers(&[1]);
 
     app.run();
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,266 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use bevy::{
+    app::Update,
+    asset::{io::file::FileAssetReader, AssetEvent, AssetServer, Handle},
+    color::{palettes, Color},
+    ecs::system::SystemParam,
+    prelude::{
+        resource_changed, ChildBuild, ChildBuilder, Condition, EventReader, IntoSystemConfigs, Res,
+        ResMut, Resource, Text,
+    },
+    tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task},
+    text::{Font, TextColor, TextFont},
+    ui::{Node, UiRect, Val},
+};
+
+use crate::loader::{Answers, CustomSizes, InputDirectory, InputSource, PuzzleSizes, RawInput};
+
+use super::{compute::Progress, resources::Year, solver, states::Part};
+
+const CORRECT_COLOR: Color = Color::Srgba(palettes::tailwind::GREEN_400);
+pub const INCORRECT_COLOR: Color = Color::Srgba(palettes::tailwind::RED_400);
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<Answers>()
+            .init_resource::<Solutions>()
+            .init_resource::<SolveCache>()
+            .init_resource::<SolveTasks>();
+
+        app.add_systems(
+            Update,
+            (
+                load_answers.run_if(
+                    resource_changed::<InputSource>
+                        .or(resource_changed::<InputDirectory>)
+                        .or(resource_changed::<CustomSizes>)
+                        .or(resource_changed::<Year>),
+                ),
+                reload_inputs,
+                poll_solve_tasks.run_if(|tasks: Res<SolveTasks>| !tasks.0.is_empty()),
+            )
+                .chain(),
+        );
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Solution {
+    /// Still solving in the background
+    #[default]
+    Pending,
+    /// The day has no input for the selected [`InputSource`]
+    Missing,
+    Solved([String; 2]),
+    Failed,
+}
+
+/// Solution of each day of the selected [`Year`] for the selected [`InputSource`], with the parts that were checked
+/// against its [`Answers`], `None` until checked or when the answer is not known
+#[derive(Debug, Default, Resource)]
+pub struct Solutions {
+    pub solutions: [Solution; 25],
+    pub stars: [[Option<bool>; 2]; 25],
+}
+
+/// Results of both parts, `None` when the solver failed
+type Results = Option<[String; 2]>;
+
+/// Input file, when it was last modified and the sizes it was solved with
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SolveKey {
+    path: PathBuf,
+    modified: SystemTime,
+    sizes: PuzzleSizes,
+}
+
+/// Results of every input solved so far, so that only new or modified inputs are solved again
+#[derive(Debug, Default, Resource)]
+struct SolveCache(HashMap<SolveKey, Results>);
+
+/// Solves the input of a day in the background
+#[derive(Debug)]
+struct SolveTask {
+    day: u8,
+    key: SolveKey,
+    task: Task<Results>,
+    progress: Progress,
+}
+
+impl Drop for SolveTask {
+    fn drop(&mut self) {
+        self.progress.cancel();
+    }
+}
+
+/// Days being solved, at most one task per day
+#[derive(Debug, Default, Resource)]
+struct SolveTasks(Vec<SolveTask>);
+
+/// Fills in the [`Solutions`] of the selected [`Year`] and [`InputSource`]
+#[derive(SystemParam)]
+struct DaySolver<'w> {
+    input_source: Res<'w, InputSource>,
+    input_directory: Res<'w, InputDirectory>,
+    custom_sizes: Res<'w, CustomSizes>,
+    year: Res<'w, Year>,
+    answers: ResMut<'w, Answers>,
+    solutions: ResMut<'w, Solutions>,
+    cache: ResMut<'w, SolveCache>,
+    tasks: ResMut<'w, SolveTasks>,
+}
+
+impl DaySolver<'_> {
+    /// Path of the input of `day`, relative to the assets
+    fn input_path(&self, day: u8) -> String {
+        self.input_source
+            .path(&self.input_directory, self.year.0, day)
+    }
+
+    /// Takes the solution of `day` from the cache, or starts solving its input
+    fn solve(&mut self, day: u8) {
+        // Replacing a running task drops it, which cancels its progress to stop its loops
+        self.tasks.0.retain(|task| task.day != day);
+
+        let path = FileAssetReader::get_base_path()
+            .join("assets")
+            .join(self.input_path(day));
+        let modified = std::fs::metadata(&path).and_then(|metadata| metadata.modified());
+        let (Some(solve), Ok(modified)) = (solver(self.year.0, day), modified) else {
+            self.set(day, Solution::Missing, [None; 2]);
+            return;
+        };
+
+        let sizes = self.input_source.sizes(&self.custom_sizes);
+        let key = SolveKey {
+            path,
+            modified,
+            sizes,
+        };
+        if let Some(results) = self.cache.0.get(&key) {
+            self.set_results(day, results.clone());
+            return;
+        }
+
+        self.set(day, Solution::Pending, [None; 2]);
+        let progress = Progress::default();
+        let task_progress = progress.clone();
+        let path = key.path.clone();
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            let data = std::fs::read(path).ok()?;
+            solve(&RawInput(data), sizes, &task_progress).ok()
+        });
+        self.tasks.0.push(SolveTask {
+            day,
+            key,
+            task,
+            progress,
+        });
+    }
+
+    fn set_results(&mut self, day: u8, results: Results) {
+        // A failing solver is wrong about every part with a known answer
+        let stars = [1, 2].map(|part| {
+            let result = results
+                .as_ref()
+                .map_or("", |results| results[part - 1].as_str());
+            self.answers.check(day, part, result)
+        });
+        let solution = results.map_or(Solution::Failed, Solution::Solved);
+        self.set(day, solution, stars);
+    }
+
+    fn set(&mut self, day: u8, solution: Solution, stars: [Option<bool>; 2]) {
+        self.solutions.solutions[usize::from(day - 1)] = solution;
+        self.solutions.stars[usize::from(day - 1)] = stars;
+    }
+}
+
+fn load_answers(mut solver: DaySolver) {
+    *solver.answers = Answers::read(*solver.input_source, &solver.input_directory, solver.year.0)
+        .unwrap_or_else(|error| {
+            bevy::log::error!("{error}");
+            Answers::default()
+        });
+
+    for day in 1..=25 {
+        solver.solve(day);
+    }
+}
+
+/// Solves again the day whose input was modified, when it is one of the selected inputs
+fn reload_inputs(
+    mut events: EventReader<AssetEvent<RawInput>>,
+    asset_server: Res<AssetServer>,
+    mut solver: DaySolver,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        let Some(path) = asset_server.get_path(*id) else {
+            continue;
+        };
+        if let Some(day) = (1..=25).find(|day| path.path() == Path::new(&solver.input_path(*day))) {
+            solver.solve(day);
+        }
+    }
+}
+
+fn poll_solve_tasks(mut solver: DaySolver) {
+    let mut solved = Vec::new();
+    solver.tasks.0.retain_mut(|task| {
+        let Some(results) = block_on(future::poll_once(&mut task.task)) else {
+            return true;
+        };
+        solved.push((task.day, task.key.clone(), results));
+        false
+    });
+
+    for (day, key, results) in solved {
+        solver.cache.0.insert(key, results.clone());
+        solver.set_results(day, results);
+    }
+}
+
+/// Check or cross after the result of `part` of `day`, nothing when its answer is not known
+pub fn build_answer_badge(
+    parent: &mut ChildBuilder,
+    answers: &Answers,
+    (day, part): (u8, Part),
+    result: &str,
+    font: Handle<Font>,
+) {
+    let part = match part {
+        Part::Part1 => 1,
+        Part::Part2 => 2,
+    };
+    let Some(correct) = answers.check(day, part, result) else {
+        return;
+    };
+
+    let (badge, color) = if correct {
+        ("✔", CORRECT_COLOR)
+    } else {
+        ("✘", INCORRECT_COLOR)
+    };
+    parent.spawn((
+        Node {
+            margin: UiRect::left(Val::Px(5.)),
+            ..Default::default()
+        },
+        Text::new(badge),
+        TextFont {
+            font,
+            ..Default::default()
+        },
+        TextColor(color),
+    ));
+}
@@ -0,0 +1,245 @@
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{
+    app::Update,
+    asset::Handle,
+    color::Color,
+    input::{keyboard::KeyCode, ButtonInput},
+    prelude::{
+        in_state, resource_added, resource_exists, resource_removed, BuildChildren, Button,
+        Changed, ChildBuild, ChildBuilder, Commands, Component, Condition, Entity,
+        IntoSystemConfigs, OnEnter, Query, Res, ResMut, Resource, State, Text, With,
+    },
+    render::view::screenshot::{save_to_disk, Screenshot},
+    text::{Font, TextColor, TextFont},
+    ui::{BackgroundColor, Interaction, UiRect, Val},
+};
+
+use crate::scroll_controls::{
+    BUTTON_BACKGROUND_COLOR, BUTTON_HOVERED_BACKGROUND_COLOR, BUTTON_SELECTED_BACKGROUND_COLOR,
+};
+
+use super::{
+    days::button_node,
+    playback::PlaybackControl,
+    states::{Part, PlaybackState, Scene, UiState},
+};
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<CaptureDirectory>();
+
+        app.add_systems(OnEnter(UiState::NotLoaded), stop_recording)
+            .add_systems(
+                Update,
+                (
+                    capture_shortcuts,
+                    capture_controls_interaction,
+                    update_record_color
+                        .run_if(resource_added::<Recording>.or(resource_removed::<Recording>)),
+                    record_frame
+                        .run_if(resource_exists::<Recording>.and(in_state(PlaybackState::Playing))),
+                ),
+            );
+    }
+}
+
+/// Where screenshots and recordings are saved, `captures` unless set with `--captures <DIR>`
+#[derive(Debug, Resource)]
+pub struct CaptureDirectory(pub PathBuf);
+
+impl Default for CaptureDirectory {
+    fn default() -> Self {
+        Self(PathBuf::from("captures"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[require(Button)]
+enum CaptureControl {
+    Screenshot,
+    Record,
+}
+
+/// Numbered PNG sequence written while a simulation plays
+#[derive(Debug, Resource)]
+struct Recording {
+    directory: PathBuf,
+    frame: usize,
+    /// Screenshot of the last frame, the frames rendered until it is saved are skipped
+    pending: Option<Entity>,
+}
+
+/// Capture buttons of the header, recording is only offered to the stepping simulations
+pub fn build_capture_buttons(parent: &mut ChildBuilder, record: bool, font: Handle<Font>) {
+    let mut controls = vec![(CaptureControl::Screenshot, "Capture")];
+    if record {
+        controls.push((CaptureControl::Record, "Record"));
+    }
+
+    for (index, (control, label)) in controls.into_iter().enumerate() {
+        let mut node = button_node();
+        if index == 0 {
+            node.margin = UiRect::left(Val::Px(20.));
+        }
+        parent
+            .spawn((node, BackgroundColor(BUTTON_BACKGROUND_COLOR), control))
+            .with_child((
+                Text::new(label),
+                TextFont {
+                    font: font.clone(),
+                    ..Default::default()
+                },
+                TextColor(Color::BLACK),
+            ));
+    }
+}
+
+/// `F12` saves a screenshot, `Shift+F12` starts or stops a recording
+fn capture_shortcuts(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    directory: Res<CaptureDirectory>,
+    scene: Res<State<Scene>>,
+    part: Option<Res<State<Part>>>,
+    recording: Option<Res<Recording>>,
+    simulations: Query<(), With<PlaybackControl>>,
+) {
+    if !keys.just_pressed(KeyCode::F12) {
+        return;
+    }
+    let name = capture_name(*scene.get(), part.map(|part| *part.get()));
+
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        if recording.is_some() {
+            commands.remove_resource::<Recording>();
+        } else if !simulations.is_empty() {
+            start_recording(&mut commands, &directory, &name);
+        }
+    } else {
+        take_screenshot(&mut commands, &directory, &name);
+    }
+}
+
+fn capture_controls_interaction(
+    mut commands: Commands,
+    mut controls: Query<
+        (&mut BackgroundColor, &Interaction, &CaptureControl),
+        Changed<Interaction>,
+    >,
+    directory: Res<CaptureDirectory>,
+    scene: Res<State<Scene>>,
+    part: Option<Res<State<Part>>>,
+    recording: Option<Res<Recording>>,
+) {
+    let recording = recording.is_some();
+    for (mut background_color, interaction, control) in controls.iter_mut() {
+        match interaction {
+            Interaction::None => {
+                background_color.0 = control_color(*control, recording);
+            }
+            Interaction::Hovered => background_color.0 = BUTTON_HOVERED_BACKGROUND_COLOR,
+            Interaction::Pressed => {
+                let name = capture_name(*scene.get(), part.as_ref().map(|part| *part.get()));
+                match control {
+                    CaptureControl::Screenshot => take_screenshot(&mut commands, &directory, &name),
+                    CaptureControl::Record if recording => {
+                        commands.remove_resource::<Recording>();
+                    }
+                    CaptureControl::Record => start_recording(&mut commands, &directory, &name),
+                }
+            }
+        }
+    }
+}
+
+/// Keeps the record button highlighted while recording, whatever started it
+fn update_record_color(
+    mut controls: Query<(&mut BackgroundColor, &Interaction, &CaptureControl)>,
+    recording: Option<Res<Recording>>,
+) {
+    for (mut background_color, interaction, control) in controls.iter_mut() {
+        if *interaction == Interaction::None {
+            background_color.0 = control_color(*control, recording.is_some());
+        }
+    }
+}
+
+fn control_color(control: CaptureControl, recording: bool) -> Color {
+    match control {
+        CaptureControl::Record if recording => BUTTON_SELECTED_BACKGROUND_COLOR,
+        _ => BUTTON_BACKGROUND_COLOR,
+    }
+}
+
+fn stop_recording(mut commands: Commands) {
+    commands.remove_resource::<Recording>();
+}
+
+fn record_frame(
+    mut commands: Commands,
+    mut recording: ResMut<Recording>,
+    screenshots: Query<(), With<Screenshot>>,
+) {
+    // The screenshot entity is despawned once its image is saved
+    if recording
+        .pending
+        .is_some_and(|pending| screenshots.contains(pending))
+    {
+        return;
+    }
+    recording.frame += 1;
+    let path = recording
+        .directory
+        .join(format!("{:05}.png", recording.frame));
+    let screenshot = commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path))
+        .id();
+    recording.pending = Some(screenshot);
+}
+
+fn take_screenshot(commands: &mut Commands, directory: &CaptureDirectory, name: &str) {
+    if let Err(error) = std::fs::create_dir_all(&directory.0) {
+        bevy::log::error!("Cannot create {}: {error}", directory.0.display());
+        return;
+    }
+    let path = directory.0.join(format!("{name}_{}.png", timestamp()));
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path));
+}
+
+fn start_recording(commands: &mut Commands, directory: &CaptureDirectory, name: &str) {
+    let directory = directory.0.join(format!("{name}_{}", timestamp()));
+    if let Err(error) = std::fs::create_dir_all(&directory) {
+        bevy::log::error!("Cannot create {}: {error}", directory.display());
+        return;
+    }
+    bevy::log::info!("Recording to {}", directory.display());
+    commands.insert_resource(Recording {
+        directory,
+        frame: 0,
+        pending: None,
+    });
+}
+
+fn capture_name(scene: Scene, part: Option<Part>) -> String {
+    match (scene, part) {
+        (Scene::Day { year, day }, Some(Part::Part1)) => format!("{year}_day{day:02}_part1"),
+        (Scene::Day { year, day }, Some(Part::Part2)) => format!("{year}_day{day:02}_part2"),
+        (Scene::Day { year, day }, None) => format!("{year}_day{day:02}"),
+        (Scene::MainMenu, _) => String::from("main_menu"),
+    }
+}
+
+fn timestamp() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis())
+}
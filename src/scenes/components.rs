@@ -1,10 +1,16 @@
 use bevy::prelude::{Button, Component};
 
+use crate::loader::InputSource;
+
 use super::states::Scene;
 
 #[derive(Component)]
 pub struct Disabled;
 
+/// Camera drawing the gizmos on top of the UI
+#[derive(Debug, Component)]
+pub struct GizmosCamera;
+
 #[derive(Debug, Component)]
 pub struct SceneChange(pub Scene);
 
@@ -14,3 +20,7 @@ pub enum PartChange {
     Part1,
     Part2,
 }
+
+#[derive(Debug, Clone, Copy, Component)]
+#[require(Button)]
+pub struct InputSourceChange(pub InputSource);
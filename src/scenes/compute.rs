@@ -0,0 +1,219 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use bevy::{
+    app::Update,
+    asset::AssetServer,
+    color::Color,
+    ecs::world::CommandQueue,
+    prelude::{
+        in_state, resource_exists, BuildChildren, ChildBuild, ChildBuilder, Commands, Component,
+        IntoSystemConfigs, NextState, OnExit, Res, ResMut, Resource, Single, With, World,
+    },
+    tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task},
+    time::Time,
+    ui::{BackgroundColor, Node, Val},
+    utils::Instant,
+};
+
+use crate::loader::{PuzzleSizes, RawInput};
+
+use super::{
+    days::PuzzleInput,
+    resources::InputError,
+    states::{InputState, Part},
+};
+
+/// Marks a [`Progress`] that was never reported
+const UNKNOWN_PROGRESS: u32 = u32::MAX;
+/// Width of the block bouncing in a progress bar without progress, in percent
+const BOUNCING_WIDTH: f32 = 20.;
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            Update,
+            (
+                poll_compute_task.run_if(resource_exists::<ComputeTask>),
+                update_progress_bar.run_if(in_state(InputState::Computing)),
+            ),
+        )
+        // Dropping the task cancels its progress, which stops its loops when the day or its input
+        // change before it finishes
+        .add_systems(OnExit(InputState::Computing), |mut commands: Commands| {
+            commands.remove_resource::<ComputeTask>();
+        });
+    }
+}
+
+#[derive(Debug, Component)]
+struct ProgressBarFill;
+
+/// Fraction of a computation that is done, shared with the task computing it.
+///
+/// The solvers run without yielding, so dropping their task does not stop them. Instead their
+/// long loops stop early once the progress is cancelled, leaving a partial result nobody reads.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    fraction: Arc<AtomicU32>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self {
+            fraction: Arc::new(AtomicU32::new(UNKNOWN_PROGRESS)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl Progress {
+    /// Reports that `done` out of `total` steps are finished
+    pub fn report(&self, done: usize, total: usize) {
+        let fraction = (done as f32 / total.max(1) as f32).clamp(0., 1.);
+        self.fraction.store(fraction.to_bits(), Ordering::Relaxed);
+    }
+
+    /// `None` until the computation reports its progress
+    pub fn get(&self) -> Option<f32> {
+        let bits = self.fraction.load(Ordering::Relaxed);
+        (bits != UNKNOWN_PROGRESS).then(|| f32::from_bits(bits))
+    }
+
+    /// Asks the computation to stop, its result is no longer needed
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Input of the open day being parsed and solved on the [`AsyncComputeTaskPool`]
+#[derive(Debug, Resource)]
+pub struct ComputeTask {
+    task: Task<CommandQueue>,
+    pub progress: Progress,
+}
+
+impl Drop for ComputeTask {
+    fn drop(&mut self) {
+        self.progress.cancel();
+    }
+}
+
+/// Time the [`ComputeTask`] spent parsing the input and solving each part
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct ComputeTimes {
+    pub parse: Duration,
+    pub solve: [Duration; 2],
+}
+
+/// Parses and solves an input in the background, then inserts it as a resource with its
+/// [`ComputeTimes`] and moves to [`InputState::Loaded`], or to [`InputState::Failed`] with an
+/// [`InputError`].
+pub fn compute_input<I: PuzzleInput>(
+    commands: &mut Commands,
+    input: &RawInput,
+    sizes: PuzzleSizes,
+    asset_server: AssetServer,
+) {
+    let input = input.clone();
+    let progress = Progress::default();
+    let task_progress = progress.clone();
+
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        let start = Instant::now();
+        let result = I::parse_input(&input, sizes, &task_progress).map(|mut input| {
+            let parse = start.elapsed();
+            let solve = [Part::Part1, Part::Part2].map(|part| {
+                let start = Instant::now();
+                if !task_progress.is_cancelled() {
+                    input.solve_part(part, &task_progress);
+                }
+                start.elapsed()
+            });
+            if !task_progress.is_cancelled() {
+                input.load_assets(&asset_server);
+            }
+            (input, ComputeTimes { parse, solve })
+        });
+
+        let mut commands = CommandQueue::default();
+        commands.push(move |world: &mut World| {
+            let state = match result {
+                Ok((input, times)) => {
+                    world.insert_resource(input);
+                    world.insert_resource(times);
+                    InputState::Loaded
+                }
+                Err(error) => {
+                    world.insert_resource(InputError::Parse(error));
+                    InputState::Failed
+                }
+            };
+            world.resource_mut::<NextState<InputState>>().set(state);
+        });
+        commands
+    });
+
+    commands.insert_resource(ComputeTask { task, progress });
+}
+
+fn poll_compute_task(mut commands: Commands, mut compute_task: ResMut<ComputeTask>) {
+    if let Some(mut queue) = block_on(future::poll_once(&mut compute_task.task)) {
+        commands.append(&mut queue);
+        commands.remove_resource::<ComputeTask>();
+    }
+}
+
+/// Progress of the [`ComputeTask`], a block bounces in the bar until the task reports one
+pub fn build_progress_bar(parent: &mut ChildBuilder) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(300.),
+                height: Val::Px(10.),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+        ))
+        .with_child((
+            Node {
+                width: Val::Percent(0.),
+                height: Val::Percent(100.),
+                ..Default::default()
+            },
+            BackgroundColor(Color::WHITE),
+            ProgressBarFill,
+        ));
+}
+
+fn update_progress_bar(
+    mut fill: Single<&mut Node, With<ProgressBarFill>>,
+    compute_task: Option<Res<ComputeTask>>,
+    time: Res<Time>,
+) {
+    let Some(compute_task) = compute_task else {
+        return;
+    };
+
+    let (left, width) = match compute_task.progress.get() {
+        Some(progress) => (0., progress * 100.),
+        None => {
+            let bounce = time.elapsed_secs().sin() * 0.5 + 0.5;
+            (bounce * (100. - BOUNCING_WIDTH), BOUNCING_WIDTH)
+        }
+    };
+    fill.left = Val::Percent(left);
+    fill.width = Val::Percent(width);
+}
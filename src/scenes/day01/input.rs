@@ -1,8 +1,8 @@
-use std::io::BufRead;
+use std::collections::BTreeMap;
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
 
 #[derive(Debug, Resource)]
 pub struct Input {
@@ -11,19 +11,52 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
         let mut left = Vec::new();
         let mut right = Vec::new();
 
-        for line in input.lines() {
-            let line = line.unwrap();
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let (l, r) = line.split_field(line.bytes, b"   ")?;
 
-            let (l, r) = line.split_once("   ").unwrap();
-
-            left.push(l.parse().unwrap());
-            right.push(r.parse().unwrap());
+            left.push(line.number(l)?);
+            right.push(line.number(r)?);
         }
 
-        Self { left, right }
+        Ok(Self { left, right })
+    }
+
+    pub fn sorted(&self) -> (Vec<u32>, Vec<u32>) {
+        let mut left = self.left.clone();
+        left.sort();
+        let mut right = self.right.clone();
+        right.sort();
+
+        (left, right)
+    }
+
+    pub fn total_distance(&self) -> u32 {
+        let (left, right) = self.sorted();
+
+        left.iter()
+            .zip(right.iter())
+            .map(|(l, r)| l.abs_diff(*r))
+            .sum()
+    }
+
+    pub fn frequencies(&self) -> BTreeMap<u32, u32> {
+        self.left
+            .iter()
+            .chain(self.right.iter())
+            .map(|id| (*id, self.right.iter().filter(|r| *r == id).count() as u32))
+            .collect()
+    }
+
+    pub fn similarity_score(&self) -> u32 {
+        let frequencies = self.frequencies();
+
+        self.left
+            .iter()
+            .map(|id| id * frequencies.get(id).copied().unwrap_or(0))
+            .sum()
     }
 }
@@ -1,88 +1,120 @@
 mod input;
 mod part1;
 mod part2;
+mod walkthrough;
 
 use bevy::{
     app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
+    prelude::{in_state, resource_exists, Commands, Condition, IntoSystemConfigs, OnExit},
 };
 
-use crate::{loader::RawInput, scenes::states::Scene};
+use crate::loader::{ParseError, PuzzleSizes, RawInput};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    playback,
+    states::{Part, Scene, VisualizationState},
 };
 
-use self::input::Input;
+use self::{
+    input::Input,
+    walkthrough::{SimilarityWalkthrough, SortWalkthrough},
+};
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin));
-
-        app.add_computed_state::<VisualizationState<1>>();
+        app.add_plugins((
+            DayPlugin::<2024, 1, Input>::default(),
+            part1::Plugin,
+            part2::Plugin,
+        ));
 
-        app.add_systems(OnEnter(Scene::Day(1)), build_day_1);
-        app.add_systems(OnExit(Scene::Day(1)), destroy_day_1);
         app.add_systems(
             Update,
-            state_button_interactions.run_if(in_state(Scene::Day(1))),
+            (
+                playback::resource_timeline_systems::<SortWalkthrough>()
+                    .run_if(in_state(Part::Part1).and(resource_exists::<SortWalkthrough>)),
+                playback::resource_timeline_systems::<SimilarityWalkthrough>()
+                    .run_if(in_state(Part::Part2).and(resource_exists::<SimilarityWalkthrough>)),
+            )
+                .run_if(in_state(VisualizationState::<2024, 1>::Ready)),
         )
         .add_systems(
-            Update,
-            process_input.run_if(in_state(VisualizationState::<1>::WaitingInput)),
+            OnExit(Scene::Day { year: 2024, day: 1 }),
+            |mut commands: Commands| {
+                commands.remove_resource::<SortWalkthrough>();
+                commands.remove_resource::<SimilarityWalkthrough>();
+            },
         );
     }
 }
 
-fn build_day_1(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day1_camera"), Camera2d)).id();
-    let day1_resource = GenericDay {
-        input: asset_server.load("inputs/day1.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day1_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &RawInput,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day1_resource);
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.total_distance().to_string(),
+            Part::Part2 => self.similarity_score().to_string(),
+        }
+    }
 }
 
-fn destroy_day_1(mut commands: Commands, day1_resource: Res<GenericDay>) {
-    commands.entity(day1_resource.camera).despawn_recursive();
-    commands.entity(day1_resource.ui).despawn_recursive();
-
-    commands.remove_resource::<GenericDay>();
+pub fn solve(
+    input: &RawInput,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn process_input(
-    mut commands: Commands,
-    day1_resource: Res<GenericDay>,
-    inputs: Res<Assets<RawInput>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day1_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::{
+            compute::Progress,
+            playback::{Scrubbable, Simulation},
+        },
+    };
+
+    use super::walkthrough::{SimilarityWalkthrough, SortWalkthrough};
+
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day1.txt").to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["11", "31"]
+        );
+    }
+
+    #[test]
+    fn walkthroughs() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day1.txt").to_vec());
+        let input = super::Input::parse(&input).unwrap();
+
+        let mut sort = SortWalkthrough::new(&input);
+        while sort.step() {}
+        assert_eq!(sort.position(), sort.length());
+        assert_eq!((sort.left, sort.right), input.sorted());
+        assert_eq!(sort.total, 11);
+
+        let mut similarity = SimilarityWalkthrough::new(&input);
+        while similarity.step() {}
+        assert_eq!(similarity.score, 31);
+        assert!(similarity.step_back());
+        assert!(similarity.seek(2));
+        assert_eq!(similarity.score, 3 * 3 + 4);
     }
 }
@@ -2,29 +2,66 @@ use bevy::{
     app::Update,
     color::Color,
     prelude::{
-        in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, Res, ResMut, Text,
+        in_state, resource_exists_and_changed, BuildChildren, ChildBuild, ChildBuilder, Commands,
+        Component, Condition, DespawnRecursiveExt, IntoSystemConfigs, NextState, Query, Res,
+        ResMut, Single, Text, With, Without,
     },
     text::TextColor,
     ui::{
-        BorderColor, BorderRadius, FlexDirection, JustifyContent, Node, Overflow, PositionType,
-        UiRect, Val,
+        BorderColor, BorderRadius, Display, FlexDirection, JustifyContent, Node, Overflow,
+        PositionType, UiRect, Val,
     },
 };
 
 use crate::{
+    loader::Answers,
     scenes::{
-        days::{build_content, build_header},
+        answers::build_answer_badge,
+        days::{build_content, build_footer, build_header},
+        playback::{build_playback_controls, build_timeline, Scrubbable},
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
     scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
 };
 
-use super::input::Input;
+use super::{
+    input::Input,
+    walkthrough::{SortPhase, SortWalkthrough},
+};
 
 const SCROLL_SPEED: f32 = 512.;
 const VAL_GRID_WIDTH: f32 = 80.;
+/// Values out of place and pairs not linked yet
+const PENDING_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+/// Value placed or pair linked by the last step
+const HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 1.0, 0.4);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Left,
+    Right,
+    Distance,
+}
+
+#[derive(Debug, Component)]
+struct Cell {
+    row: usize,
+    column: Column,
+}
+
+#[derive(Debug, Component)]
+struct PairRow(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+enum Label {
+    TotalDistance,
+    Phase,
+}
+
+/// Shown once every pair is linked
+#[derive(Debug, Component)]
+struct AnswerBadge;
 
 pub struct Plugin;
 
@@ -34,7 +71,15 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part1))
-                .run_if(in_state(VisualizationState::<1>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 1>::WaitingUi)),
+        )
+        .add_systems(
+            Update,
+            update_walkthrough.run_if(
+                in_state(Part::Part1)
+                    .and(in_state(VisualizationState::<2024, 1>::Ready))
+                    .and(resource_exists_and_changed::<SortWalkthrough>),
+            ),
         );
     }
 }
@@ -45,39 +90,43 @@ fn build_ui(
     input: Res<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 1 Part 1");
-    let header = build_header(&mut commands, "day1", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day1", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day1");
+    let footer = build_footer(&mut commands, "day1");
+
+    commands.insert_resource(SortWalkthrough::new(&input));
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input, &fonts));
+        .with_children(|parent| build_visualization(parent, &input, &answers, &fonts));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<SortWalkthrough>(parent, &fonts);
+        build_timeline::<SortWalkthrough>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day1_resource.ui)
         .despawn_descendants()
-        .add_children(&[header, content]);
+        .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
-    let mut left = input.left.clone();
-    left.sort();
-    let mut right = input.right.clone();
-    right.sort();
-
-    let diff: u32 = left
-        .iter()
-        .zip(right.iter())
-        .map(|(l, r)| l.abs_diff(*r))
-        .sum();
+fn build_visualization(
+    parent: &mut ChildBuilder,
+    input: &Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
+    let diff = input.total_distance();
 
     parent
         .spawn(Node {
             top: Val::Px(50.),
-            bottom: Val::Px(10.),
+            bottom: Val::Px(60.),
             left: Val::Px(10.),
             right: Val::Px(10.),
             position_type: PositionType::Absolute,
@@ -89,13 +138,12 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
             parent
                 .spawn(Node {
                     flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(5.),
                     ..Default::default()
                 })
                 .with_children(|parent| {
                     parent
-                        .spawn((Node {
-                            ..Default::default()
-                        },))
+                        .spawn(Node::default())
                         .with_child((Text::new("Total distance"), TextColor::WHITE));
                     parent
                         .spawn((
@@ -108,7 +156,31 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child((Text::new(diff.to_string()), TextColor::WHITE));
+                        .with_children(|parent| {
+                            parent.spawn((Text::new("0"), TextColor::WHITE, Label::TotalDistance));
+                            parent
+                                .spawn((
+                                    Node {
+                                        display: Display::None,
+                                        ..Default::default()
+                                    },
+                                    AnswerBadge,
+                                ))
+                                .with_children(|parent| {
+                                    build_answer_badge(
+                                        parent,
+                                        answers,
+                                        (1, Part::Part1),
+                                        &diff.to_string(),
+                                        fonts.symbol2.clone(),
+                                    );
+                                });
+                        });
+                    parent.spawn((
+                        Text::new("Sorting the left list"),
+                        TextColor(PENDING_COLOR),
+                        Label::Phase,
+                    ));
                 });
 
             parent
@@ -124,24 +196,14 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             ..Default::default()
                         })
                         .with_children(|parent| {
-                            parent
-                                .spawn(Node {
-                                    min_width: Val::Px(VAL_GRID_WIDTH),
-                                    ..Default::default()
-                                })
-                                .with_child(Text::new("Left"));
-                            parent
-                                .spawn(Node {
-                                    min_width: Val::Px(VAL_GRID_WIDTH),
-                                    ..Default::default()
-                                })
-                                .with_child(Text::new("Right"));
-                            parent
-                                .spawn(Node {
-                                    min_width: Val::Px(VAL_GRID_WIDTH),
-                                    ..Default::default()
-                                })
-                                .with_child(Text::new("Diff"));
+                            for title in ["Left", "Right", "Diff"] {
+                                parent
+                                    .spawn(Node {
+                                        min_width: Val::Px(VAL_GRID_WIDTH),
+                                        ..Default::default()
+                                    })
+                                    .with_child(Text::new(title));
+                            }
                         });
                     let window = parent
                         .spawn((
@@ -153,46 +215,8 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             ScrollWindow,
                         ))
                         .with_children(|parent| {
-                            for (l, r) in left.iter().zip(right) {
-                                parent
-                                    .spawn((
-                                        Node {
-                                            border: UiRect::all(Val::Px(3.)),
-                                            padding: UiRect::axes(Val::Px(5.), Val::Px(2.)),
-                                            ..Default::default()
-                                        },
-                                        BorderColor(Color::WHITE),
-                                        BorderRadius::all(Val::Px(5.)),
-                                    ))
-                                    .with_children(|parent| {
-                                        parent
-                                            .spawn(Node {
-                                                min_width: Val::Px(VAL_GRID_WIDTH),
-                                                ..Default::default()
-                                            })
-                                            .with_child((
-                                                Text::new(l.to_string()),
-                                                TextColor::WHITE,
-                                            ));
-                                        parent
-                                            .spawn(Node {
-                                                min_width: Val::Px(VAL_GRID_WIDTH),
-                                                ..Default::default()
-                                            })
-                                            .with_child((
-                                                Text::new(r.to_string()),
-                                                TextColor::WHITE,
-                                            ));
-                                        parent
-                                            .spawn(Node {
-                                                min_width: Val::Px(VAL_GRID_WIDTH),
-                                                ..Default::default()
-                                            })
-                                            .with_child((
-                                                Text::new((l.abs_diff(r)).to_string()),
-                                                TextColor::WHITE,
-                                            ));
-                                    });
+                            for (row, (l, r)) in input.left.iter().zip(&input.right).enumerate() {
+                                build_pair_row(parent, row, [*l, *r]);
                             }
                         })
                         .id();
@@ -207,3 +231,106 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                 });
         });
 }
+
+/// Row of a pair, before sorting
+fn build_pair_row(parent: &mut ChildBuilder, row: usize, [l, r]: [u32; 2]) {
+    parent
+        .spawn((
+            Node {
+                border: UiRect::all(Val::Px(3.)),
+                padding: UiRect::axes(Val::Px(5.), Val::Px(2.)),
+                ..Default::default()
+            },
+            BorderColor(PENDING_COLOR),
+            BorderRadius::all(Val::Px(5.)),
+            PairRow(row),
+        ))
+        .with_children(|parent| {
+            for (column, value) in [
+                (Column::Left, l.to_string()),
+                (Column::Right, r.to_string()),
+                (Column::Distance, String::new()),
+            ] {
+                parent
+                    .spawn(Node {
+                        min_width: Val::Px(VAL_GRID_WIDTH),
+                        ..Default::default()
+                    })
+                    .with_child((
+                        Text::new(value),
+                        TextColor(PENDING_COLOR),
+                        Cell { row, column },
+                    ));
+            }
+        });
+}
+
+fn update_walkthrough(
+    walkthrough: Res<SortWalkthrough>,
+    mut rows: Query<(&PairRow, &mut BorderColor)>,
+    mut cells: Query<(&Cell, &mut Text, &mut TextColor)>,
+    mut labels: Query<(&Label, &mut Text), Without<Cell>>,
+    mut badge: Single<&mut Node, With<AnswerBadge>>,
+) {
+    let len = walkthrough.left.len();
+    let finished = walkthrough.is_finished();
+    // Values in place and whether their list is the one being sorted
+    let (left_sorted, right_sorted, linked) = match walkthrough.phase() {
+        SortPhase::SortingLeft(index) => ((index, true), (0, false), 0),
+        SortPhase::SortingRight(index) => ((len, false), (index, true), 0),
+        SortPhase::Linking(index) => ((len, false), (len, false), index),
+    };
+    let color = |row: usize, (done, active): (usize, bool)| {
+        if active && row + 1 == done {
+            HIGHLIGHT_COLOR
+        } else if row < done {
+            Color::WHITE
+        } else {
+            PENDING_COLOR
+        }
+    };
+
+    for (cell, mut text, mut text_color) in cells.iter_mut() {
+        let row = cell.row;
+        let (value, value_color) = match cell.column {
+            Column::Left => (walkthrough.left[row].to_string(), color(row, left_sorted)),
+            Column::Right => (walkthrough.right[row].to_string(), color(row, right_sorted)),
+            Column::Distance if row < linked => (
+                walkthrough.left[row]
+                    .abs_diff(walkthrough.right[row])
+                    .to_string(),
+                color(row, (linked, !finished)),
+            ),
+            Column::Distance => (String::new(), PENDING_COLOR),
+        };
+        if text.0 != value {
+            text.0 = value;
+        }
+        if text_color.0 != value_color {
+            text_color.0 = value_color;
+        }
+    }
+    for (row, mut border_color) in rows.iter_mut() {
+        let row_color = color(row.0, (linked, !finished));
+        if border_color.0 != row_color {
+            border_color.0 = row_color;
+        }
+    }
+
+    for (label, mut text) in labels.iter_mut() {
+        text.0 = match label {
+            Label::TotalDistance => walkthrough.total.to_string(),
+            Label::Phase if finished => String::new(),
+            Label::Phase => String::from(match walkthrough.phase() {
+                SortPhase::SortingLeft(_) => "Sorting the left list",
+                SortPhase::SortingRight(_) => "Sorting the right list",
+                SortPhase::Linking(_) => "Linking the pairs",
+            }),
+        };
+    }
+    badge.display = if finished {
+        Display::Flex
+    } else {
+        Display::None
+    };
+}
@@ -1,32 +1,66 @@
-use std::collections::{BTreeMap, BTreeSet};
-
 use bevy::{
     app::Update,
     color::Color,
     prelude::{
-        in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, Res, ResMut, Text,
+        in_state, resource_exists_and_changed, BuildChildren, ChildBuild, ChildBuilder, Commands,
+        Component, Condition, DespawnRecursiveExt, IntoSystemConfigs, NextState, Query, Res,
+        ResMut, Single, Text, With, Without,
     },
     text::TextColor,
     ui::{
-        BorderColor, BorderRadius, FlexDirection, JustifyContent, Node, Overflow, PositionType,
-        UiRect, Val,
+        BorderColor, BorderRadius, Display, FlexDirection, JustifyContent, Node, Overflow,
+        PositionType, UiRect, Val,
     },
 };
 
 use crate::{
+    loader::Answers,
     scenes::{
-        days::{build_content, build_header},
+        answers::build_answer_badge,
+        days::{build_content, build_footer, build_header},
+        playback::{build_playback_controls, build_timeline, Scrubbable},
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
     scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
 };
 
-use super::input::Input;
+use super::{input::Input, walkthrough::SimilarityWalkthrough};
 
 const SCROLL_SPEED: f32 = 512.;
 const VAL_GRID_WIDTH: f32 = 130.;
+/// Location IDs not scored yet
+const PENDING_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+/// Location ID scored by the last step, and its occurrences in the right list
+const HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 1.0, 0.4);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Id,
+    Frequency,
+    Score,
+}
+
+/// Cell of the row of a location ID of the left list
+#[derive(Debug, Component)]
+struct Cell {
+    row: usize,
+    column: Column,
+}
+
+#[derive(Debug, Component)]
+struct ScoreRow(usize);
+
+/// Location ID of the right list
+#[derive(Debug, Component)]
+struct RightEntry(usize);
+
+#[derive(Debug, Component)]
+struct SimilarityScore;
+
+/// Shown once every location ID is scored
+#[derive(Debug, Component)]
+struct AnswerBadge;
 
 pub struct Plugin;
 
@@ -36,7 +70,15 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part2))
-                .run_if(in_state(VisualizationState::<1>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 1>::WaitingUi)),
+        )
+        .add_systems(
+            Update,
+            update_walkthrough.run_if(
+                in_state(Part::Part2)
+                    .and(in_state(VisualizationState::<2024, 1>::Ready))
+                    .and(resource_exists_and_changed::<SimilarityWalkthrough>),
+            ),
         );
     }
 }
@@ -47,42 +89,43 @@ fn build_ui(
     input: Res<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 1 Part 2");
-    let header = build_header(&mut commands, "day1", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day1", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day1");
+    let footer = build_footer(&mut commands, "day1");
+
+    commands.insert_resource(SimilarityWalkthrough::new(&input));
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input, &fonts));
+        .with_children(|parent| build_visualization(parent, &input, &answers, &fonts));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<SimilarityWalkthrough>(parent, &fonts);
+        build_timeline::<SimilarityWalkthrough>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day1_resource.ui)
         .despawn_descendants()
-        .add_children(&[header, content]);
+        .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
-    let all_ids = BTreeSet::from_iter(input.left.iter().chain(input.right.iter()).copied());
-
-    let frequencies = BTreeMap::from_iter(
-        all_ids
-            .iter()
-            .map(|id| (*id, input.right.iter().filter(|r| *r == id).count() as u32)),
-    );
-
-    let similarity_score: u32 = input
-        .left
-        .iter()
-        .map(|id| id * frequencies.get(id).copied().unwrap_or(0))
-        .sum();
+fn build_visualization(
+    parent: &mut ChildBuilder,
+    input: &Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
+    let similarity_score = input.similarity_score();
 
     parent
         .spawn(Node {
             top: Val::Px(50.),
-            bottom: Val::Px(10.),
+            bottom: Val::Px(60.),
             left: Val::Px(10.),
             right: Val::Px(10.),
             position_type: PositionType::Absolute,
@@ -110,10 +153,30 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child((
-                            Text::new(similarity_score.to_string()),
-                            TextColor(Color::WHITE),
-                        ));
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("0"),
+                                TextColor(Color::WHITE),
+                                SimilarityScore,
+                            ));
+                            parent
+                                .spawn((
+                                    Node {
+                                        display: Display::None,
+                                        ..Default::default()
+                                    },
+                                    AnswerBadge,
+                                ))
+                                .with_children(|parent| {
+                                    build_answer_badge(
+                                        parent,
+                                        answers,
+                                        (1, Part::Part2),
+                                        &similarity_score.to_string(),
+                                        fonts.symbol2.clone(),
+                                    );
+                                });
+                        });
                 });
             parent
                 .spawn(Node {
@@ -129,24 +192,14 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             ..Default::default()
                         })
                         .with_children(|parent| {
-                            parent
-                                .spawn(Node {
-                                    min_width: Val::Px(VAL_GRID_WIDTH),
-                                    ..Default::default()
-                                })
-                                .with_child((Text::new("Location ID"), TextColor::WHITE));
-                            parent
-                                .spawn(Node {
-                                    min_width: Val::Px(VAL_GRID_WIDTH),
-                                    ..Default::default()
-                                })
-                                .with_child((Text::new("Frequency"), TextColor::WHITE));
-                            parent
-                                .spawn(Node {
-                                    min_width: Val::Px(VAL_GRID_WIDTH),
-                                    ..Default::default()
-                                })
-                                .with_child((Text::new("Score"), TextColor::WHITE));
+                            for title in ["Location ID", "Frequency", "Score"] {
+                                parent
+                                    .spawn(Node {
+                                        min_width: Val::Px(VAL_GRID_WIDTH),
+                                        ..Default::default()
+                                    })
+                                    .with_child((Text::new(title), TextColor::WHITE));
+                            }
                         });
                     let window = parent
                         .spawn((
@@ -158,49 +211,49 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             ScrollWindow,
                         ))
                         .with_children(|parent| {
-                            for id in &input.left {
-                                parent
-                                    .spawn((
-                                        Node {
-                                            flex_direction: FlexDirection::Row,
-                                            border: UiRect::all(Val::Px(3.)),
-                                            padding: UiRect::all(Val::Px(5.)),
-                                            column_gap: Val::Px(5.),
-                                            ..Default::default()
-                                        },
-                                        BorderColor(Color::WHITE),
-                                        BorderRadius::all(Val::Px(5.)),
-                                    ))
-                                    .with_children(|parent| {
-                                        let frequency = frequencies.get(id).copied().unwrap_or(0);
-                                        parent
-                                            .spawn(Node {
-                                                min_width: Val::Px(VAL_GRID_WIDTH),
-                                                ..Default::default()
-                                            })
-                                            .with_child((
-                                                Text::new(id.to_string()),
-                                                TextColor::WHITE,
-                                            ));
-                                        parent
-                                            .spawn(Node {
-                                                min_width: Val::Px(VAL_GRID_WIDTH),
-                                                ..Default::default()
-                                            })
-                                            .with_child((
-                                                Text::new(frequency.to_string()),
-                                                TextColor::WHITE,
-                                            ));
-                                        parent
-                                            .spawn(Node {
-                                                min_width: Val::Px(VAL_GRID_WIDTH),
-                                                ..Default::default()
-                                            })
-                                            .with_child((
-                                                Text::new((id * frequency).to_string()),
-                                                TextColor::WHITE,
-                                            ));
-                                    });
+                            for (row, id) in input.left.iter().enumerate() {
+                                build_score_row(parent, row, *id);
+                            }
+                        })
+                        .id();
+
+                    build_vertical_scroll_buttons(
+                        parent,
+                        window,
+                        SCROLL_SPEED,
+                        BUTTON_BACKGROUND_COLOR,
+                        fonts.symbol1.clone(),
+                    );
+                });
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(Node {
+                            padding: UiRect::all(Val::Px(5.)),
+                            ..Default::default()
+                        })
+                        .with_child((Text::new("Right list"), TextColor::WHITE));
+                    let window = parent
+                        .spawn((
+                            Node {
+                                flex_direction: FlexDirection::Column,
+                                overflow: Overflow::scroll_y(),
+                                padding: UiRect::horizontal(Val::Px(8.)),
+                                ..Default::default()
+                            },
+                            ScrollWindow,
+                        ))
+                        .with_children(|parent| {
+                            for (index, id) in input.right.iter().enumerate() {
+                                parent.spawn((
+                                    Text::new(id.to_string()),
+                                    TextColor(Color::WHITE),
+                                    RightEntry(index),
+                                ));
                             }
                         })
                         .id();
@@ -215,3 +268,102 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                 });
         });
 }
+
+/// Row of a location ID of the left list, before it is scored
+fn build_score_row(parent: &mut ChildBuilder, row: usize, id: u32) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                border: UiRect::all(Val::Px(3.)),
+                padding: UiRect::all(Val::Px(5.)),
+                column_gap: Val::Px(5.),
+                ..Default::default()
+            },
+            BorderColor(PENDING_COLOR),
+            BorderRadius::all(Val::Px(5.)),
+            ScoreRow(row),
+        ))
+        .with_children(|parent| {
+            for (column, value) in [
+                (Column::Id, id.to_string()),
+                (Column::Frequency, String::new()),
+                (Column::Score, String::new()),
+            ] {
+                parent
+                    .spawn(Node {
+                        min_width: Val::Px(VAL_GRID_WIDTH),
+                        ..Default::default()
+                    })
+                    .with_child((
+                        Text::new(value),
+                        TextColor(PENDING_COLOR),
+                        Cell { row, column },
+                    ));
+            }
+        });
+}
+
+fn update_walkthrough(
+    walkthrough: Res<SimilarityWalkthrough>,
+    mut rows: Query<(&ScoreRow, &mut BorderColor)>,
+    mut cells: Query<(&Cell, &mut Text, &mut TextColor)>,
+    mut right_entries: Query<(&RightEntry, &mut TextColor), Without<Cell>>,
+    mut score: Single<&mut Text, (With<SimilarityScore>, Without<Cell>)>,
+    mut badge: Single<&mut Node, With<AnswerBadge>>,
+) {
+    let scored = walkthrough.scored;
+    let finished = walkthrough.is_finished();
+    let color = |row: usize| {
+        if !finished && row + 1 == scored {
+            HIGHLIGHT_COLOR
+        } else if row < scored {
+            Color::WHITE
+        } else {
+            PENDING_COLOR
+        }
+    };
+
+    for (cell, mut text, mut text_color) in cells.iter_mut() {
+        let id = walkthrough.left[cell.row];
+        let frequency = walkthrough.frequency(id);
+        let value = match cell.column {
+            Column::Id => id.to_string(),
+            _ if cell.row >= scored => String::new(),
+            Column::Frequency => frequency.to_string(),
+            Column::Score => (id * frequency).to_string(),
+        };
+        if text.0 != value {
+            text.0 = value;
+        }
+        let value_color = color(cell.row);
+        if text_color.0 != value_color {
+            text_color.0 = value_color;
+        }
+    }
+    for (row, mut border_color) in rows.iter_mut() {
+        let row_color = color(row.0);
+        if border_color.0 != row_color {
+            border_color.0 = row_color;
+        }
+    }
+
+    let current = walkthrough.current().filter(|_| !finished);
+    for (entry, mut text_color) in right_entries.iter_mut() {
+        let entry_color = if current == Some(walkthrough.right[entry.0]) {
+            HIGHLIGHT_COLOR
+        } else {
+            Color::WHITE
+        };
+        if text_color.0 != entry_color {
+            text_color.0 = entry_color;
+        }
+    }
+
+    score.0 = walkthrough.score.to_string();
+    badge.display = if finished {
+        Display::Flex
+    } else {
+        Display::None
+    };
+}
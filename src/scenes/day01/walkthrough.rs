@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::Resource;
+
+use crate::scenes::playback::{Scrubbable, Simulation};
+
+use super::input::Input;
+
+/// Time the whole walkthrough takes at 1x speed, in seconds
+const WALKTHROUGH_DURATION: f32 = 20.;
+const MIN_STEPS_PER_SECOND: f32 = 2.;
+
+fn steps_per_second(steps: usize) -> f32 {
+    (steps as f32 / WALKTHROUGH_DURATION).max(MIN_STEPS_PER_SECOND)
+}
+
+/// Sorts the left list then the right list, placing one value per step, then links each
+/// pair in order while adding up their distances
+#[derive(Debug, Resource)]
+pub struct SortWalkthrough {
+    start: (Vec<u32>, Vec<u32>),
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+    pub total: u32,
+    position: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortPhase {
+    /// Values of the left list before the index are in place
+    SortingLeft(usize),
+    /// Values of the right list before the index are in place
+    SortingRight(usize),
+    /// Pairs before the index are linked
+    Linking(usize),
+}
+
+impl SortWalkthrough {
+    pub fn new(input: &Input) -> Self {
+        Self {
+            start: (input.left.clone(), input.right.clone()),
+            left: input.left.clone(),
+            right: input.right.clone(),
+            total: 0,
+            position: 0,
+        }
+    }
+
+    pub fn phase(&self) -> SortPhase {
+        let len = self.left.len();
+        if self.position < len {
+            SortPhase::SortingLeft(self.position)
+        } else if self.position < 2 * len {
+            SortPhase::SortingRight(self.position - len)
+        } else {
+            SortPhase::Linking(self.position - 2 * len)
+        }
+    }
+
+    /// Swaps the smallest value left unsorted into `index`
+    fn place(list: &mut [u32], index: usize) {
+        if let Some(smallest) = (index..list.len()).min_by_key(|i| list[*i]) {
+            list.swap(index, smallest);
+        }
+    }
+}
+
+impl Simulation for SortWalkthrough {
+    fn steps_per_second(&self) -> f32 {
+        steps_per_second(self.length())
+    }
+
+    fn step(&mut self) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        match self.phase() {
+            SortPhase::SortingLeft(index) => Self::place(&mut self.left, index),
+            SortPhase::SortingRight(index) => Self::place(&mut self.right, index),
+            SortPhase::Linking(index) => self.total += self.left[index].abs_diff(self.right[index]),
+        }
+        self.position += 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.left.clone_from(&self.start.0);
+        self.right.clone_from(&self.start.1);
+        self.total = 0;
+        self.position = 0;
+    }
+}
+
+impl Scrubbable for SortWalkthrough {
+    type Snapshot = (Vec<u32>, Vec<u32>, u32, usize);
+
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn length(&self) -> usize {
+        self.left.len() * 3
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (
+            self.left.clone(),
+            self.right.clone(),
+            self.total,
+            self.position,
+        )
+    }
+
+    fn restore(&mut self, (left, right, total, position): &Self::Snapshot) {
+        self.left.clone_from(left);
+        self.right.clone_from(right);
+        self.total = *total;
+        self.position = *position;
+    }
+}
+
+/// Scores the values of the left list one per step, by how often they appear in the
+/// right list
+#[derive(Debug, Resource)]
+pub struct SimilarityWalkthrough {
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+    pub frequencies: BTreeMap<u32, u32>,
+    /// Values of the left list already scored
+    pub scored: usize,
+    pub score: u32,
+}
+
+impl SimilarityWalkthrough {
+    pub fn new(input: &Input) -> Self {
+        Self {
+            left: input.left.clone(),
+            right: input.right.clone(),
+            frequencies: input.frequencies(),
+            scored: 0,
+            score: 0,
+        }
+    }
+
+    pub fn frequency(&self, id: u32) -> u32 {
+        self.frequencies.get(&id).copied().unwrap_or(0)
+    }
+
+    /// Value of the left list scored by the last step
+    pub fn current(&self) -> Option<u32> {
+        self.scored.checked_sub(1).map(|index| self.left[index])
+    }
+}
+
+impl Simulation for SimilarityWalkthrough {
+    const REVERSIBLE: bool = true;
+
+    fn steps_per_second(&self) -> f32 {
+        steps_per_second(self.length())
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&id) = self.left.get(self.scored) else {
+            return false;
+        };
+        self.score += id * self.frequency(id);
+        self.scored += 1;
+        true
+    }
+
+    fn step_back(&mut self) -> bool {
+        let Some(id) = self.current() else {
+            return false;
+        };
+        self.score -= id * self.frequency(id);
+        self.scored -= 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.scored = 0;
+        self.score = 0;
+    }
+}
+
+impl Scrubbable for SimilarityWalkthrough {
+    type Snapshot = (usize, u32);
+
+    fn position(&self) -> usize {
+        self.scored
+    }
+
+    fn length(&self) -> usize {
+        self.left.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.scored, self.score)
+    }
+
+    fn restore(&mut self, &(scored, score): &Self::Snapshot) {
+        self.scored = scored;
+        self.score = score;
+    }
+
+    fn seek(&mut self, position: usize) -> bool {
+        self.scored = position.min(self.left.len());
+        self.score = self.left[..self.scored]
+            .iter()
+            .map(|id| id * self.frequency(*id))
+            .sum();
+        true
+    }
+}
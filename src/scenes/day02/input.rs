@@ -1,8 +1,8 @@
-use std::{cmp::Ordering, io::BufRead};
+use std::cmp::Ordering;
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
 
 #[derive(Debug, Resource)]
 pub struct Input {
@@ -22,6 +22,15 @@ pub enum Safety {
     Unsafe,
 }
 
+/// Rule broken by the difference between two consecutive levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// The levels differ by less than 1 or more than 3
+    Step,
+    /// The levels go the other way than most of the report
+    Direction,
+}
+
 #[derive(Debug, Default)]
 struct SafetyTest {
     previous: u8,
@@ -31,18 +40,44 @@ struct SafetyTest {
     max_change: u8,
 }
 
+impl Report {
+    /// Differences breaking a rule of [`Input::test_safety`], by the index of their first level
+    pub fn violations(&self) -> Vec<(usize, Violation)> {
+        let pairs = || self.report.windows(2);
+        let increasing = pairs().filter(|pair| pair[0] < pair[1]).count();
+        let decreasing = pairs().filter(|pair| pair[0] > pair[1]).count();
+        let direction = if increasing >= decreasing {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+
+        pairs()
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let cmp = pair[0].cmp(&pair[1]);
+                if cmp == Ordering::Equal || pair[0].abs_diff(pair[1]) > 3 {
+                    Some((i, Violation::Step))
+                } else if cmp != direction {
+                    Some((i, Violation::Direction))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
         let mut reports = Vec::new();
 
-        for line in input.lines() {
-            let line = line.unwrap();
-
+        for line in input.lines().filter(|line| !line.is_empty()) {
             let report = line
-                .split_ascii_whitespace()
-                .map(|val| val.parse::<u8>())
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap();
+                .split(|c| *c == b' ')
+                .filter(|val| !val.is_empty())
+                .map(|val| line.number::<u8>(val))
+                .collect::<Result<Vec<_>, _>>()?;
 
             let safety = if Self::test_safety(report.iter().copied()) {
                 Safety::Safe
@@ -63,7 +98,21 @@ impl Input {
             reports.push(Report { report, safety });
         }
 
-        Self { reports }
+        Ok(Self { reports })
+    }
+
+    pub fn safe(&self) -> usize {
+        self.reports
+            .iter()
+            .filter(|report| matches!(report.safety, Safety::Safe))
+            .count()
+    }
+
+    pub fn kinda_safe(&self) -> usize {
+        self.reports
+            .iter()
+            .filter(|report| matches!(report.safety, Safety::Safe | Safety::OneError(_)))
+            .count()
     }
 
     fn test_safety(levels: impl Iterator<Item = u8>) -> bool {
@@ -85,3 +134,46 @@ impl Input {
             && (1..=3).contains(&safety.max_change)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Input, Report, Safety, Violation};
+
+    #[test]
+    fn test_safety() {
+        let reports: [(&[u8], bool); 6] = [
+            (&[7, 6, 4, 2, 1], true),
+            (&[1, 2, 7, 8, 9], false),
+            (&[9, 7, 6, 2, 1], false),
+            (&[1, 3, 2, 4, 5], false),
+            (&[8, 6, 4, 4, 1], false),
+            (&[1, 3, 6, 7, 9], true),
+        ];
+
+        for (levels, safe) in reports {
+            assert_eq!(
+                Input::test_safety(levels.iter().copied()),
+                safe,
+                "{levels:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn violations() {
+        let violations = |levels: &[u8]| {
+            Report {
+                report: levels.to_vec(),
+                safety: Safety::Unsafe,
+            }
+            .violations()
+        };
+
+        assert_eq!(violations(&[7, 6, 4, 2, 1]), []);
+        assert_eq!(violations(&[1, 2, 7, 8, 9]), [(1, Violation::Step)]);
+        assert_eq!(violations(&[9, 7, 6, 2, 1]), [(2, Violation::Step)]);
+        assert_eq!(violations(&[1, 3, 2, 4, 5]), [(1, Violation::Direction)]);
+        assert_eq!(violations(&[8, 6, 4, 4, 1]), [(2, Violation::Step)]);
+        assert_eq!(violations(&[1, 3, 6, 7, 9]), []);
+    }
+}
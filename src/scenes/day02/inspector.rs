@@ -0,0 +1,373 @@
+use bevy::{
+    app::Update,
+    color::{palettes, Color},
+    math::Vec2,
+    prelude::{
+        in_state, resource_exists, resource_exists_and_changed, BuildChildren, Button, Changed,
+        ChildBuild, ChildBuilder, Commands, Component, Condition, DespawnRecursiveExt, Entity,
+        Gizmos, GlobalTransform, IntoSystemConfigs, OnEnter, Query, Res, ResMut, Resource, Single,
+        Text, With,
+    },
+    text::{TextColor, TextFont},
+    ui::{
+        BackgroundColor, BorderColor, BorderRadius, Display, FlexDirection, Interaction, Node,
+        Outline, PositionType, UiRect, Val,
+    },
+    utils::HashSet,
+    window::Window,
+};
+
+use crate::{
+    scenes::states::VisualizationState,
+    scroll_controls::{BUTTON_BACKGROUND_COLOR, BUTTON_SELECTED_BACKGROUND_COLOR},
+};
+
+use super::input::{Input, Report, Safety, Violation};
+
+const CHART_WIDTH: f32 = 360.;
+const CHART_HEIGHT: f32 = 200.;
+const POINT_SIZE: f32 = 10.;
+const STEP_COLOR: Color = Color::Srgba(palettes::basic::RED);
+const DIRECTION_COLOR: Color = Color::Srgba(palettes::css::ORANGE);
+const SELECTED_OUTLINE_COLOR: Color = Color::srgb(0.7, 1.0, 1.0);
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            Update,
+            (
+                filter_interaction,
+                report_interaction,
+                update_filters.run_if(resource_exists_and_changed::<ReportFilters>),
+                update_selected_report.run_if(resource_exists_and_changed::<SelectedReport>),
+                draw_chart,
+            )
+                .chain()
+                .run_if(
+                    in_state(VisualizationState::<2024, 2>::Ready)
+                        .and(resource_exists::<SelectedReport>),
+                ),
+        )
+        // The list and the panel are built fresh, show the current selection and filters on them
+        .add_systems(
+            OnEnter(VisualizationState::<2024, 2>::Ready),
+            (update_filters, update_selected_report),
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SafetyFilter {
+    Safe,
+    OneError,
+    Unsafe,
+}
+
+impl SafetyFilter {
+    pub const ALL: [Self; 3] = [Self::Safe, Self::OneError, Self::Unsafe];
+
+    pub fn of(safety: &Safety) -> Self {
+        match safety {
+            Safety::Safe => Self::Safe,
+            Safety::OneError(_) => Self::OneError,
+            Safety::Unsafe => Self::Unsafe,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Safe => "Safe",
+            Self::OneError => "One error",
+            Self::Unsafe => "Unsafe",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Self::Safe => palettes::basic::GREEN.into(),
+            Self::OneError => palettes::basic::YELLOW.into(),
+            Self::Unsafe => palettes::basic::RED.into(),
+        }
+    }
+}
+
+/// Report shown in the detail panel
+#[derive(Debug, Default, Resource)]
+pub struct SelectedReport(pub Option<usize>);
+
+/// Kinds of reports hidden from the list
+#[derive(Debug, Default, Resource)]
+pub struct ReportFilters(pub HashSet<SafetyFilter>);
+
+#[derive(Debug, Component)]
+#[require(Button)]
+pub struct ReportRow(pub usize);
+
+#[derive(Debug, Component)]
+#[require(Button)]
+struct FilterToggle(SafetyFilter);
+
+#[derive(Debug, Component)]
+pub struct DetailPanel;
+
+/// Level of the selected report on the chart, its segments are drawn between these
+#[derive(Debug, Component)]
+struct ChartPoint(usize);
+
+pub fn build_filters(parent: &mut ChildBuilder, input: &Input) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(5.),
+            margin: UiRect::bottom(Val::Px(5.)),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for filter in SafetyFilter::ALL {
+                let count = input
+                    .reports
+                    .iter()
+                    .filter(|report| SafetyFilter::of(&report.safety) == filter)
+                    .count();
+                parent
+                    .spawn((
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.), Val::Px(3.)),
+                            border: UiRect::bottom(Val::Px(3.)),
+                            ..Default::default()
+                        },
+                        BackgroundColor(BUTTON_SELECTED_BACKGROUND_COLOR),
+                        BorderColor(filter.color()),
+                        FilterToggle(filter),
+                    ))
+                    .with_child((
+                        Text::new(format!("{} ({count})", filter.name())),
+                        TextColor(Color::BLACK),
+                    ));
+            }
+        });
+}
+
+fn filter_interaction(
+    toggles: Query<(&Interaction, &FilterToggle), Changed<Interaction>>,
+    mut filters: ResMut<ReportFilters>,
+) {
+    for (interaction, toggle) in toggles.iter() {
+        if *interaction == Interaction::Pressed && !filters.0.remove(&toggle.0) {
+            filters.0.insert(toggle.0);
+        }
+    }
+}
+
+fn update_filters(
+    mut toggles: Query<(&FilterToggle, &mut BackgroundColor)>,
+    mut rows: Query<(&ReportRow, &mut Node)>,
+    filters: Res<ReportFilters>,
+    input: Res<Input>,
+) {
+    for (toggle, mut background_color) in toggles.iter_mut() {
+        background_color.0 = if filters.0.contains(&toggle.0) {
+            BUTTON_BACKGROUND_COLOR
+        } else {
+            BUTTON_SELECTED_BACKGROUND_COLOR
+        };
+    }
+    for (row, mut node) in rows.iter_mut() {
+        let hidden = input
+            .reports
+            .get(row.0)
+            .is_some_and(|report| filters.0.contains(&SafetyFilter::of(&report.safety)));
+        node.display = if hidden { Display::None } else { Display::Flex };
+    }
+}
+
+fn report_interaction(
+    rows: Query<(&Interaction, &ReportRow), Changed<Interaction>>,
+    mut selected: ResMut<SelectedReport>,
+) {
+    for (interaction, row) in rows.iter() {
+        if *interaction == Interaction::Pressed && selected.0 != Some(row.0) {
+            selected.0 = Some(row.0);
+        }
+    }
+}
+
+fn update_selected_report(
+    mut commands: Commands,
+    rows: Query<(Entity, &ReportRow)>,
+    panel: Single<Entity, With<DetailPanel>>,
+    selected: Res<SelectedReport>,
+    input: Res<Input>,
+) {
+    for (entity, row) in rows.iter() {
+        if selected.0 == Some(row.0) {
+            commands.entity(entity).insert(Outline::new(
+                Val::Px(2.),
+                Val::ZERO,
+                SELECTED_OUTLINE_COLOR,
+            ));
+        } else {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+
+    commands
+        .entity(*panel)
+        .despawn_descendants()
+        .with_children(|parent| {
+            match selected
+                .0
+                .and_then(|index| Some((index, input.reports.get(index)?)))
+            {
+                Some((index, report)) => build_report_details(parent, index, report),
+                None => {
+                    parent.spawn((
+                        Text::new("Click a report to inspect it"),
+                        TextColor(Color::WHITE),
+                    ));
+                }
+            }
+        });
+}
+
+fn build_report_details(parent: &mut ChildBuilder, index: usize, report: &Report) {
+    let levels = &report.report;
+    let violations = report.violations();
+
+    parent.spawn((
+        Text::new(format!("Report {}", index + 1)),
+        TextColor(Color::WHITE),
+    ));
+    let (verdict, color) = match report.safety {
+        Safety::Safe => (String::from("Safe"), SafetyFilter::Safe.color()),
+        Safety::OneError(removed) => (
+            format!(
+                "Safe once level {} ({}) is removed",
+                removed + 1,
+                levels[removed]
+            ),
+            SafetyFilter::OneError.color(),
+        ),
+        Safety::Unsafe => (
+            String::from("Unsafe, even without any one level"),
+            SafetyFilter::Unsafe.color(),
+        ),
+    };
+    parent.spawn((Text::new(verdict), TextColor(color)));
+
+    let min = levels.iter().copied().min().unwrap_or_default();
+    let max = levels.iter().copied().max().unwrap_or_default();
+    let range = f32::from(max - min).max(1.);
+    let spacing = CHART_WIDTH / (levels.len().max(2) - 1) as f32;
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(CHART_WIDTH + POINT_SIZE * 4.),
+                height: Val::Px(CHART_HEIGHT + POINT_SIZE * 4.),
+                margin: UiRect::vertical(Val::Px(10.)),
+                border: UiRect::all(Val::Px(2.)),
+                ..Default::default()
+            },
+            BorderColor(Color::WHITE),
+            BorderRadius::all(Val::Px(5.)),
+        ))
+        .with_children(|parent| {
+            for (i, level) in levels.iter().enumerate() {
+                let removed = matches!(report.safety, Safety::OneError(removed) if removed == i);
+                let left = POINT_SIZE + i as f32 * spacing;
+                let top = POINT_SIZE + CHART_HEIGHT * f32::from(max - level) / range;
+                parent.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(left),
+                        top: Val::Px(top),
+                        width: Val::Px(POINT_SIZE),
+                        height: Val::Px(POINT_SIZE),
+                        ..Default::default()
+                    },
+                    BackgroundColor(if removed {
+                        SafetyFilter::OneError.color()
+                    } else {
+                        Color::WHITE
+                    }),
+                    BorderRadius::MAX,
+                    ChartPoint(i),
+                ));
+                parent.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(left + POINT_SIZE),
+                        top: Val::Px(top - POINT_SIZE * 1.5),
+                        ..Default::default()
+                    },
+                    Text::new(level.to_string()),
+                    TextFont {
+                        font_size: 12.,
+                        ..Default::default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            }
+        });
+
+    for (i, violation) in violations {
+        let (rule, color) = match violation {
+            Violation::Step => ("step outside 1 to 3", STEP_COLOR),
+            Violation::Direction => ("against the direction of the report", DIRECTION_COLOR),
+        };
+        parent.spawn((
+            Text::new(format!(
+                "Levels {} to {}: {} to {}, {rule}",
+                i + 1,
+                i + 2,
+                levels[i],
+                levels[i + 1]
+            )),
+            TextColor(color),
+        ));
+    }
+}
+
+/// Segments between the points of the chart, colored after the rule they break
+fn draw_chart(
+    mut gizmos: Gizmos,
+    window: Single<&Window>,
+    points: Query<(&ChartPoint, &GlobalTransform)>,
+    selected: Res<SelectedReport>,
+    input: Res<Input>,
+) {
+    let Some(report) = selected.0.and_then(|index| input.reports.get(index)) else {
+        return;
+    };
+    let mut positions = vec![None; report.report.len()];
+    for (point, transform) in points.iter() {
+        positions[point.0] = Some(transform.translation().truncate());
+    }
+    let Some(positions) = positions.into_iter().collect::<Option<Vec<_>>>() else {
+        return;
+    };
+    let window_size = window.physical_size().as_vec2() / 2.;
+    let to_world = |position: Vec2| (position - window_size) * Vec2::new(1., -1.);
+
+    let violations = report.violations();
+    for (i, pair) in positions.windows(2).enumerate() {
+        let color = match violations.iter().find(|(index, _)| *index == i) {
+            Some((_, Violation::Step)) => STEP_COLOR,
+            Some((_, Violation::Direction)) => DIRECTION_COLOR,
+            None => Color::WHITE,
+        };
+        gizmos.line_2d(to_world(pair[0]), to_world(pair[1]), color);
+    }
+    // The report as it is once the level is removed
+    if let Safety::OneError(removed) = report.safety {
+        if removed > 0 && removed + 1 < positions.len() {
+            gizmos.line_2d(
+                to_world(positions[removed - 1]),
+                to_world(positions[removed + 1]),
+                SafetyFilter::Safe.color(),
+            );
+        }
+    }
+}
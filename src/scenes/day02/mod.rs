@@ -1,87 +1,95 @@
 mod input;
+pub(super) mod inspector;
 mod ui;
 
-use bevy::{
-    app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
-};
+use bevy::prelude::{Commands, OnEnter, OnExit};
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, PuzzleSizes, RawInput};
 
 use input::Input;
+use inspector::{ReportFilters, SelectedReport};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, despawn_gizmos_camera, spawn_gizmos_camera, DayPlugin, PuzzleInput},
+    states::{Part, Scene, VisualizationState},
 };
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins(ui::Plugin);
-
-        app.add_computed_state::<VisualizationState<2>>();
+        app.add_plugins((
+            DayPlugin::<2024, 2, Input>::default(),
+            ui::Plugin,
+            inspector::Plugin,
+        ));
 
-        app.add_systems(OnEnter(Scene::Day(2)), build_day_2)
-            .add_systems(OnExit(Scene::Day(2)), destroy_day_2)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<2>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(2))),
-            );
+        app.add_systems(
+            OnEnter(Scene::Day { year: 2024, day: 2 }),
+            spawn_gizmos_camera("day2_gizmos_camera"),
+        )
+        // The reports change with the input, nothing can stay selected across a reload
+        .add_systems(
+            OnEnter(VisualizationState::<2024, 2>::WaitingInput),
+            reset_inspector,
+        )
+        .add_systems(
+            OnExit(Scene::Day { year: 2024, day: 2 }),
+            (tear_down_inspector, despawn_gizmos_camera),
+        );
     }
 }
 
-fn build_day_2(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day2_camera"), Camera2d)).id();
-    let day2_resource = GenericDay {
-        input: asset_server.load("inputs/day2.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day2_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
+fn reset_inspector(mut commands: Commands) {
+    commands.insert_resource(SelectedReport::default());
+    commands.insert_resource(ReportFilters::default());
+}
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day2_resource);
+fn tear_down_inspector(mut commands: Commands) {
+    commands.remove_resource::<SelectedReport>();
+    commands.remove_resource::<ReportFilters>();
 }
 
-fn destroy_day_2(mut commands: Commands, day2_resource: Res<GenericDay>) {
-    commands.entity(day2_resource.camera).despawn_recursive();
-    commands.entity(day2_resource.ui).despawn_recursive();
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &RawInput,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
 
-    commands.remove_resource::<GenericDay>();
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.safe().to_string(),
+            Part::Part2 => self.kinda_safe().to_string(),
+        }
+    }
 }
 
-fn process_input(
-    mut commands: Commands,
-    day2_resource: Res<GenericDay>,
-    inputs: Res<Assets<RawInput>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day2_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+pub fn solve(
+    input: &RawInput,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
+
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day2.txt").to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["2", "4"]
+        );
     }
 }
@@ -2,8 +2,8 @@ use bevy::{
     app::Update,
     color::{palettes, Color},
     prelude::{
-        in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, IntoSystemConfigs, NextState,
-        Res, ResMut, Text,
+        in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, DespawnRecursiveExt,
+        IntoSystemConfigs, NextState, Res, ResMut, Text,
     },
     text::TextColor,
     ui::{BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType, UiRect, Val},
@@ -18,7 +18,10 @@ use crate::{
     scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
 };
 
-use super::input;
+use super::{
+    input,
+    inspector::{build_filters, DetailPanel, ReportRow, SafetyFilter},
+};
 
 const SCROLL_SPEED: f32 = 512.;
 
@@ -28,7 +31,7 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui.run_if(in_state(VisualizationState::<2>::WaitingUi)),
+            build_ui.run_if(in_state(VisualizationState::<2024, 2>::WaitingUi)),
         );
     }
 }
@@ -41,7 +44,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 2");
-    let header = build_header(&mut commands, "day2", false, fonts.font.clone());
+    let header = build_header(&mut commands, "day2", false, false, fonts.font.clone());
     let content = build_content(&mut commands, "day2");
 
     commands
@@ -50,27 +53,15 @@ fn build_ui(
 
     commands
         .entity(day2_resource.ui)
+        .despawn_descendants()
         .add_children(&[header, content]);
 
     next_state.set(UiState::Loaded);
 }
 
 fn build_visualization(parent: &mut ChildBuilder, input: &input::Input, fonts: &FontHandles) {
-    let safe = input
-        .reports
-        .iter()
-        .filter(|report| matches!(report.safety, input::Safety::Safe))
-        .count();
-    let kinda_safe = input
-        .reports
-        .iter()
-        .filter(|report| {
-            matches!(
-                report.safety,
-                input::Safety::Safe | input::Safety::OneError(_)
-            )
-        })
-        .count();
+    let safe = input.safe();
+    let kinda_safe = input.kinda_safe();
 
     parent
         .spawn(Node {
@@ -132,6 +123,7 @@ fn build_visualization(parent: &mut ChildBuilder, input: &input::Input, fonts: &
                     ..Default::default()
                 })
                 .with_children(|parent| {
+                    build_filters(parent, input);
                     let window = parent
                         .spawn((
                             Node {
@@ -143,12 +135,8 @@ fn build_visualization(parent: &mut ChildBuilder, input: &input::Input, fonts: &
                             ScrollWindow,
                         ))
                         .with_children(|parent| {
-                            for report in &input.reports {
-                                let border_color = match report.safety {
-                                    input::Safety::Safe => palettes::basic::GREEN,
-                                    input::Safety::OneError(_) => palettes::basic::YELLOW,
-                                    input::Safety::Unsafe => palettes::basic::RED,
-                                };
+                            for (index, report) in input.reports.iter().enumerate() {
+                                let border_color = SafetyFilter::of(&report.safety).color();
                                 parent
                                     .spawn((
                                         Node {
@@ -158,8 +146,9 @@ fn build_visualization(parent: &mut ChildBuilder, input: &input::Input, fonts: &
                                             padding: UiRect::all(Val::Px(3.)),
                                             ..Default::default()
                                         },
-                                        BorderColor(border_color.into()),
+                                        BorderColor(border_color),
                                         BorderRadius::all(Val::Px(5.)),
+                                        ReportRow(index),
                                     ))
                                     .with_children(|parent| {
                                         let unsafe_val =
@@ -192,5 +181,15 @@ fn build_visualization(parent: &mut ChildBuilder, input: &input::Input, fonts: &
                         fonts.symbol1.clone(),
                     );
                 });
+
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(5.),
+                    margin: UiRect::left(Val::Px(10.)),
+                    ..Default::default()
+                },
+                DetailPanel,
+            ));
         });
 }
@@ -1,11 +1,16 @@
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
+
+/// Longest operand of a `mul` instruction, longer runs of digits corrupt it
+const MAX_OPERAND_DIGITS: u32 = 3;
 
 #[derive(Debug, Resource)]
 pub struct Input {
     pub input: String,
     pub segments: Vec<Segment>,
+    /// State of the parser after each byte, which are all ASCII characters
+    pub states: Vec<StateMachine>,
 }
 
 #[derive(Debug, Default)]
@@ -13,27 +18,66 @@ pub struct Segment {
     pub start: usize,
     pub len: usize,
     pub mul: u32,
+    /// Whether the segment is a valid `mul` instruction, whose product may be 0
+    pub is_mul: bool,
     pub enabled: bool,
 }
 
+impl Segment {
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input_data = String::from_utf8(input.0.clone()).unwrap();
-        let segments = Self::collect_all_mul(&input_data, false);
-        Self {
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let input_data = input.text()?;
+        // The segments and the walkthrough index the memory by byte
+        if let Some((line, column)) = input.lines().find_map(|line| {
+            let column = line.bytes.iter().position(|c| !c.is_ascii())?;
+            Some((line, column))
+        }) {
+            return Err(line.error(column, "expected ASCII characters"));
+        }
+        let (segments, states) = Self::collect_all_mul(&input_data, false);
+        Ok(Self {
             input: input_data,
             segments,
-        }
+            states,
+        })
+    }
+
+    pub fn sum_of_muls(&self) -> u32 {
+        self.segments.iter().map(|segment| segment.mul).sum()
+    }
+
+    pub fn sum_of_enabled_muls(&self) -> u32 {
+        self.segments
+            .iter()
+            .filter_map(|segment| {
+                if segment.enabled {
+                    Some(segment.mul)
+                } else {
+                    None
+                }
+            })
+            .sum()
     }
 
-    fn collect_all_mul(string: &str, disable_conditionals: bool) -> Vec<Segment> {
-        let mut chars = string.chars();
+    fn collect_all_mul(
+        string: &str,
+        disable_conditionals: bool,
+    ) -> (Vec<Segment>, Vec<StateMachine>) {
+        let bytes = string.bytes();
         let mut muls = Vec::new();
+        let mut states = Vec::new();
 
         let mut state_machine = StateMachine::Start;
 
         let mut lhs = 0;
         let mut rhs = 0;
+        // Digits of the operand being read
+        let mut digits = 0;
 
         let mut enabled = true;
 
@@ -42,15 +86,11 @@ impl Input {
             ..Default::default()
         };
 
-        loop {
-            let Some(c) = chars.next() else {
-                break;
-            };
-
+        for c in bytes {
             segment.len += 1;
 
             state_machine = match (state_machine, c) {
-                (StateMachine::Start, 'm') => {
+                (StateMachine::Start, b'm') => {
                     // 'm' is not included
                     segment.len -= 1;
                     let new_segment = std::mem::take(&mut segment);
@@ -59,6 +99,7 @@ impl Input {
                         start: new_segment.start + new_segment.len,
                         len: 1,
                         mul: 0,
+                        is_mul: false,
                         enabled,
                     };
 
@@ -66,28 +107,42 @@ impl Input {
 
                     StateMachine::ReadM
                 }
-                (StateMachine::ReadM, 'u') => StateMachine::ReadU,
-                (StateMachine::ReadU, 'l') => StateMachine::ReadL,
-                (StateMachine::ReadL, '(') => StateMachine::ReadLParen(0),
-                (StateMachine::ReadLParen(0) | StateMachine::ReadingDigits1, '0'..='9') => {
+                (StateMachine::ReadM, b'u') => StateMachine::ReadU,
+                (StateMachine::ReadU, b'l') => StateMachine::ReadL,
+                (StateMachine::ReadL, b'(') => {
+                    digits = 0;
+                    StateMachine::ReadLParen(0)
+                }
+                (StateMachine::ReadLParen(0) | StateMachine::ReadingDigits1, b'0'..=b'9')
+                    if digits < MAX_OPERAND_DIGITS =>
+                {
+                    digits += 1;
                     lhs *= 10;
-                    lhs += c.to_digit(10).unwrap();
+                    lhs += u32::from(c - b'0');
                     StateMachine::ReadingDigits1
                 }
-                (StateMachine::ReadingDigits1, ',') => StateMachine::ReadComma,
-                (StateMachine::ReadComma | StateMachine::ReadingDigits2, '0'..='9') => {
+                (StateMachine::ReadingDigits1, b',') => {
+                    digits = 0;
+                    StateMachine::ReadComma
+                }
+                (StateMachine::ReadComma | StateMachine::ReadingDigits2, b'0'..=b'9')
+                    if digits < MAX_OPERAND_DIGITS =>
+                {
+                    digits += 1;
                     rhs *= 10;
-                    rhs += c.to_digit(10).unwrap();
+                    rhs += u32::from(c - b'0');
                     StateMachine::ReadingDigits2
                 }
-                (StateMachine::ReadingDigits2, ')') => {
+                (StateMachine::ReadingDigits2, b')') => {
                     let mut new_segment = std::mem::take(&mut segment);
                     new_segment.mul = lhs * rhs;
+                    new_segment.is_mul = true;
 
                     segment = Segment {
                         start: new_segment.start + new_segment.len,
                         len: 0,
                         mul: 0,
+                        is_mul: false,
                         enabled,
                     };
 
@@ -97,7 +152,7 @@ impl Input {
                     rhs = 0;
                     StateMachine::Start
                 }
-                (StateMachine::Start, 'd') => {
+                (StateMachine::Start, b'd') => {
                     // 'd' is not included
                     segment.len -= 1;
                     let new_segment = std::mem::take(&mut segment);
@@ -106,18 +161,21 @@ impl Input {
                         start: new_segment.start + new_segment.len,
                         len: 1,
                         mul: 0,
+                        is_mul: false,
                         enabled,
                     };
 
+                    muls.push(new_segment);
+
                     StateMachine::ReadD
                 }
-                (StateMachine::ReadD, 'o') => StateMachine::ReadO,
-                (StateMachine::ReadO, '(') => StateMachine::ReadLParen(1),
-                (StateMachine::ReadO, 'n') => StateMachine::ReadN,
-                (StateMachine::ReadN, '\'') => StateMachine::ReadQuote,
-                (StateMachine::ReadQuote, 't') => StateMachine::ReadT,
-                (StateMachine::ReadT, '(') => StateMachine::ReadLParen(2),
-                (StateMachine::ReadLParen(1), ')') => {
+                (StateMachine::ReadD, b'o') => StateMachine::ReadO,
+                (StateMachine::ReadO, b'(') => StateMachine::ReadLParen(1),
+                (StateMachine::ReadO, b'n') => StateMachine::ReadN,
+                (StateMachine::ReadN, b'\'') => StateMachine::ReadQuote,
+                (StateMachine::ReadQuote, b't') => StateMachine::ReadT,
+                (StateMachine::ReadT, b'(') => StateMachine::ReadLParen(2),
+                (StateMachine::ReadLParen(1), b')') => {
                     let mut new_segment = std::mem::take(&mut segment);
                     new_segment.enabled = true;
 
@@ -126,6 +184,7 @@ impl Input {
                         start: new_segment.start + new_segment.len,
                         len: 0,
                         mul: 0,
+                        is_mul: false,
                         enabled,
                     };
 
@@ -133,7 +192,7 @@ impl Input {
 
                     StateMachine::Start
                 }
-                (StateMachine::ReadLParen(2), ')') => {
+                (StateMachine::ReadLParen(2), b')') => {
                     let new_segment = std::mem::take(&mut segment);
 
                     enabled = disable_conditionals;
@@ -141,6 +200,7 @@ impl Input {
                         start: new_segment.start + new_segment.len,
                         len: 0,
                         mul: 0,
+                        is_mul: false,
                         enabled,
                     };
 
@@ -160,6 +220,7 @@ impl Input {
                         start: new_segment.start + new_segment.len,
                         len: 0,
                         mul: 0,
+                        is_mul: false,
                         enabled,
                     };
 
@@ -170,13 +231,18 @@ impl Input {
                     StateMachine::Start
                 }
             };
+            states.push(state_machine);
+        }
+        if segment.len > 0 {
+            muls.push(segment);
         }
 
-        muls
+        (muls, states)
     }
 }
 
-enum StateMachine {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateMachine {
     Start,
     ReadM,
     ReadU,
@@ -191,3 +257,26 @@ enum StateMachine {
     ReadQuote,
     ReadT,
 }
+
+impl StateMachine {
+    /// Part of an instruction the parser has read so far
+    pub fn pattern(self) -> &'static str {
+        match self {
+            Self::Start => "",
+            Self::ReadM => "m",
+            Self::ReadU => "mu",
+            Self::ReadL => "mul",
+            Self::ReadLParen(0) => "mul(",
+            Self::ReadLParen(1) => "do(",
+            Self::ReadLParen(_) => "don't(",
+            Self::ReadingDigits1 => "mul(a",
+            Self::ReadComma => "mul(a,",
+            Self::ReadingDigits2 => "mul(a,b",
+            Self::ReadD => "d",
+            Self::ReadO => "do",
+            Self::ReadN => "don",
+            Self::ReadQuote => "don'",
+            Self::ReadT => "don't",
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use bevy::{
+    app::Update,
+    color::{palettes, Color},
+    prelude::{
+        in_state, resource_exists_and_changed, BuildChildren, Changed, ChildBuild, ChildBuilder,
+        Commands, Component, DespawnRecursiveExt, Entity, Has, IntoSystemConfigs, Query, Res, Text,
+        With,
+    },
+    text::{TextColor, TextFont},
+    ui::{BackgroundColor, FlexDirection, FlexWrap, Interaction, Node, Overflow, UiRect, Val},
+};
+
+use crate::{
+    scenes::{resources::FontHandles, states::VisualizationState},
+    scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
+};
+
+use super::{
+    input::{Input, Segment},
+    walkthrough::ParserWalkthrough,
+};
+
+const SCROLL_SPEED: f32 = 512.;
+const CORRUPTED_COLOR: Color = Color::Srgba(palettes::basic::GRAY);
+const MUL_COLOR: Color = Color::srgb(0.5, 1.0, 0.5);
+const CONDITIONAL_COLOR: Color = Color::srgb(0.4, 0.8, 1.0);
+/// Background of the memory between a `don't()` and the next `do()`
+const DISABLED_BACKGROUND_COLOR: Color = Color::srgb(0.3, 0.1, 0.15);
+/// Background of the character read by the last step
+const CURSOR_COLOR: Color = Color::srgb(1.0, 0.4, 1.0);
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            Update,
+            (
+                update_cursor.run_if(resource_exists_and_changed::<ParserWalkthrough>),
+                hover_instruction,
+            )
+                .run_if(in_state(VisualizationState::<2024, 3>::Ready)),
+        );
+    }
+}
+
+/// Span of the memory, index in `Input::segments`
+#[derive(Debug, Component)]
+struct MemorySegment(usize);
+
+/// Segment holding the cursor
+#[derive(Debug, Component)]
+struct Cursor;
+
+/// Valid `mul` instruction, with the total once it is counted
+#[derive(Debug, Component)]
+struct Instruction {
+    segment: usize,
+    counted: bool,
+    running_total: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+enum Label {
+    Parser,
+    Total,
+    Hovered,
+}
+
+const HOVER_HINT: &str = "Hover an instruction to see its product";
+
+fn segment_text<'a>(input: &'a Input, segment: &Segment) -> &'a str {
+    &input.input[segment.start..segment.end()]
+}
+
+fn segment_color(input: &Input, segment: &Segment) -> Color {
+    if segment.is_mul {
+        MUL_COLOR
+    } else if matches!(segment_text(input, segment), "do()" | "don't()") {
+        CONDITIONAL_COLOR
+    } else {
+        CORRUPTED_COLOR
+    }
+}
+
+/// Memory with its instructions highlighted, shading the disabled regions when
+/// `enabled_only` is set
+pub fn build_memory(
+    parent: &mut ChildBuilder,
+    input: &Input,
+    enabled_only: bool,
+    fonts: &FontHandles,
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(20.),
+            margin: UiRect::vertical(Val::Px(5.)),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (label, text) in [
+                (Label::Parser, parser_text(input, 0)),
+                (Label::Total, total_text(0)),
+                (Label::Hovered, String::from(HOVER_HINT)),
+            ] {
+                parent.spawn((Text::new(text), TextColor(Color::WHITE), label));
+            }
+        });
+
+    let font = TextFont {
+        font: fonts.font.clone(),
+        ..Default::default()
+    };
+    let window = parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                overflow: Overflow::scroll_y(),
+                ..Default::default()
+            },
+            ScrollWindow,
+        ))
+        .with_children(|parent| {
+            let mut running_total = 0;
+            for (index, segment) in input.segments.iter().enumerate() {
+                if segment.len == 0 {
+                    continue;
+                }
+                let background = if enabled_only && !segment.enabled {
+                    DISABLED_BACKGROUND_COLOR
+                } else {
+                    Color::NONE
+                };
+                let mut entity = parent.spawn((
+                    Node::default(),
+                    BackgroundColor(background),
+                    MemorySegment(index),
+                ));
+                if segment.is_mul {
+                    let counted = segment.enabled || !enabled_only;
+                    if counted {
+                        running_total += segment.mul;
+                    }
+                    entity.insert((
+                        Interaction::default(),
+                        Instruction {
+                            segment: index,
+                            counted,
+                            running_total,
+                        },
+                    ));
+                }
+                entity.with_children(|parent| {
+                    build_segment_text(parent, input, segment, None, &font);
+                });
+            }
+        })
+        .id();
+
+    build_vertical_scroll_buttons(
+        parent,
+        window,
+        SCROLL_SPEED,
+        BUTTON_BACKGROUND_COLOR,
+        fonts.symbol1.clone(),
+    );
+}
+
+/// Text of a segment, splitting out the character at `cursor` when it is in the segment
+fn build_segment_text(
+    parent: &mut ChildBuilder,
+    input: &Input,
+    segment: &Segment,
+    cursor: Option<usize>,
+    font: &TextFont,
+) {
+    let text = segment_text(input, segment).replace("\n", " ");
+    let color = TextColor(segment_color(input, segment));
+    let Some(cursor) = cursor.map(|cursor| cursor - segment.start) else {
+        parent.spawn((Text::new(text), color, font.clone()));
+        return;
+    };
+
+    let (before, after) = text.split_at(cursor);
+    let (current, after) = after.split_at(1);
+    if !before.is_empty() {
+        parent.spawn((Text::new(before), color, font.clone()));
+    }
+    parent
+        .spawn((Node::default(), BackgroundColor(CURSOR_COLOR)))
+        .with_child((Text::new(current), TextColor(Color::BLACK), font.clone()));
+    if !after.is_empty() {
+        parent.spawn((Text::new(after), color, font.clone()));
+    }
+}
+
+fn parser_text(input: &Input, position: usize) -> String {
+    format!("Read {position} of {} characters", input.states.len())
+}
+
+fn total_text(total: u32) -> String {
+    format!("Running total: {total}")
+}
+
+fn update_cursor(
+    mut commands: Commands,
+    segments: Query<(Entity, &MemorySegment, Has<Cursor>)>,
+    mut labels: Query<(&mut Text, &Label)>,
+    walkthrough: Res<ParserWalkthrough>,
+    input: Res<Input>,
+    fonts: Res<FontHandles>,
+) {
+    let cursor = walkthrough.cursor();
+    let font = TextFont {
+        font: fonts.font.clone(),
+        ..Default::default()
+    };
+    for (entity, segment_index, has_cursor) in segments.iter() {
+        let segment = &input.segments[segment_index.0];
+        let contains_cursor =
+            cursor.is_some_and(|cursor| (segment.start..segment.end()).contains(&cursor));
+        if !contains_cursor && !has_cursor {
+            continue;
+        }
+        let mut entity = commands.entity(entity);
+        if contains_cursor {
+            entity.insert(Cursor);
+        } else {
+            entity.remove::<Cursor>();
+        }
+        entity.despawn_descendants().with_children(|parent| {
+            build_segment_text(
+                parent,
+                &input,
+                segment,
+                cursor.filter(|_| contains_cursor),
+                &font,
+            );
+        });
+    }
+
+    for (mut text, label) in labels.iter_mut() {
+        match label {
+            Label::Parser => {
+                let pattern = walkthrough.state(&input).pattern();
+                text.0 = parser_text(&input, walkthrough.position);
+                if !pattern.is_empty() {
+                    text.0.push_str(&format!(", matched {pattern}"));
+                }
+            }
+            Label::Total => text.0 = total_text(walkthrough.total),
+            Label::Hovered => (),
+        }
+    }
+}
+
+fn hover_instruction(
+    changed: Query<(), (With<Instruction>, Changed<Interaction>)>,
+    instructions: Query<(&Interaction, &Instruction)>,
+    mut labels: Query<(&mut Text, &Label)>,
+    input: Res<Input>,
+) {
+    if changed.is_empty() {
+        return;
+    }
+    let hovered = instructions
+        .iter()
+        .find(|(interaction, _)| **interaction != Interaction::None)
+        .map(|(_, instruction)| instruction);
+
+    let text = match hovered {
+        Some(instruction) => {
+            let segment = &input.segments[instruction.segment];
+            let status = if instruction.counted {
+                ""
+            } else {
+                ", disabled"
+            };
+            format!(
+                "{} = {}{status}, running total {}",
+                segment_text(&input, segment),
+                segment.mul,
+                instruction.running_total
+            )
+        }
+        None => String::from(HOVER_HINT),
+    };
+    for (mut label_text, label) in labels.iter_mut() {
+        if *label == Label::Hovered {
+            label_text.0.clone_from(&text);
+        }
+    }
+}
@@ -1,88 +1,162 @@
 mod input;
+mod memory;
 mod part1;
 mod part2;
+mod walkthrough;
 
 use bevy::{
     app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
+    prelude::{in_state, resource_exists, Commands, Condition, IntoSystemConfigs, OnExit},
 };
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, PuzzleSizes, RawInput};
 
-use input::Input;
+use self::{input::Input, walkthrough::ParserWalkthrough};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    playback,
+    states::{Part, Scene, VisualizationState},
 };
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin));
-
-        app.add_computed_state::<VisualizationState<3>>();
-
-        app.add_systems(OnEnter(Scene::Day(3)), build_day_3)
-            .add_systems(OnExit(Scene::Day(3)), destroy_day_3)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<3>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(3))),
-            );
+        app.add_plugins((
+            DayPlugin::<2024, 3, Input>::default(),
+            memory::Plugin,
+            part1::Plugin,
+            part2::Plugin,
+        ));
+
+        app.add_systems(
+            Update,
+            playback::resource_timeline_systems::<ParserWalkthrough>().run_if(
+                in_state(VisualizationState::<2024, 3>::Ready)
+                    .and(resource_exists::<ParserWalkthrough>),
+            ),
+        )
+        .add_systems(
+            OnExit(Scene::Day { year: 2024, day: 3 }),
+            |mut commands: Commands| {
+                commands.remove_resource::<ParserWalkthrough>();
+            },
+        );
     }
 }
 
-fn build_day_3(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day3_camera"), Camera2d)).id();
-    let day3_resource = GenericDay {
-        input: asset_server.load("inputs/day3.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day3_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &RawInput,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day3_resource);
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.sum_of_muls().to_string(),
+            Part::Part2 => self.sum_of_enabled_muls().to_string(),
+        }
+    }
 }
 
-fn destroy_day_3(mut commands: Commands, day3_resource: Res<GenericDay>) {
-    commands.entity(day3_resource.camera).despawn_recursive();
-    commands.entity(day3_resource.ui).despawn_recursive();
-
-    commands.remove_resource::<GenericDay>();
+pub fn solve(
+    input: &RawInput,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn process_input(
-    mut commands: Commands,
-    day3_resource: Res<GenericDay>,
-    inputs: Res<Assets<RawInput>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day3_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::{
+            compute::Progress,
+            playback::{Scrubbable, Simulation},
+        },
+    };
+
+    use super::walkthrough::ParserWalkthrough;
+
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day3.txt").to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["161", "48"]
+        );
+    }
+
+    #[test]
+    fn walkthrough() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day3.txt").to_vec());
+        let input = super::Input::parse(&input).unwrap();
+
+        assert_eq!(input.states.len(), input.input.len());
+        let mut end = 0;
+        for segment in &input.segments {
+            assert_eq!(segment.start, end);
+            end = segment.end();
+        }
+        assert_eq!(end, input.input.len());
+
+        let mut all = ParserWalkthrough::new(&input, false);
+        while all.step() {}
+        assert_eq!(all.total, 161);
+
+        let mut enabled = ParserWalkthrough::new(&input, true);
+        while enabled.step() {}
+        assert_eq!(enabled.total, 48);
+
+        // "xmul(2,4)"
+        assert!(enabled.seek(7));
+        assert_eq!(enabled.state(&input).pattern(), "mul(a,");
+        assert!(enabled.step() && enabled.step());
+        assert_eq!(enabled.total, 8);
+        assert!(enabled.step_back());
+        assert_eq!(enabled.total, 0);
+    }
+
+    #[test]
+    fn zero_product() {
+        let input = RawInput(b"mul(0,5)mul(2,3)".to_vec());
+        let input = super::Input::parse(&input).unwrap();
+
+        let instructions: Vec<_> = input
+            .segments
+            .iter()
+            .filter(|segment| segment.is_mul)
+            .map(|segment| (segment.start, segment.mul))
+            .collect();
+        assert_eq!(instructions, [(0, 0), (8, 6)]);
+    }
+
+    #[test]
+    fn long_operands() {
+        let input = RawInput(b"mul(99999999999,1)mul(1,1234)mul(999,999)".to_vec());
+        let input = super::Input::parse(&input).unwrap();
+
+        let products: Vec<_> = input
+            .segments
+            .iter()
+            .filter(|segment| segment.is_mul)
+            .map(|segment| segment.mul)
+            .collect();
+        assert_eq!(products, [998001]);
+    }
+
+    #[test]
+    fn non_ascii() {
+        let input = RawInput("mul(1,2)\nxé mul(3,4)".as_bytes().to_vec());
+        let error = super::Input::parse(&input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
     }
 }
@@ -1,26 +1,22 @@
 use bevy::{
     app::Update,
-    color::{palettes, Color},
+    color::Color,
     prelude::{
         in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, DespawnRecursiveExt,
         IntoSystemConfigs, NextState, Res, ResMut, Text,
     },
-    text::{TextColor, TextFont},
-    ui::{BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType, UiRect, Val},
+    text::TextColor,
+    ui::{BorderColor, BorderRadius, FlexDirection, Node, PositionType, UiRect, Val},
 };
 
-use crate::{
-    scenes::{
-        days::{build_content, build_header},
-        resources::{FontHandles, GenericDay},
-        states::{Part, UiState, VisualizationState},
-    },
-    scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
+use crate::scenes::{
+    days::{build_content, build_footer, build_header},
+    playback::{build_playback_controls, build_timeline},
+    resources::{FontHandles, GenericDay},
+    states::{Part, UiState, VisualizationState},
 };
 
-use super::input::Input;
-
-const SCROLL_SPEED: f32 = 512.;
+use super::{input::Input, memory::build_memory, walkthrough::ParserWalkthrough};
 
 pub struct Plugin;
 
@@ -30,7 +26,7 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part1))
-                .run_if(in_state(VisualizationState::<3>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 3>::WaitingUi)),
         );
     }
 }
@@ -43,27 +39,34 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 3 Part 1");
-    let header = build_header(&mut commands, "day3", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day3", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day3");
+    let footer = build_footer(&mut commands, "day3");
+
+    commands.insert_resource(ParserWalkthrough::new(&input, false));
 
     commands
         .entity(content)
         .with_children(|parent| build_visualization(parent, &input, &fonts));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<ParserWalkthrough>(parent, &fonts);
+        build_timeline::<ParserWalkthrough>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day1_resource.ui)
         .despawn_descendants()
-        .add_children(&[header, content]);
+        .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
 }
 
 fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
-    let res: u32 = input.segments.iter().map(|segment| segment.mul).sum();
+    let res = input.sum_of_muls();
     parent
         .spawn(Node {
             top: Val::Px(50.),
-            bottom: Val::Px(10.),
+            bottom: Val::Px(60.),
             left: Val::Px(10.),
             right: Val::Px(10.),
             flex_direction: FlexDirection::Column,
@@ -97,45 +100,6 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                         .with_child((Text::new(res.to_string()), TextColor(Color::WHITE)));
                 });
 
-            let window = parent
-                .spawn((
-                    Node {
-                        flex_direction: FlexDirection::Row,
-                        flex_wrap: bevy::ui::FlexWrap::Wrap,
-                        overflow: Overflow::scroll_y(),
-                        ..Default::default()
-                    },
-                    ScrollWindow,
-                ))
-                .with_children(|parent| {
-                    for segment in input.segments.iter() {
-                        let color = if segment.mul != 0 {
-                            Color::WHITE
-                        } else {
-                            palettes::basic::GRAY.into()
-                        };
-
-                        let segment_text =
-                            &input.input[segment.start..(segment.start + segment.len)];
-
-                        parent.spawn(Node::default()).with_child((
-                            Text::new(segment_text.replace("\n", " ")),
-                            TextColor(color),
-                            TextFont {
-                                font: fonts.font.clone(),
-                                ..Default::default()
-                            },
-                        ));
-                    }
-                })
-                .id();
-
-            build_vertical_scroll_buttons(
-                parent,
-                window,
-                SCROLL_SPEED,
-                BUTTON_BACKGROUND_COLOR,
-                fonts.symbol1.clone(),
-            );
+            build_memory(parent, input, false, fonts);
         });
 }
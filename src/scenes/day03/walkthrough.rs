@@ -0,0 +1,127 @@
+use bevy::prelude::Resource;
+
+use crate::scenes::playback::{Scrubbable, Simulation};
+
+use super::input::{Input, StateMachine};
+
+/// Time the whole walkthrough takes at 1x speed, in seconds
+const WALKTHROUGH_DURATION: f32 = 30.;
+const MIN_STEPS_PER_SECOND: f32 = 4.;
+
+/// Feeds the memory to the parser one character per step, adding up the products of the
+/// instructions as they are closed
+#[derive(Debug, Resource)]
+pub struct ParserWalkthrough {
+    /// Characters read so far
+    pub position: usize,
+    length: usize,
+    /// End of each counted instruction with its product, in order
+    products: Vec<(usize, u32)>,
+    /// Instructions counted so far
+    counted: usize,
+    pub total: u32,
+}
+
+impl ParserWalkthrough {
+    /// Counts only the enabled instructions when `enabled_only` is set
+    pub fn new(input: &Input, enabled_only: bool) -> Self {
+        Self {
+            position: 0,
+            length: input.states.len(),
+            products: input
+                .segments
+                .iter()
+                .filter(|segment| segment.is_mul && (segment.enabled || !enabled_only))
+                .map(|segment| (segment.end(), segment.mul))
+                .collect(),
+            counted: 0,
+            total: 0,
+        }
+    }
+
+    /// Index of the character read by the last step
+    pub fn cursor(&self) -> Option<usize> {
+        self.position.checked_sub(1)
+    }
+
+    pub fn state(&self, input: &Input) -> StateMachine {
+        self.cursor()
+            .map_or(StateMachine::Start, |cursor| input.states[cursor])
+    }
+}
+
+impl Simulation for ParserWalkthrough {
+    const REVERSIBLE: bool = true;
+
+    fn steps_per_second(&self) -> f32 {
+        (self.length as f32 / WALKTHROUGH_DURATION).max(MIN_STEPS_PER_SECOND)
+    }
+
+    fn step(&mut self) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        self.position += 1;
+        if let Some(&(end, mul)) = self.products.get(self.counted) {
+            if end == self.position {
+                self.total += mul;
+                self.counted += 1;
+            }
+        }
+        true
+    }
+
+    fn step_back(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        if let Some(&(end, mul)) = self.counted.checked_sub(1).map(|i| &self.products[i]) {
+            if end == self.position {
+                self.total -= mul;
+                self.counted -= 1;
+            }
+        }
+        self.position -= 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.position = 0;
+        self.counted = 0;
+        self.total = 0;
+    }
+}
+
+impl Scrubbable for ParserWalkthrough {
+    type Snapshot = (usize, usize, u32);
+
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.position, self.counted, self.total)
+    }
+
+    fn restore(&mut self, &(position, counted, total): &Self::Snapshot) {
+        self.position = position;
+        self.counted = counted;
+        self.total = total;
+    }
+
+    fn seek(&mut self, position: usize) -> bool {
+        self.position = position.min(self.length);
+        self.counted = self
+            .products
+            .partition_point(|(end, _)| *end <= self.position);
+        self.total = self.products[..self.counted]
+            .iter()
+            .map(|(_, mul)| mul)
+            .sum();
+        true
+    }
+}
@@ -2,7 +2,10 @@ use std::collections::BTreeSet;
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    scenes::states::Part,
+};
 
 const XMAS: &[u8] = b"MAS";
 const MAS: &[u8] = b"AS";
@@ -17,14 +20,38 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input.split(|c| *c == b'\n').collect::<Vec<_>>();
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let input = input.grid(|tile| b"XMAS".contains(&tile))?;
 
-        let mut positions_xmas = BTreeSet::new();
-        let mut positions_x_mas = BTreeSet::new();
+        Ok(Self {
+            lines: input.into_iter().map(Vec::from).collect(),
+            positions_xmas: BTreeSet::new(),
+            positions_x_mas: BTreeSet::new(),
+            result_part1: 0,
+            result_part2: 0,
+        })
+    }
 
+    /// Answer of `part`, marking the letters of the words it found
+    pub fn solve(&mut self, part: Part) -> u32 {
+        match part {
+            Part::Part1 => {
+                self.find_xmas();
+                self.result_part1
+            }
+            Part::Part2 => {
+                self.find_x_mas();
+                self.result_part2
+            }
+        }
+    }
+
+    /// `XMAS` in any of the eight directions
+    fn find_xmas(&mut self) {
+        let input = self.lines.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        let mut positions_xmas = BTreeSet::new();
         let mut result_part1 = 0;
-        let mut result_part2 = 0;
 
         for (i, line) in input.iter().enumerate() {
             for (j, c) in line.iter().enumerate() {
@@ -81,7 +108,24 @@ impl Input {
                         ]);
                         result_part1 += 1;
                     }
-                } else if *c == b'M' {
+                }
+            }
+        }
+
+        self.positions_xmas = positions_xmas;
+        self.result_part1 = result_part1;
+    }
+
+    /// Two `MAS` crossing on their `A`
+    fn find_x_mas(&mut self) {
+        let input = self.lines.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        let mut positions_x_mas = BTreeSet::new();
+        let mut result_part2 = 0;
+
+        for (i, line) in input.iter().enumerate() {
+            for (j, c) in line.iter().enumerate() {
+                if *c == b'M' {
                     if line.get(j + 2).filter(|c| **c == b'M').is_some() {
                         if Self::test_ne(input.as_slice(), (j, i), MAS)
                             && Self::test_nw(input.as_slice(), (j + 2, i), MAS)
@@ -143,13 +187,8 @@ impl Input {
             }
         }
 
-        Self {
-            lines: input.into_iter().map(Vec::from).collect(),
-            positions_xmas,
-            positions_x_mas,
-            result_part1,
-            result_part2,
-        }
+        self.positions_x_mas = positions_x_mas;
+        self.result_part2 = result_part2;
     }
 
     fn test_left(input: &[&[u8]], position: (usize, usize), rest: &[u8]) -> bool {
@@ -30,7 +30,7 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part1))
-                .run_if(in_state(VisualizationState::<4>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 4>::WaitingUi)),
         );
     }
 }
@@ -43,7 +43,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 4 Part 1");
-    let header = build_header(&mut commands, "day4", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day4", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day4");
 
     commands
@@ -30,7 +30,7 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part2))
-                .run_if(in_state(VisualizationState::<4>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 4>::WaitingUi)),
         );
     }
 }
@@ -43,8 +43,8 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 1 Part 2");
-    let header = build_header(&mut commands, "day1", true, fonts.font.clone());
-    let content = build_content(&mut commands, "day1");
+    let header = build_header(&mut commands, "day4", true, false, fonts.font.clone());
+    let content = build_content(&mut commands, "day4");
 
     commands
         .entity(content)
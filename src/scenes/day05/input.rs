@@ -2,7 +2,7 @@ use std::{cmp::Ordering, collections::BTreeMap};
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
 
 #[derive(Debug, Resource)]
 pub struct Input {
@@ -10,25 +10,21 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
         let mut rules = BTreeMap::new();
         let mut manuals = Vec::new();
 
-        let mut lines = input.0.split(|c| *c == b'\n');
+        let mut lines = input.lines();
 
         for line in &mut lines {
             if line.is_empty() {
                 break;
             }
 
-            let (l, r) = line.split_at(2);
+            let (l, r) = line.split_field(line.bytes, b"|")?;
 
-            let l = String::from_utf8_lossy(l)
-                .parse()
-                .expect("Left value should be valid number");
-            let r = String::from_utf8_lossy(&r[1..])
-                .parse()
-                .expect("Right value should be valid number");
+            let l = line.number(l)?;
+            let r = line.number(r)?;
 
             rules
                 .entry(l)
@@ -45,10 +41,8 @@ impl Input {
 
             let mut pages = line
                 .split(|c| *c == b',')
-                .map(String::from_utf8_lossy)
-                .map(|val| val.parse())
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap();
+                .map(|val| line.number(val))
+                .collect::<Result<Vec<_>, _>>()?;
 
             let sorted = pages.is_sorted_by(Self::comparator(&rules));
 
@@ -59,21 +53,29 @@ impl Input {
             manuals.push(Manual { sorted, pages });
         }
 
-        Self { manuals }
+        Ok(Self { manuals })
+    }
+
+    pub fn sum_of_middle_pages(&self, sorted: bool) -> u32 {
+        self.manuals
+            .iter()
+            .filter(|manual| manual.sorted == sorted)
+            .map(|manual| manual.pages[manual.pages.len() / 2])
+            .sum()
     }
 
     fn comparator(map: &BTreeMap<u32, Vec<u32>>) -> impl FnMut(&u32, &u32) -> bool + use<'_> {
-        |l, r| map.get(l).expect("Page must exist.").contains(r)
+        |l, r| map.get(l).is_some_and(|after| after.contains(r))
     }
 
     fn sorter(map: &BTreeMap<u32, Vec<u32>>) -> impl FnMut(&u32, &u32) -> Ordering + use<'_> {
         |l, r| {
-            if map.get(l).expect("Page must exist.").contains(r) {
+            if map.get(l).is_some_and(|after| after.contains(r)) {
                 Ordering::Less
-            } else if map.get(r).expect("Page must exist.").contains(l) {
+            } else if map.get(r).is_some_and(|after| after.contains(l)) {
                 Ordering::Greater
             } else {
-                unreachable!("Every pair should exists.");
+                Ordering::Equal
             }
         }
     }
@@ -84,3 +86,32 @@ pub struct Manual {
     pub sorted: bool,
     pub pages: Vec<u32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Input;
+
+    #[test]
+    fn sorter() {
+        let example = include_str!("../../../assets/examples/2024/day5.txt");
+        let mut rules = BTreeMap::<u32, Vec<u32>>::new();
+        for rule in example.lines().take_while(|line| !line.is_empty()) {
+            let (l, r) = rule.split_once('|').unwrap();
+            rules
+                .entry(l.parse().unwrap())
+                .or_default()
+                .push(r.parse().unwrap());
+        }
+
+        for (mut pages, sorted) in [
+            (vec![75, 97, 47, 61, 53], vec![97, 75, 47, 61, 53]),
+            (vec![61, 13, 29], vec![61, 29, 13]),
+            (vec![97, 13, 75, 29, 47], vec![97, 75, 47, 29, 13]),
+        ] {
+            pages.sort_by(Input::sorter(&rules));
+            assert_eq!(pages, sorted);
+        }
+    }
+}
@@ -30,7 +30,7 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part1))
-                .run_if(in_state(VisualizationState::<5>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 5>::WaitingUi)),
         );
     }
 }
@@ -43,7 +43,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 5 Part 1");
-    let header = build_header(&mut commands, "day5", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day5", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day5");
 
     commands
@@ -64,10 +64,7 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
         .iter()
         .filter(|manual| manual.sorted)
         .collect::<Vec<_>>();
-    let result = sorted_manuals
-        .iter()
-        .map(|manual| manual.pages[manual.pages.len() / 2])
-        .sum::<u32>();
+    let result = input.sum_of_middle_pages(true);
 
     parent
         .spawn(Node {
@@ -2,51 +2,70 @@ use std::collections::BTreeSet;
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    scenes::compute::Progress,
+};
+
+/// The guard starts facing up
+const DIRECTION: (isize, isize) = (0, -1);
 
 #[derive(Debug, Resource)]
 pub struct Input {
     pub lines: Vec<Vec<u8>>,
+    start: (usize, usize),
     pub paths: BTreeSet<(usize, usize)>,
     pub possible_obstacles: BTreeSet<(usize, usize)>,
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input
-            .split(|c| *c == b'\n')
-            .filter(|line| !line.is_empty())
-            .collect::<Vec<_>>();
+    /// The guard has to leave the map, so parsing walks its path once
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let input = input.grid(|tile| b".#^".contains(&tile))?;
 
-        let starting_position = input
+        let Some(starting_position) = input
             .iter()
             .enumerate()
             .find_map(|(j, line)| line.iter().position(|c| *c == b'^').map(|i| (i, j)))
-            .unwrap();
-        let direction = (0isize, -1isize);
+        else {
+            return Err(ParseError::new(1, 1, "missing guard `^`"));
+        };
+
+        let Some(paths) = Self::get_path(input.as_slice(), starting_position, DIRECTION, None)
+        else {
+            return Err(ParseError::new(
+                starting_position.1 + 1,
+                starting_position.0 + 1,
+                "guard never leaves the map",
+            ));
+        };
+
+        Ok(Self {
+            lines: input.into_iter().map(Vec::from).collect(),
+            start: starting_position,
+            paths,
+            possible_obstacles: BTreeSet::new(),
+        })
+    }
 
-        let paths = Self::get_path(input.as_slice(), starting_position, direction, None).unwrap();
+    /// Obstacles on the path that trap the guard in a loop
+    pub fn solve_obstacles(&mut self, progress: &Progress) -> usize {
+        let input = self.lines.iter().map(Vec::as_slice).collect::<Vec<_>>();
 
-        let possible_obstacles = paths
+        self.possible_obstacles = self
+            .paths
             .iter()
             .copied()
-            .filter(|obstacle| obstacle != &starting_position)
+            .enumerate()
+            .take_while(|_| !progress.is_cancelled())
+            .inspect(|(i, _)| progress.report(*i, self.paths.len()))
+            .map(|(_, obstacle)| obstacle)
+            .filter(|obstacle| obstacle != &self.start)
             .filter(|obstacle| {
-                Self::get_path(
-                    input.as_slice(),
-                    starting_position,
-                    direction,
-                    Some(*obstacle),
-                )
-                .is_none()
+                Self::get_path(input.as_slice(), self.start, DIRECTION, Some(*obstacle)).is_none()
             })
             .collect();
-
-        Self {
-            lines: input.into_iter().map(Vec::from).collect(),
-            paths,
-            possible_obstacles,
-        }
+        self.possible_obstacles.len()
     }
 
     fn get_path(
@@ -60,14 +79,10 @@ impl Input {
         let mut position = starting_position;
         let mut direction = direction;
 
-        loop {
-            let (Some(x), Some(y)) = (
-                position.0.checked_add_signed(direction.0),
-                position.1.checked_add_signed(direction.1),
-            ) else {
-                break;
-            };
-
+        while let (Some(x), Some(y)) = (
+            position.0.checked_add_signed(direction.0),
+            position.1.checked_add_signed(direction.1),
+        ) {
             if x >= input[0].len() || y >= input.len() {
                 break;
             }
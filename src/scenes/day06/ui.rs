@@ -18,19 +18,18 @@ use crate::{
         resources::{FontHandles, GenericDay},
         states::{UiState, VisualizationState},
     },
-    scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
+    scroll_controls::PanZoom,
 };
 
 use super::input::Input;
 
-const SCROLL_SPEED: f32 = 512.;
 const STARTING_COLOR: Srgba = palettes::tailwind::GREEN_950;
 const GROUND_COLOR: Srgba = palettes::tailwind::YELLOW_100;
 const PATH_COLOR: Srgba = palettes::tailwind::GREEN_100;
 const BOX_COLOR: Srgba = palettes::tailwind::RED_700;
 const POSSIBLE_BOX_COLOR: Srgba = palettes::tailwind::RED_700;
-const TILE_DIMENSION: f32 = 12.;
-const BOX_DIMENSION: f32 = 8.;
+/// Size of a box relative to its tile
+const BOX_SIZE: Val = Val::Percent(200. / 3.);
 
 pub struct Plugin;
 
@@ -38,7 +37,7 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui.run_if(in_state(VisualizationState::<6>::WaitingUi)),
+            build_ui.run_if(in_state(VisualizationState::<2024, 6>::WaitingUi)),
         );
     }
 }
@@ -51,12 +50,12 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 6");
-    let header = build_header(&mut commands, "day6", false, fonts.font.clone());
+    let header = build_header(&mut commands, "day6", false, false, fonts.font.clone());
     let content = build_content(&mut commands, "day6");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input, &fonts));
+        .with_children(|parent| build_visualization(parent, &input));
     commands
         .entity(day6_resource.ui)
         .despawn_descendants()
@@ -65,7 +64,7 @@ fn build_ui(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
+fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
     parent
         .spawn(Node {
             top: Val::Px(50.),
@@ -127,78 +126,80 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                         ));
                 });
 
-            let window = parent
+            let (columns, rows) = (input.lines[0].len(), input.lines.len());
+            parent
                 .spawn((
                     Node {
-                        flex_direction: FlexDirection::Column,
-                        overflow: Overflow::scroll_y(),
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
                         ..Default::default()
                     },
-                    ScrollWindow,
+                    PanZoom::default(),
                 ))
                 .with_children(|parent| {
-                    for (y, line) in input.lines.iter().enumerate() {
-                        parent
-                            .spawn(Node {
-                                flex_direction: FlexDirection::Row,
-                                aspect_ratio: Some(1.),
-                                ..Default::default()
-                            })
-                            .with_children(|parent| {
-                                for (x, c) in line.iter().enumerate() {
-                                    let tile_color = if *c == b'^' {
-                                        STARTING_COLOR
-                                    } else if input.paths.contains(&(x, y)) {
-                                        PATH_COLOR
-                                    } else {
-                                        GROUND_COLOR
-                                    };
-
-                                    let mut tile = parent.spawn((
-                                        Node {
-                                            width: Val::Px(TILE_DIMENSION),
-                                            height: Val::Px(TILE_DIMENSION),
-                                            aspect_ratio: Some(1.),
-                                            ..Default::default()
-                                        },
-                                        BackgroundColor(tile_color.into()),
-                                    ));
+                    parent
+                        .spawn(Node {
+                            height: Val::Percent(100.),
+                            flex_direction: FlexDirection::Column,
+                            flex_shrink: 0.,
+                            aspect_ratio: Some(columns as f32 / rows as f32),
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            for (y, line) in input.lines.iter().enumerate() {
+                                parent
+                                    .spawn(Node {
+                                        height: Val::Percent(100. / rows as f32),
+                                        flex_direction: FlexDirection::Row,
+                                        ..Default::default()
+                                    })
+                                    .with_children(|parent| {
+                                        for (x, c) in line.iter().enumerate() {
+                                            let tile_color = if *c == b'^' {
+                                                STARTING_COLOR
+                                            } else if input.paths.contains(&(x, y)) {
+                                                PATH_COLOR
+                                            } else {
+                                                GROUND_COLOR
+                                            };
 
-                                    if *c == b'#' {
-                                        tile.with_child((
-                                            Node {
-                                                width: Val::Px(BOX_DIMENSION),
-                                                height: Val::Px(BOX_DIMENSION),
-                                                aspect_ratio: Some(1.),
-                                                ..Default::default()
-                                            },
-                                            BackgroundColor(BOX_COLOR.into()),
-                                        ));
-                                    } else if input.possible_obstacles.contains(&(x, y)) {
-                                        tile.with_child((
-                                            Node {
-                                                width: Val::Px(BOX_DIMENSION),
-                                                height: Val::Px(BOX_DIMENSION),
-                                                aspect_ratio: Some(1.),
-                                                ..Default::default()
-                                            },
-                                            BackgroundColor(
-                                                POSSIBLE_BOX_COLOR.with_alpha(0.25).into(),
-                                            ),
-                                        ));
-                                    }
-                                }
-                            });
-                    }
-                })
-                .id();
+                                            let mut tile = parent.spawn((
+                                                Node {
+                                                    width: Val::Percent(100. / columns as f32),
+                                                    height: Val::Percent(100.),
+                                                    ..Default::default()
+                                                },
+                                                BackgroundColor(tile_color.into()),
+                                            ));
 
-            build_vertical_scroll_buttons(
-                parent,
-                window,
-                SCROLL_SPEED,
-                BUTTON_BACKGROUND_COLOR,
-                fonts.symbol1.clone(),
-            );
+                                            if *c == b'#' {
+                                                tile.with_child((
+                                                    Node {
+                                                        width: BOX_SIZE,
+                                                        height: BOX_SIZE,
+                                                        ..Default::default()
+                                                    },
+                                                    BackgroundColor(BOX_COLOR.into()),
+                                                ));
+                                            } else if input.possible_obstacles.contains(&(x, y)) {
+                                                tile.with_child((
+                                                    Node {
+                                                        width: BOX_SIZE,
+                                                        height: BOX_SIZE,
+                                                        ..Default::default()
+                                                    },
+                                                    BackgroundColor(
+                                                        POSSIBLE_BOX_COLOR.with_alpha(0.25).into(),
+                                                    ),
+                                                ));
+                                            }
+                                        }
+                                    });
+                            }
+                        });
+                });
         });
 }
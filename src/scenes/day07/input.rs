@@ -1,6 +1,13 @@
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
+
+const TWO_OPS: [Operator; 2] = [("+", std::ops::Add::add), ("*", std::ops::Mul::mul)];
+const THREE_OPS: [Operator; 3] = [
+    ("+", std::ops::Add::add),
+    ("*", std::ops::Mul::mul),
+    ("||", concatenate_u64),
+];
 
 #[derive(Debug, Resource)]
 pub struct Input {
@@ -10,61 +17,69 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input
-            .split(|c| *c == b'\n')
-            .filter(|line| !line.is_empty())
-            .collect::<Vec<_>>();
-
-        let mut two_ops = 0;
-        let mut three_ops = 0;
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let input = input.lines().filter(|line| !line.is_empty());
 
         let mut operations = vec![];
 
         for line in input {
-            let line_split = line.split(|c| *c == b':').collect::<Vec<_>>();
-            let [l, r] = line_split.as_slice() else {
-                unreachable!("There should be 1 ':'.");
-            };
+            let (l, r) = line.split_field(line.bytes, b":")?;
 
-            let result = String::from_utf8_lossy(l).parse().unwrap();
+            let result = line.number(l)?;
             let operands: Vec<u64> = r
                 .split(|c| *c == b' ')
                 .filter(|val| !val.is_empty())
-                .map(|val| String::from_utf8_lossy(val).parse().unwrap())
-                .collect();
-            let operators = Self::is_valid_operation(
-                result,
-                operands.as_slice(),
-                &[
-                    ("+", std::ops::Add::add),
-                    ("*", std::ops::Mul::mul),
-                    ("||", concatenate_u64),
-                ],
-            )
-            .into_iter()
-            .map(|(operator, _)| operator)
-            .collect::<Vec<_>>();
-
-            if !operators.is_empty() {
-                three_ops += result;
-                if !operators.contains(&"||") {
-                    two_ops += result;
-                }
+                .map(|val| line.number(val))
+                .collect::<Result<_, _>>()?;
+            if operands.is_empty() {
+                return Err(line.error(line.len(), "expected at least one operand"));
             }
 
             operations.push(Operation {
                 result,
                 operands,
-                operators,
+                operators: vec![],
             });
         }
 
-        Self {
-            two_ops,
-            three_ops,
+        Ok(Self {
+            two_ops: 0,
+            three_ops: 0,
             operations,
+        })
+    }
+
+    /// Sum of the results the operands can produce with `+` and `*`
+    pub fn solve_two_ops(&mut self) -> u64 {
+        self.two_ops = self
+            .operations
+            .iter()
+            .filter(|operation| {
+                !Self::is_valid_operation(operation.result, &operation.operands, &TWO_OPS)
+                    .is_empty()
+            })
+            .map(|operation| operation.result)
+            .sum();
+        self.two_ops
+    }
+
+    /// Sum of the results the operands can produce with `+`, `*` and `||`, keeping the
+    /// operators of each operation
+    pub fn solve_three_ops(&mut self) -> u64 {
+        for operation in self.operations.iter_mut() {
+            operation.operators =
+                Self::is_valid_operation(operation.result, &operation.operands, &THREE_OPS)
+                    .into_iter()
+                    .map(|(operator, _)| operator)
+                    .collect();
         }
+        self.three_ops = self
+            .operations
+            .iter()
+            .filter(|operation| !operation.operators.is_empty())
+            .map(|operation| operation.result)
+            .sum();
+        self.three_ops
     }
 
     fn is_valid_operation(result: u64, operands: &[u64], operators: &[Operator]) -> Vec<Operator> {
@@ -135,6 +150,34 @@ impl Iterator for Permutations<'_> {
 }
 
 fn concatenate_u64(lhs: u64, rhs: u64) -> u64 {
-    let rhs_log = rhs.ilog10();
+    let rhs_log = rhs.checked_ilog10().unwrap_or(0);
     lhs * 10u64.pow(rhs_log + 1) + rhs
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Input, Operator, THREE_OPS, TWO_OPS};
+
+    fn operators(result: u64, operands: &[u64], operators: &[Operator]) -> Vec<&'static str> {
+        Input::is_valid_operation(result, operands, operators)
+            .into_iter()
+            .map(|(operator, _)| operator)
+            .collect()
+    }
+
+    #[test]
+    fn is_valid_operation() {
+        assert_eq!(operators(190, &[10, 19], &TWO_OPS), ["*"]);
+        assert_eq!(operators(3267, &[81, 40, 27], &TWO_OPS).len(), 2);
+        assert_eq!(operators(292, &[11, 6, 16, 20], &TWO_OPS), ["+", "*", "+"]);
+        assert!(operators(83, &[17, 5], &TWO_OPS).is_empty());
+        assert!(operators(7290, &[6, 8, 6, 15], &TWO_OPS).is_empty());
+
+        assert_eq!(operators(156, &[15, 6], &THREE_OPS), ["||"]);
+        assert_eq!(
+            operators(7290, &[6, 8, 6, 15], &THREE_OPS),
+            ["*", "||", "*"]
+        );
+        assert!(operators(161011, &[16, 10, 13], &THREE_OPS).is_empty());
+    }
+}
@@ -10,10 +10,12 @@ use bevy::{
 };
 
 use crate::{
+    loader::Answers,
     scenes::{
+        answers::build_answer_badge,
         days::{build_content, build_header},
         resources::{FontHandles, GenericDay},
-        states::{UiState, VisualizationState},
+        states::{Part, UiState, VisualizationState},
     },
     scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
 };
@@ -28,7 +30,7 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui.run_if(in_state(VisualizationState::<7>::WaitingUi)),
+            build_ui.run_if(in_state(VisualizationState::<2024, 7>::WaitingUi)),
         );
     }
 }
@@ -39,14 +41,15 @@ fn build_ui(
     input: Res<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 7");
-    let header = build_header(&mut commands, "day7", false, fonts.font.clone());
+    let header = build_header(&mut commands, "day7", false, false, fonts.font.clone());
     let content = build_content(&mut commands, "day7");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input, &fonts));
+        .with_children(|parent| build_visualization(parent, &input, &answers, &fonts));
     commands
         .entity(day7_resource.ui)
         .despawn_descendants()
@@ -55,7 +58,12 @@ fn build_ui(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
+fn build_visualization(
+    parent: &mut ChildBuilder,
+    input: &Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
     parent
         .spawn(Node {
             top: Val::Px(50.),
@@ -94,10 +102,19 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child((
-                            Text::new(input.two_ops.to_string()),
-                            TextColor(Color::WHITE),
-                        ));
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new(input.two_ops.to_string()),
+                                TextColor(Color::WHITE),
+                            ));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (7, Part::Part1),
+                                &input.two_ops.to_string(),
+                                fonts.symbol2.clone(),
+                            );
+                        });
                     parent
                         .spawn(Node {
                             padding: UiRect::all(Val::Px(3.)),
@@ -117,10 +134,19 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child((
-                            Text::new(input.three_ops.to_string()),
-                            TextColor(Color::WHITE),
-                        ));
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new(input.three_ops.to_string()),
+                                TextColor(Color::WHITE),
+                            ));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (7, Part::Part2),
+                                &input.three_ops.to_string(),
+                                fonts.symbol2.clone(),
+                            );
+                        });
                 });
 
             let window = parent
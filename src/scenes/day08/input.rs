@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use bevy::{color::Color, prelude::Resource};
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
 
 pub type Coord = (usize, usize);
 
@@ -14,11 +14,8 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input
-            .split(|c| *c == b'\n')
-            .filter(|line| !line.is_empty())
-            .collect::<Vec<_>>();
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let input = input.grid(|tile| tile == b'.' || tile.is_ascii_alphanumeric())?;
 
         let bounds = (input[0].len(), input.len());
 
@@ -73,11 +70,35 @@ impl Input {
             }
         }
 
-        Self {
+        Ok(Self {
             bounds,
             antennas,
             slopes,
-        }
+        })
+    }
+
+    pub fn antinodes(&self) -> BTreeMap<Coord, Color> {
+        self.slopes
+            .iter()
+            .flat_map(|slope| {
+                [
+                    slope.interpolate(-1).map(|node| (node, slope.color)),
+                    slope.interpolate(2).map(|node| (node, slope.color)),
+                ]
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn resonant_antinodes(&self) -> BTreeMap<Coord, Color> {
+        self.slopes
+            .iter()
+            .flat_map(|slope| {
+                (-isize::try_from(self.bounds.0).unwrap()..isize::try_from(self.bounds.0).unwrap())
+                    .map(|i| slope.interpolate(i).map(|node| (node, slope.color)))
+            })
+            .flatten()
+            .collect()
     }
 }
 
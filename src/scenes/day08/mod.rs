@@ -2,86 +2,67 @@ mod input;
 mod part1;
 mod part2;
 
-use bevy::{
-    app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
-};
 use input::Input;
 
-use crate::loader::RawInput as InputAsset;
+use crate::loader::{ParseError, PuzzleSizes, RawInput as InputAsset};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    states::Part,
 };
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin));
+        app.add_plugins((
+            DayPlugin::<2024, 8, Input>::default(),
+            part1::Plugin,
+            part2::Plugin,
+        ));
+    }
+}
 
-        app.add_computed_state::<VisualizationState<8>>();
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &InputAsset,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
 
-        app.add_systems(OnEnter(Scene::Day(8)), build_day_8)
-            .add_systems(OnExit(Scene::Day(8)), destroy_day_8)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<8>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(8))),
-            );
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.antinodes().len().to_string(),
+            Part::Part2 => self.resonant_antinodes().len().to_string(),
+        }
     }
 }
 
-fn build_day_8(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day8_camera"), Camera2d)).id();
-    let day8_resource = GenericDay {
-        input: asset_server.load("inputs/day8.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day8_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
-
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day8_resource);
+pub fn solve(
+    input: &InputAsset,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn destroy_day_8(mut commands: Commands, day8_resource: Res<GenericDay>) {
-    commands.entity(day8_resource.camera).despawn_recursive();
-    commands.entity(day8_resource.ui).despawn_recursive();
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
 
-    commands.remove_resource::<GenericDay>();
-}
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day8.txt").to_vec());
 
-fn process_input(
-    mut commands: Commands,
-    day8_resource: Res<GenericDay>,
-    inputs: Res<Assets<InputAsset>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day8_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["14", "34"]
+        );
     }
 }
@@ -1,5 +1,3 @@
-use std::collections::BTreeMap;
-
 use bevy::{
     app::Update,
     color::{palettes, Color, Luminance},
@@ -20,13 +18,11 @@ use crate::{
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
-    scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
+    scroll_controls::PanZoom,
 };
 
 use super::input::Input;
 
-const SCROLL_SPEED: f32 = 512.;
-
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
@@ -35,7 +31,7 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part1))
-                .run_if(in_state(VisualizationState::<8>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 8>::WaitingUi)),
         );
     }
 }
@@ -48,12 +44,12 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 8 Part 1");
-    let header = build_header(&mut commands, "day8", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day8", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day8");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input, &fonts));
+        .with_children(|parent| build_visualization(parent, &input));
     commands
         .entity(day8_resource.ui)
         .despawn_descendants()
@@ -62,18 +58,8 @@ fn build_ui(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
-    let nodes = input
-        .slopes
-        .iter()
-        .flat_map(|slope| {
-            [
-                slope.interpolate(-1).map(|node| (node, slope.color)),
-                slope.interpolate(2).map(|node| (node, slope.color)),
-            ]
-        })
-        .flatten()
-        .collect::<BTreeMap<_, _>>();
+fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
+    let nodes = input.antinodes();
 
     parent
         .spawn(Node {
@@ -113,66 +99,75 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                         .with_child((Text::new(nodes.len().to_string()), TextColor(Color::WHITE)));
                 });
 
-            let window = parent
+            let (columns, rows) = input.bounds;
+            parent
                 .spawn((
                     Node {
-                        flex_direction: FlexDirection::Column,
-                        row_gap: Val::Px(1.),
-                        overflow: Overflow::scroll_y(),
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
                         ..Default::default()
                     },
-                    ScrollWindow,
+                    PanZoom::default(),
                 ))
                 .with_children(|parent| {
-                    for y in 0..input.bounds.1 {
-                        parent
-                            .spawn(Node {
-                                flex_direction: FlexDirection::Row,
-                                aspect_ratio: Some(1.),
-                                column_gap: Val::Px(1.),
-                                ..Default::default()
-                            })
-                            .with_children(|parent| {
-                                for x in 0..input.bounds.0 {
-                                    let color =
-                                        input.antennas.get(&(x, y)).copied().unwrap_or_else(|| {
-                                            palettes::tailwind::YELLOW_200.into()
-                                        });
-
-                                    let mut tile = parent.spawn((
-                                        Node {
-                                            width: Val::Px(16.),
-                                            height: Val::Px(16.),
-                                            flex_direction: FlexDirection::Column,
-                                            justify_content: bevy::ui::JustifyContent::SpaceEvenly,
-                                            ..Default::default()
-                                        },
-                                        BackgroundColor(color),
-                                    ));
+                    parent
+                        .spawn(Node {
+                            height: Val::Percent(100.),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(1.),
+                            flex_shrink: 0.,
+                            aspect_ratio: Some(columns as f32 / rows as f32),
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            for y in 0..input.bounds.1 {
+                                parent
+                                    .spawn(Node {
+                                        height: Val::Percent(100. / rows as f32),
+                                        flex_direction: FlexDirection::Row,
+                                        column_gap: Val::Px(1.),
+                                        ..Default::default()
+                                    })
+                                    .with_children(|parent| {
+                                        for x in 0..input.bounds.0 {
+                                            let color = input
+                                                .antennas
+                                                .get(&(x, y))
+                                                .copied()
+                                                .unwrap_or_else(|| {
+                                                    palettes::tailwind::YELLOW_200.into()
+                                                });
 
-                                    if let Some(node_color) = nodes.get(&(x, y)) {
-                                        tile.with_child((
-                                            Node {
-                                                width: Val::Px(8.),
-                                                height: Val::Px(8.),
-                                                align_self: bevy::ui::AlignSelf::Center,
-                                                ..Default::default()
-                                            },
-                                            BackgroundColor(node_color.lighter(0.125)),
-                                        ));
-                                    }
-                                }
-                            });
-                    }
-                })
-                .id();
+                                            let mut tile = parent.spawn((
+                                                Node {
+                                                    width: Val::Percent(100. / columns as f32),
+                                                    height: Val::Percent(100.),
+                                                    flex_direction: FlexDirection::Column,
+                                                    justify_content:
+                                                        bevy::ui::JustifyContent::SpaceEvenly,
+                                                    ..Default::default()
+                                                },
+                                                BackgroundColor(color),
+                                            ));
 
-            build_vertical_scroll_buttons(
-                parent,
-                window,
-                SCROLL_SPEED,
-                BUTTON_BACKGROUND_COLOR,
-                fonts.symbol1.clone(),
-            );
+                                            if let Some(node_color) = nodes.get(&(x, y)) {
+                                                tile.with_child((
+                                                    Node {
+                                                        width: Val::Percent(50.),
+                                                        height: Val::Percent(50.),
+                                                        align_self: bevy::ui::AlignSelf::Center,
+                                                        ..Default::default()
+                                                    },
+                                                    BackgroundColor(node_color.lighter(0.125)),
+                                                ));
+                                            }
+                                        }
+                                    });
+                            }
+                        });
+                });
         });
 }
@@ -1,18 +1,24 @@
 use bevy::{color::Color, prelude::Resource};
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    scenes::playback::{Scrubbable, Simulation},
+};
 
 #[derive(Debug, Resource)]
 pub struct Input {
     pub start: Vec<Block>,
     pub disk: Vec<Block>,
     pub pos: usize,
+    pub whole_files: bool,
     last_moved: u64,
+    steps: usize,
+    length: usize,
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let line = input.split(|c| *c == b'\n').next().unwrap();
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let line = input.grid(|block_size| block_size.is_ascii_digit())?[0];
 
         let mut unique = 0;
 
@@ -45,20 +51,32 @@ impl Input {
             })
             .collect::<Vec<_>>();
 
-        let blocks_len = blocks.len();
-
-        Self {
+        let mut input = Self {
             start: blocks.clone(),
             disk: blocks,
-            pos: blocks_len,
-            last_moved: unique + 1,
-        }
+            pos: 0,
+            whole_files: false,
+            last_moved: 0,
+            steps: 0,
+            length: 0,
+        };
+        input.reset();
+
+        Ok(input)
     }
 
-    pub fn reset(&mut self) {
-        self.disk = self.start.clone();
-        self.pos = self.disk.len();
-        self.last_moved = self.disk[self.disk.len() - 1].0 + 1;
+    /// Checksum of the compacted disk, moving whole files when `whole_files` is set. The disk is
+    /// left as it was before
+    pub fn solve(&mut self, whole_files: bool) -> u64 {
+        let previous = self.whole_files;
+        self.whole_files = whole_files;
+        self.reset();
+        while self.step() {}
+        let checksum = self.calculate_checksum();
+
+        self.whole_files = previous;
+        self.reset();
+        checksum
     }
 
     pub fn calculate_checksum(&self) -> u64 {
@@ -146,3 +164,83 @@ impl Input {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Block(pub u64, pub Color);
+
+impl Simulation for Input {
+    fn steps_per_second(&self) -> f32 {
+        if self.whole_files {
+            960.
+        } else {
+            3840.
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.pos == 0 {
+            return false;
+        }
+        if self.whole_files {
+            self.defrag_multi();
+        } else {
+            self.defrag_single();
+        }
+        self.steps += 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.disk = self.start.clone();
+        self.pos = self.disk.len();
+        self.last_moved = self.disk[self.disk.len() - 1].0 + 1;
+        self.steps = 0;
+
+        let files = self
+            .start
+            .iter()
+            .map(|block| block.0)
+            .max()
+            .unwrap_or_default();
+        self.length = if self.whole_files {
+            // Every file is visited once, and files that moved are visited again
+            // when the scan reaches their new place
+            usize::try_from(files).unwrap() * 2
+        } else {
+            // Every block past the size of the defragmented disk takes a step,
+            // plus the step that finds there is nothing left to move
+            let used = self.start.iter().filter(|block| block.0 != 0).count();
+            self.start[used..]
+                .iter()
+                .filter(|block| block.0 != 0)
+                .count()
+                + 1
+        };
+    }
+}
+
+impl Scrubbable for Input {
+    type Snapshot = (Vec<Block>, usize, u64, usize);
+
+    const SNAPSHOT_INTERVAL: usize = 2048;
+
+    fn position(&self) -> usize {
+        self.steps
+    }
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn is_finished(&self) -> bool {
+        self.pos == 0
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.disk.clone(), self.pos, self.last_moved, self.steps)
+    }
+
+    fn restore(&mut self, (disk, pos, last_moved, steps): &Self::Snapshot) {
+        self.disk.clone_from(disk);
+        self.pos = *pos;
+        self.last_moved = *last_moved;
+        self.steps = *steps;
+    }
+}
@@ -1,88 +1,76 @@
-mod controls;
 mod input;
 mod part1;
 mod part2;
 
 use bevy::{
     app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
+    prelude::{in_state, IntoSystemConfigs},
 };
 use input::Input;
 
-use crate::loader::RawInput as InputAsset;
+use crate::loader::{ParseError, PuzzleSizes, RawInput as InputAsset};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    playback,
+    states::{Part, VisualizationState},
 };
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin, controls::Plugin));
+        app.add_plugins((
+            DayPlugin::<2024, 9, Input>::default(),
+            part1::Plugin,
+            part2::Plugin,
+        ));
 
-        app.add_computed_state::<VisualizationState<9>>();
-
-        app.add_systems(OnEnter(Scene::Day(9)), build_day_9)
-            .add_systems(OnExit(Scene::Day(9)), destroy_day_9)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<9>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(9))),
-            );
+        app.add_systems(
+            Update,
+            playback::resource_timeline_systems::<Input>()
+                .run_if(in_state(VisualizationState::<2024, 9>::Ready)),
+        );
     }
 }
 
-fn build_day_9(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day9_camera"), Camera2d)).id();
-    let day9_resource = GenericDay {
-        input: asset_server.load("inputs/day9.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day9_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &InputAsset,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
+
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        self.solve(part == Part::Part2).to_string()
+    }
+}
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day9_resource);
+pub fn solve(
+    input: &InputAsset,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn destroy_day_9(mut commands: Commands, day9_resource: Res<GenericDay>) {
-    commands.entity(day9_resource.camera).despawn_recursive();
-    commands.entity(day9_resource.ui).despawn_recursive();
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
 
-    commands.remove_resource::<GenericDay>();
-}
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day9.txt").to_vec());
 
-fn process_input(
-    mut commands: Commands,
-    day9_resource: Res<GenericDay>,
-    inputs: Res<Assets<InputAsset>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day9_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["1928", "2858"]
+        );
     }
 }
@@ -3,7 +3,7 @@ use bevy::{
     color::Color,
     prelude::{
         in_state, resource_changed, BuildChildren, ChildBuild, ChildBuilder, Commands, Condition,
-        DespawnRecursiveExt, IntoSystemConfigs, NextState, Res, ResMut, Text,
+        DespawnRecursiveExt, DetectChangesMut, IntoSystemConfigs, NextState, Res, ResMut, Text,
     },
     text::TextColor,
     ui::{
@@ -14,15 +14,15 @@ use bevy::{
 
 use crate::{
     scenes::{
-        day09::controls::build_control,
         days::{build_content, build_footer, build_header},
+        playback::{build_playback_controls, build_timeline, Simulation},
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
     scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
 };
 
-use super::{controls::ControlState, input::Input};
+use super::input::Input;
 
 const SCROLL_SPEED: f32 = 512.;
 const BLOCK_SIZE: f32 = 4.;
@@ -35,19 +35,12 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui.run_if(
                 in_state(Part::Part2)
-                    .and(in_state(VisualizationState::<9>::WaitingUi))
+                    .and(in_state(VisualizationState::<2024, 9>::WaitingUi))
                     .or(in_state(Part::Part2).and(
-                        in_state(VisualizationState::<9>::Ready).and(resource_changed::<Input>),
+                        in_state(VisualizationState::<2024, 9>::Ready)
+                            .and(resource_changed::<Input>),
                     )),
             ),
-        )
-        .add_systems(
-            Update,
-            run_refrag.run_if(
-                in_state(Part::Part2)
-                    .and(in_state(VisualizationState::<9>::Ready))
-                    .and(in_state(ControlState::Playing)),
-            ),
         );
     }
 }
@@ -55,21 +48,28 @@ impl bevy::app::Plugin for Plugin {
 fn build_ui(
     mut commands: Commands,
     day9_resource: Res<GenericDay>,
-    input: Res<Input>,
+    mut input: ResMut<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
 ) {
+    if !input.whole_files {
+        let input = input.bypass_change_detection();
+        input.whole_files = true;
+        input.reset();
+    }
+
     bevy::log::trace!("Day 9 Part 2");
-    let header = build_header(&mut commands, "day9", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day9", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day9");
     let footer = build_footer(&mut commands, "day9");
 
     commands
         .entity(content)
         .with_children(|parent| build_visualization(parent, &input, &fonts));
-    commands
-        .entity(footer)
-        .with_children(|parent| build_control(parent, fonts.symbol2.clone()));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<Input>(parent, &fonts);
+        build_timeline::<Input>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day9_resource.ui)
@@ -156,12 +156,3 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
             );
         });
 }
-
-pub fn run_refrag(mut input: ResMut<Input>, mut next_state: ResMut<NextState<ControlState>>) {
-    for _ in 0..16 {
-        if input.pos == 0 {
-            next_state.set(ControlState::Paused);
-        }
-        input.defrag_multi();
-    }
-}
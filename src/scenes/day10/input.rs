@@ -1,8 +1,8 @@
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
 
 type Coord = (usize, usize);
 
@@ -13,8 +13,8 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input.split(|c| *c == b'\n').filter(|line| !line.is_empty());
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let input = input.grid(|tile| tile.is_ascii_digit())?;
 
         let tiles = input
             .into_iter()
@@ -65,6 +65,26 @@ impl Input {
             .iter()
             .all(|trails_from_head| trails_from_head.1.iter().all(|trail| trail.len() == 9)));
 
-        Self { tiles, trails }
+        Ok(Self { tiles, trails })
+    }
+
+    pub fn score(&self) -> usize {
+        self.trails
+            .values()
+            .map(|trails_from_head| {
+                trails_from_head
+                    .iter()
+                    .map(|trail| trail[8])
+                    .collect::<BTreeSet<_>>()
+                    .len()
+            })
+            .sum()
+    }
+
+    pub fn rating(&self) -> usize {
+        self.trails
+            .iter()
+            .map(|trails_from_head| trails_from_head.1.len())
+            .sum()
     }
 }
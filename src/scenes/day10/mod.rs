@@ -1,4 +1,4 @@
-mod components;
+pub(super) mod components;
 mod input;
 mod part1;
 mod part2;
@@ -6,108 +6,57 @@ mod resources;
 
 use bevy::{
     app::Update,
-    asset::{AssetServer, Assets},
     color::Color,
-    core::Name,
     prelude::{
-        apply_deferred, in_state, resource_exists_and_changed, resource_removed, AppExtStates,
-        BuildChildren, Camera2d, Changed, ClearColor, Commands, Condition, DespawnRecursiveExt,
-        Entity, IntoSystemConfigs, NextState, OnEnter, OnExit, Query, Res, ResMut, Text, With,
+        apply_deferred, in_state, resource_exists_and_changed, resource_removed, BuildChildren,
+        Changed, Commands, Condition, DespawnRecursiveExt, Entity, IntoSystemConfigs, Query, Res,
+        Text, With,
     },
     text::{TextColor, TextFont},
-    ui::{FlexDirection, Interaction, Node, TargetCamera, Val},
+    ui::{Interaction, Node},
 };
 use components::{PartOfTrail, Start};
 use input::Input;
 use resources::HoveredTile;
 
-use crate::loader::RawInput as InputAsset;
+use crate::loader::{ParseError, PuzzleSizes, RawInput as InputAsset};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    states::{Part, VisualizationState},
 };
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin));
-
-        app.add_computed_state::<VisualizationState<10>>();
-
-        app.add_systems(OnEnter(Scene::Day(10)), build_day_10)
-            .add_systems(OnExit(Scene::Day(10)), destroy_day_10)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<10>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(10))),
-            )
-            .add_systems(
-                Update,
-                (hovered_start, apply_deferred)
-                    .chain()
-                    .run_if(in_state(VisualizationState::<10>::Ready)),
-            )
-            .add_systems(
-                Update,
-                clear_trails.after(hovered_start).run_if(
-                    in_state(VisualizationState::<10>::Ready).and(resource_removed::<HoveredTile>),
-                ),
-            )
-            .add_systems(
-                Update,
-                update_trails.after(clear_trails).run_if(
-                    in_state(VisualizationState::<10>::Ready)
-                        .and(resource_exists_and_changed::<HoveredTile>),
-                ),
-            );
-    }
-}
-
-fn build_day_10(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day10_camera"), Camera2d)).id();
-    let day10_resource = GenericDay {
-        input: asset_server.load("inputs/day10.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day10_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
-
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day10_resource);
-}
-
-fn destroy_day_10(mut commands: Commands, day10_resource: Res<GenericDay>) {
-    commands.entity(day10_resource.camera).despawn_recursive();
-    commands.entity(day10_resource.ui).despawn_recursive();
-
-    commands.remove_resource::<GenericDay>();
-}
-
-fn process_input(
-    mut commands: Commands,
-    day10_resource: Res<GenericDay>,
-    inputs: Res<Assets<InputAsset>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day10_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+        app.add_plugins((
+            DayPlugin::<2024, 10, Input>::default(),
+            part1::Plugin,
+            part2::Plugin,
+        ));
+
+        app.add_systems(
+            Update,
+            (hovered_start, apply_deferred)
+                .chain()
+                .run_if(in_state(VisualizationState::<2024, 10>::Ready)),
+        )
+        .add_systems(
+            Update,
+            clear_trails.after(hovered_start).run_if(
+                in_state(VisualizationState::<2024, 10>::Ready)
+                    .and(resource_removed::<HoveredTile>),
+            ),
+        )
+        .add_systems(
+            Update,
+            update_trails.after(clear_trails).run_if(
+                in_state(VisualizationState::<2024, 10>::Ready)
+                    .and(resource_exists_and_changed::<HoveredTile>),
+            ),
+        );
     }
 }
 
@@ -185,3 +134,46 @@ fn update_trails(
         }
     }
 }
+
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &InputAsset,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
+
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.score().to_string(),
+            Part::Part2 => self.rating().to_string(),
+        }
+    }
+}
+
+pub fn solve(
+    input: &InputAsset,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
+
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day10.txt").to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["36", "81"]
+        );
+    }
+}
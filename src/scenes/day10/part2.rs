@@ -20,7 +20,7 @@ use crate::{
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
-    scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
+    scroll_controls::PanZoom,
 };
 
 use super::{
@@ -28,17 +28,15 @@ use super::{
     input::Input,
 };
 
-const SCROLL_SPEED: f32 = 512.;
-const BLOCK_SIZE: f32 = 16.;
-
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<10>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 10>::WaitingUi)),
+            ),
         );
     }
 }
@@ -51,12 +49,12 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 10 Part 2");
-    let header = build_header(&mut commands, "day10", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day10", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day10");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input, &fonts));
+        .with_children(|parent| build_visualization(parent, &input));
 
     commands
         .entity(day10_resource.ui)
@@ -66,12 +64,8 @@ fn build_ui(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
-    let trails: usize = input
-        .trails
-        .iter()
-        .map(|trails_from_head| trails_from_head.1.len())
-        .sum();
+fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
+    let trails = input.rating();
     let mut part_of_trail = BTreeMap::new();
 
     for (trail_head, trails_from_this_trail_head) in input.trails.iter() {
@@ -129,76 +123,84 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                         .with_child((Text::new(trails.to_string()), TextColor(Color::WHITE)));
                 });
 
-            let window = parent
+            let (columns, rows) = (input.tiles[0].len(), input.tiles.len());
+            parent
                 .spawn((
                     Node {
-                        flex_direction: FlexDirection::Column,
-                        row_gap: Val::Px(1.),
-                        column_gap: Val::Px(1.),
-                        overflow: Overflow::scroll_y(),
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
                         ..Default::default()
                     },
-                    ScrollWindow,
+                    PanZoom::default(),
                 ))
                 .with_children(|parent| {
-                    for (y, line) in input.tiles.iter().enumerate() {
-                        parent
-                            .spawn(Node {
-                                flex_direction: FlexDirection::Row,
-                                column_gap: Val::Px(1.),
-                                ..Default::default()
-                            })
-                            .with_children(|parent| {
-                                for (x, tile) in line.iter().enumerate() {
-                                    let mut start = parent.spawn((
-                                        Node {
-                                            width: Val::Px(BLOCK_SIZE),
-                                            height: Val::Px(BLOCK_SIZE),
-                                            flex_direction: FlexDirection::Row,
-                                            justify_content: JustifyContent::Center,
-                                            ..Default::default()
-                                        },
-                                        BackgroundColor(
-                                            Color::hsv(
-                                                180.,
-                                                0.1 + (0.8 * ((tile + 1) as f32 / 10.)),
-                                                1.,
-                                            )
-                                            .to_linear()
-                                            .into(),
-                                        ),
-                                    ));
-
-                                    if *tile == 0 {
-                                        start.insert(Start((x, y)));
-                                        start.with_child((
-                                            Node {
-                                                align_self: bevy::ui::AlignSelf::Center,
-                                                justify_self: bevy::ui::JustifySelf::Center,
-                                                ..Default::default()
-                                            },
-                                            Text::new("S"),
-                                            TextColor(Color::BLACK),
-                                            TextFont {
-                                                font_size: 12.,
-                                                ..Default::default()
-                                            },
-                                        ));
-                                    } else if let Some(part_of_trail) = part_of_trail.get(&(x, y)) {
-                                        start.insert(part_of_trail.clone());
-                                    }
-                                }
-                            });
-                    }
-                })
-                .id();
-
-            build_vertical_scroll_buttons(
-                parent,
-                window,
-                SCROLL_SPEED,
-                BUTTON_BACKGROUND_COLOR,
-                fonts.symbol1.clone(),
-            );
+                    parent
+                        .spawn(Node {
+                            height: Val::Percent(100.),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(1.),
+                            flex_shrink: 0.,
+                            aspect_ratio: Some(columns as f32 / rows as f32),
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            for (y, line) in input.tiles.iter().enumerate() {
+                                parent
+                                    .spawn(Node {
+                                        height: Val::Percent(100. / rows as f32),
+                                        flex_direction: FlexDirection::Row,
+                                        column_gap: Val::Px(1.),
+                                        ..Default::default()
+                                    })
+                                    .with_children(|parent| {
+                                        for (x, tile) in line.iter().enumerate() {
+                                            let mut start = parent.spawn((
+                                                Node {
+                                                    width: Val::Percent(100. / columns as f32),
+                                                    height: Val::Percent(100.),
+                                                    overflow: Overflow::clip(),
+                                                    flex_direction: FlexDirection::Row,
+                                                    justify_content: JustifyContent::Center,
+                                                    ..Default::default()
+                                                },
+                                                BackgroundColor(
+                                                    Color::hsv(
+                                                        180.,
+                                                        0.1 + (0.8 * ((tile + 1) as f32 / 10.)),
+                                                        1.,
+                                                    )
+                                                    .to_linear()
+                                                    .into(),
+                                                ),
+                                            ));
+
+                                            if *tile == 0 {
+                                                start.insert(Start((x, y)));
+                                                start.with_child((
+                                                    Node {
+                                                        align_self: bevy::ui::AlignSelf::Center,
+                                                        justify_self: bevy::ui::JustifySelf::Center,
+                                                        ..Default::default()
+                                                    },
+                                                    Text::new("S"),
+                                                    TextColor(Color::BLACK),
+                                                    TextFont {
+                                                        font_size: 12.,
+                                                        ..Default::default()
+                                                    },
+                                                ));
+                                            } else if let Some(part_of_trail) =
+                                                part_of_trail.get(&(x, y))
+                                            {
+                                                start.insert(part_of_trail.clone());
+                                            }
+                                        }
+                                    });
+                            }
+                        });
+                });
         });
 }
@@ -2,65 +2,70 @@ use std::collections::BTreeMap;
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    scenes::playback::Simulation,
+};
+
+const BLINKS: usize = 75;
+/// Blinks counted by the first part
+const FIRST_BLINKS: usize = 25;
 
 #[derive(Debug, Resource)]
 pub struct Input {
     pub start: Vec<u64>,
     pub pebbles: BTreeMap<u64, usize>,
+    pub blinks: usize,
     pub twenty_five: usize,
     pub seventy_five: usize,
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let start = input
-            .split(|c| *c == b'\n')
-            .next()
-            .unwrap()
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let Some(line) = input.lines().next().filter(|line| !line.is_empty()) else {
+            return Err(input.unexpected_end());
+        };
+        let start = line
             .split(|c| *c == b' ')
-            .map(|val| String::from_utf8_lossy(val).parse::<u64>())
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap();
+            .map(|val| line.number::<u64>(val))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut input = Self {
             start,
             pebbles: BTreeMap::new(),
+            blinks: 0,
             twenty_five: 0,
             seventy_five: 0,
         };
         input.reset();
 
-        for _ in 0..25 {
-            input.blink();
-        }
-
-        input.twenty_five = input.pebbles.values().sum();
-
-        for _ in 0..50 {
-            input.blink();
-        }
-
-        input.seventy_five = input.pebbles.values().sum();
+        Ok(input)
+    }
 
-        input.reset();
+    /// Pebbles after 25 blinks
+    pub fn solve_twenty_five(&mut self) -> usize {
+        self.twenty_five = self.count_after(FIRST_BLINKS);
+        self.twenty_five
+    }
 
-        input
+    /// Pebbles after 75 blinks
+    pub fn solve_seventy_five(&mut self) -> usize {
+        self.seventy_five = self.count_after(BLINKS);
+        self.seventy_five
     }
 
-    pub fn reset(&mut self) {
-        self.pebbles.clear();
-        for pebble in self.start.iter() {
-            self.pebbles
-                .entry(*pebble)
-                .and_modify(|count| {
-                    *count += 1;
-                })
-                .or_insert(1);
+    /// Pebbles after `blinks`, leaving the row as it was before blinking
+    fn count_after(&mut self, blinks: usize) -> usize {
+        for _ in 0..blinks {
+            self.blink();
         }
+        let count = self.pebbles.values().sum();
+        self.reset();
+        count
     }
 
     pub fn blink(&mut self) {
+        self.blinks += 1;
         let pebbles = std::mem::take(&mut self.pebbles);
         for (pebble, count) in pebbles {
             match pebble {
@@ -92,3 +97,30 @@ impl Input {
         }
     }
 }
+
+impl Simulation for Input {
+    fn steps_per_second(&self) -> f32 {
+        2.
+    }
+
+    fn step(&mut self) -> bool {
+        if self.blinks == BLINKS {
+            return false;
+        }
+        self.blink();
+        true
+    }
+
+    fn reset(&mut self) {
+        self.blinks = 0;
+        self.pebbles.clear();
+        for pebble in self.start.iter() {
+            self.pebbles
+                .entry(*pebble)
+                .and_modify(|count| {
+                    *count += 1;
+                })
+                .or_insert(1);
+        }
+    }
+}
@@ -1,87 +1,74 @@
-mod controls;
 mod input;
 mod ui;
 
 use bevy::{
     app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
+    prelude::{in_state, IntoSystemConfigs},
 };
 use input::Input;
 
-use crate::loader::RawInput as InputAsset;
+use crate::loader::{ParseError, PuzzleSizes, RawInput as InputAsset};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    playback,
+    states::{Part, VisualizationState},
 };
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((ui::Plugin, controls::Plugin));
+        app.add_plugins((DayPlugin::<2024, 11, Input>::default(), ui::Plugin));
 
-        app.add_computed_state::<VisualizationState<11>>();
-
-        app.add_systems(OnEnter(Scene::Day(11)), build_day_11)
-            .add_systems(OnExit(Scene::Day(11)), destroy_day_11)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<11>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(11))),
-            );
+        app.add_systems(
+            Update,
+            playback::resource_systems::<Input>()
+                .run_if(in_state(VisualizationState::<2024, 11>::Ready)),
+        );
     }
 }
 
-fn build_day_11(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day11_camera"), Camera2d)).id();
-    let day11_resource = GenericDay {
-        input: asset_server.load("inputs/day11.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day11_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &InputAsset,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
+
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.solve_twenty_five().to_string(),
+            Part::Part2 => self.solve_seventy_five().to_string(),
+        }
+    }
+}
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day11_resource);
+pub fn solve(
+    input: &InputAsset,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn destroy_day_11(mut commands: Commands, day11_resource: Res<GenericDay>) {
-    commands.entity(day11_resource.camera).despawn_recursive();
-    commands.entity(day11_resource.ui).despawn_recursive();
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
 
-    commands.remove_resource::<GenericDay>();
-}
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day11.txt").to_vec());
 
-fn process_input(
-    mut commands: Commands,
-    day11_resource: Res<GenericDay>,
-    inputs: Res<Assets<InputAsset>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day11_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["55312", "65601038650482"]
+        );
     }
 }
@@ -17,8 +17,8 @@ use bevy::{
 
 use crate::{
     scenes::{
-        day11::controls::build_controls,
         days::{build_content, build_footer, build_header},
+        playback::build_playback_controls,
         resources::{FontHandles, GenericDay},
         states::{UiState, VisualizationState},
     },
@@ -35,12 +35,13 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui.run_if(in_state(VisualizationState::<11>::WaitingUi)),
+            build_ui.run_if(in_state(VisualizationState::<2024, 11>::WaitingUi)),
         )
         .add_systems(
             Update,
-            rebuild_pebbles
-                .run_if(in_state(VisualizationState::<11>::Ready).and(resource_changed::<Input>)),
+            rebuild_pebbles.run_if(
+                in_state(VisualizationState::<2024, 11>::Ready).and(resource_changed::<Input>),
+            ),
         );
     }
 }
@@ -54,14 +55,16 @@ fn build_ui(
     asset_server: Res<AssetServer>,
 ) {
     bevy::log::trace!("Day 11");
-    let header = build_header(&mut commands, "day11", false, fonts.font.clone());
+    let header = build_header(&mut commands, "day11", false, true, fonts.font.clone());
     let content = build_content(&mut commands, "day11");
     let footer = build_footer(&mut commands, "day11");
 
     commands.entity(content).with_children(|parent| {
         build_visualization(parent, &input, asset_server.load("pebble.png"), &fonts)
     });
-    commands.entity(footer).with_children(build_controls);
+    commands
+        .entity(footer)
+        .with_children(|parent| build_playback_controls::<Input>(parent, &fonts));
 
     commands
         .entity(day11_resource.ui)
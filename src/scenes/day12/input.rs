@@ -1,7 +1,7 @@
 use bevy::prelude::Resource;
 
 use crate::{
-    loader::RawInput,
+    loader::{ParseError, RawInput},
     tools::{Convolution, MergeInsert, MergeInsertNode},
 };
 
@@ -13,31 +13,41 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input.split(|c| *c == b'\n').filter(|line| !line.is_empty());
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let input = input.grid(|tile| tile.is_ascii_uppercase())?;
 
         let tiles = input
             .into_iter()
             .map(|line| line.to_vec())
             .collect::<Vec<_>>();
 
-        let slices = tiles.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        Ok(Self {
+            tiles,
+            fences: 0,
+            fence_runs: 0,
+        })
+    }
 
-        let fences = Self::calculate_area_perimeter(slices.as_slice())
+    /// Price of the fences, priced by the perimeter of each region
+    pub fn solve_fences(&mut self) -> u64 {
+        let slices = self.tiles.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+
+        self.fences = Self::calculate_area_perimeter(slices.as_slice())
             .into_iter()
             .map(|(area, perimeter)| area * perimeter)
             .sum();
+        self.fences
+    }
 
-        let fence_runs = Self::calculate_sides(slices.as_slice())
+    /// Price of the fences, priced by the number of sides of each region
+    pub fn solve_fence_runs(&mut self) -> u64 {
+        let slices = self.tiles.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+
+        self.fence_runs = Self::calculate_sides(slices.as_slice())
             .into_iter()
             .map(|(area, perimeter)| area * perimeter)
             .sum();
-
-        Self {
-            tiles,
-            fences,
-            fence_runs,
-        }
+        self.fence_runs
     }
 
     fn calculate_area_perimeter(data: &[&[u8]]) -> Vec<(u64, u64)> {
@@ -359,3 +369,23 @@ impl Input {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Input;
+
+    #[test]
+    fn calculate_sides() {
+        let garden: [&[u8]; 4] = [b"AAAA", b"BBCD", b"BBCC", b"EEEC"];
+        let mut regions = Input::calculate_sides(&garden);
+        regions.sort();
+        assert_eq!(regions, [(1, 4), (3, 4), (4, 4), (4, 4), (4, 8)]);
+
+        let garden: [&[u8]; 5] = [b"EEEEE", b"EXXXX", b"EEEEE", b"EXXXX", b"EEEEE"];
+        let price: u64 = Input::calculate_sides(&garden)
+            .into_iter()
+            .map(|(area, sides)| area * sides)
+            .sum();
+        assert_eq!(price, 236);
+    }
+}
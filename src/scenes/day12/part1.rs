@@ -20,23 +20,21 @@ use crate::{
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
-    scroll_controls::{ui::build_vertical_scroll_buttons, ScrollWindow, BUTTON_BACKGROUND_COLOR},
+    scroll_controls::PanZoom,
     tools::Convolution,
 };
 
 use super::input::Input;
 
-const SCROLL_SPEED: f32 = 512.;
-const BLOCK_SIZE: f32 = 16.;
-
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<12>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 12>::WaitingUi)),
+            ),
         );
     }
 }
@@ -49,12 +47,12 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 12 Part 1");
-    let header = build_header(&mut commands, "day12", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day12", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day12");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input, &fonts));
+        .with_children(|parent| build_visualization(parent, &input));
 
     commands
         .entity(day12_resource.ui)
@@ -64,7 +62,7 @@ fn build_ui(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHandles) {
+fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
     let slices = input.tiles.iter().map(AsRef::as_ref).collect::<Vec<_>>();
     let mut convolution = Convolution::new(slices.as_slice());
     let tile_influence = input
@@ -129,64 +127,71 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input, fonts: &FontHan
                         .with_child((Text::new(input.fences.to_string()), TextColor(Color::WHITE)));
                 });
 
-            let window = parent
+            let (columns, rows) = (input.tiles[0].len(), input.tiles.len());
+            parent
                 .spawn((
                     Node {
-                        flex_direction: FlexDirection::Column,
-                        overflow: Overflow::scroll_y(),
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
                         ..Default::default()
                     },
-                    ScrollWindow,
+                    PanZoom::default(),
                 ))
                 .with_children(|parent| {
-                    for row in tile_influence {
-                        parent
-                            .spawn(Node {
-                                flex_direction: FlexDirection::Row,
-                                flex_wrap: FlexWrap::NoWrap,
-                                ..Default::default()
-                            })
-                            .with_children(|parent| {
-                                for (crop, fences) in row {
-                                    let color = colors.entry(crop).or_insert_with(|| {
-                                        Color::hsv(
-                                            (((360 / 26) * u32::from(*crop)) % 360) as f32,
-                                            1.,
-                                            1.,
-                                        )
+                    parent
+                        .spawn(Node {
+                            height: Val::Percent(100.),
+                            flex_direction: FlexDirection::Column,
+                            flex_shrink: 0.,
+                            aspect_ratio: Some(columns as f32 / rows as f32),
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            for row in tile_influence {
+                                parent
+                                    .spawn(Node {
+                                        height: Val::Percent(100. / rows as f32),
+                                        flex_direction: FlexDirection::Row,
+                                        flex_wrap: FlexWrap::NoWrap,
+                                        ..Default::default()
+                                    })
+                                    .with_children(|parent| {
+                                        for (crop, fences) in row {
+                                            let color = colors.entry(crop).or_insert_with(|| {
+                                                Color::hsv(
+                                                    (((360 / 26) * u32::from(*crop)) % 360) as f32,
+                                                    1.,
+                                                    1.,
+                                                )
+                                            });
+
+                                            let mut field = parent.spawn((
+                                                Node {
+                                                    width: Val::Percent(100. / columns as f32),
+                                                    height: Val::Percent(100.),
+                                                    overflow: Overflow::clip(),
+                                                    ..Default::default()
+                                                },
+                                                BackgroundColor(*color),
+                                            ));
+
+                                            if fences != 0 {
+                                                field.with_child((
+                                                    Text::new(fences.to_string()),
+                                                    TextColor(Color::BLACK),
+                                                    TextFont {
+                                                        font_size: 12.,
+                                                        ..Default::default()
+                                                    },
+                                                ));
+                                            }
+                                        }
                                     });
-
-                                    let mut field = parent.spawn((
-                                        Node {
-                                            width: Val::Px(BLOCK_SIZE),
-                                            height: Val::Px(BLOCK_SIZE),
-                                            ..Default::default()
-                                        },
-                                        BackgroundColor(*color),
-                                    ));
-
-                                    if fences != 0 {
-                                        field.with_child((
-                                            Text::new(fences.to_string()),
-                                            TextColor(Color::BLACK),
-                                            TextFont {
-                                                font_size: 12.,
-                                                ..Default::default()
-                                            },
-                                        ));
-                                    }
-                                }
-                            });
-                    }
-                })
-                .id();
-
-            build_vertical_scroll_buttons(
-                parent,
-                window,
-                SCROLL_SPEED,
-                BUTTON_BACKGROUND_COLOR,
-                fonts.symbol1.clone(),
-            );
+                            }
+                        });
+                });
         });
 }
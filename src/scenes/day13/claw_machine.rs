@@ -34,13 +34,14 @@ impl bevy::app::Plugin for Plugin {
         .add_systems(
             Update,
             update_canvas.run_if(
-                in_state(VisualizationState::<13>::Ready)
+                in_state(VisualizationState::<2024, 13>::Ready)
                     .and(resource_changed::<SelectedClawMachine>),
             ),
         )
         .add_systems(
             Update,
-            selected_claw_machine_interaction.run_if(in_state(VisualizationState::<13>::Ready)),
+            selected_claw_machine_interaction
+                .run_if(in_state(VisualizationState::<2024, 13>::Ready)),
         );
     }
 }
@@ -1,6 +1,6 @@
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{Line, ParseError, RawInput};
 
 type Coord = (i64, i64);
 
@@ -10,67 +10,44 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let mut input = input.split(|c| *c == b'\n');
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let mut lines = input.lines().filter(|line| !line.is_empty());
 
         let mut machines = vec![];
 
-        loop {
-            let Some(a) = input.next() else {
-                break;
-            };
-            let a = {
-                let split = a.split(|c| *c == b' ').collect::<Vec<_>>();
-                let [b"Button", b"A:", x, y] = split.as_slice() else {
-                    unreachable!("AoC inputs are well formed.");
-                };
-                (
-                    String::from_utf8_lossy(&x[2..(x.len() - 1)])
-                        .parse()
-                        .unwrap(),
-                    String::from_utf8_lossy(&y[2..]).parse().unwrap(),
-                )
-            };
-
-            let b = input.next().unwrap();
-            let b = {
-                let split = b.split(|c| *c == b' ').collect::<Vec<_>>();
-                let [b"Button", b"B:", x, y] = split.as_slice() else {
-                    unreachable!("AoC inputs are well formed.");
-                };
-                (
-                    String::from_utf8_lossy(&x[2..(x.len() - 1)])
-                        .parse()
-                        .unwrap(),
-                    String::from_utf8_lossy(&y[2..]).parse().unwrap(),
-                )
-            };
-
-            let reward = input.next().unwrap();
-            let reward = {
-                let split = reward.split(|c| *c == b' ').collect::<Vec<_>>();
-                let [b"Prize:", x, y] = split.as_slice() else {
-                    unreachable!("AoC inputs are well formed.");
-                };
-                (
-                    String::from_utf8_lossy(&x[2..(x.len() - 1)])
-                        .parse()
-                        .unwrap(),
-                    String::from_utf8_lossy(&y[2..]).parse().unwrap(),
-                )
-            };
-
-            let machine_break = input.next().unwrap();
-            assert!(machine_break.is_empty());
+        while let Some(a) = lines.next() {
+            let button_a = Self::parse_line(a, b"Button A: ", b"+")?;
+            let b = lines.next().ok_or_else(|| input.unexpected_end())?;
+            let button_b = Self::parse_line(b, b"Button B: ", b"+")?;
+            let prize = lines.next().ok_or_else(|| input.unexpected_end())?;
+            let prize = Self::parse_line(prize, b"Prize: ", b"=")?;
 
             machines.push(ClawMachine {
-                button_a: a,
-                button_b: b,
-                prize: reward,
+                button_a,
+                button_b,
+                prize,
             });
         }
 
-        Self { machines }
+        Ok(Self { machines })
+    }
+
+    fn parse_line(line: Line, prefix: &[u8], sign: &[u8]) -> Result<Coord, ParseError> {
+        let values = line.strip_prefix(line.bytes, prefix)?;
+        let (x, y) = line.split_field(values, b", ")?;
+        let x = line.strip_prefix(x, b"X")?;
+        let x = line.strip_prefix(x, sign)?;
+        let y = line.strip_prefix(y, b"Y")?;
+        let y = line.strip_prefix(y, sign)?;
+
+        Ok((line.number(x)?, line.number(y)?))
+    }
+
+    pub fn total_cost(&self, padding: i64) -> i64 {
+        self.machines
+            .iter()
+            .flat_map(|machine| machine.find_cheapest_solution(padding).map(|res| res.0))
+            .sum()
     }
 }
 
@@ -98,3 +75,29 @@ impl ClawMachine {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ClawMachine;
+
+    const PADDING: i64 = 10000000000000;
+
+    #[test]
+    fn find_cheapest_solution() {
+        let machine = ClawMachine {
+            button_a: (94, 34),
+            button_b: (22, 67),
+            prize: (8400, 5400),
+        };
+        assert_eq!(machine.find_cheapest_solution(0), Some((280, 80, 40)));
+        assert_eq!(machine.find_cheapest_solution(PADDING), None);
+
+        let machine = ClawMachine {
+            button_a: (26, 66),
+            button_b: (67, 21),
+            prize: (12748, 12176),
+        };
+        assert_eq!(machine.find_cheapest_solution(0), None);
+        assert!(machine.find_cheapest_solution(PADDING).is_some());
+    }
+}
@@ -26,8 +26,9 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<13>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 13>::WaitingUi)),
+            ),
         );
     }
 }
@@ -40,7 +41,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 13 Part 2");
-    let header = build_header(&mut commands, "day13", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day13", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day13");
 
     commands
@@ -56,15 +57,7 @@ fn build_ui(
 }
 
 fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
-    let total_cost: i64 = input
-        .machines
-        .iter()
-        .flat_map(|machine| {
-            machine
-                .find_cheapest_solution(10000000000000)
-                .map(|res| res.0)
-        })
-        .sum();
+    let total_cost = input.total_cost(10000000000000);
 
     parent
         .spawn(Node {
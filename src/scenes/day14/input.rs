@@ -2,55 +2,77 @@ use std::{borrow::Borrow, cmp::Ordering};
 
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
-
-pub const BOUNDS: (i64, i64) = (101, 103);
+use crate::{
+    loader::{ParseError, PuzzleSizes, RawInput},
+    scenes::{
+        compute::Progress,
+        playback::{Scrubbable, Simulation},
+    },
+};
 
 #[derive(Debug, Resource)]
 pub struct Input {
+    pub bounds: (i64, i64),
     pub start: Vec<Robot>,
     pub robots: Vec<Robot>,
+    pub seconds: i64,
+    /// Seconds until the robots draw a christmas tree
+    pub easter_egg: i64,
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input.split(|c| *c == b'\n').filter(|line| !line.is_empty());
+    pub fn parse(input: &RawInput, sizes: PuzzleSizes) -> Result<Self, ParseError> {
+        let bounds = sizes.robot_area;
+        if bounds.0 <= 0 || bounds.1 <= 0 {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!("the {}x{} area is empty", bounds.0, bounds.1),
+            ));
+        }
+        let input = input.lines().filter(|line| !line.is_empty());
 
         let robots = input
             .map(|line| {
-                let split1 = line.split(|c| *c == b' ').collect::<Vec<_>>();
-                let [p, v] = split1.as_slice() else {
-                    unreachable!("Will always have 2 items.")
-                };
-
-                let splitp = p[2..]
-                    .split(|c| *c == b',')
-                    .map(|val| String::from_utf8_lossy(val).parse())
-                    .collect::<Result<Vec<_>, _>>()
-                    .unwrap();
-                let splitv = v[2..]
-                    .split(|c| *c == b',')
-                    .map(|val| String::from_utf8_lossy(val).parse())
-                    .collect::<Result<Vec<_>, _>>()
-                    .unwrap();
-
-                Robot {
-                    position: (splitp[0], splitp[1]),
-                    velocity: (splitv[0], splitv[1]),
+                let (p, v) = line.split_field(line.bytes, b" ")?;
+
+                let (px, py) = line.split_field(line.strip_prefix(p, b"p=")?, b",")?;
+                let (vx, vy) = line.split_field(line.strip_prefix(v, b"v=")?, b",")?;
+
+                let position = (line.number(px)?, line.number(py)?);
+                if !(0..bounds.0).contains(&position.0) || !(0..bounds.1).contains(&position.1) {
+                    return Err(line.error_at(
+                        px,
+                        format!("robot is outside of the {}x{} area", bounds.0, bounds.1),
+                    ));
                 }
+
+                Ok(Robot {
+                    position,
+                    velocity: (line.number(vx)?, line.number(vy)?),
+                })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self {
+        Ok(Self {
+            bounds,
+            easter_egg: 0,
             start: robots.clone(),
             robots,
-        }
+            seconds: 0,
+        })
+    }
+
+    pub fn solve_easter_egg(&mut self, progress: &Progress) -> i64 {
+        self.easter_egg = Self::easter_egg(&self.start, self.bounds, progress);
+        self.easter_egg
     }
 
     pub fn step(&mut self, seconds: i64) {
+        self.seconds += seconds;
         self.robots
             .iter_mut()
-            .for_each(|robot| robot.step(seconds, BOUNDS));
+            .for_each(|robot| robot.step(seconds, self.bounds));
     }
 
     pub fn safety_factor(&self, seconds: i64) -> isize {
@@ -59,23 +81,26 @@ impl Input {
             .iter()
             .map(|robot| {
                 let mut robot = *robot;
-                robot.step(seconds, BOUNDS);
+                robot.step(seconds, self.bounds);
                 robot
             })
             .fold((0, 0, 0, 0), |a, b| {
-                Self::count_robots_in_quadrant(a, b, BOUNDS)
+                Self::count_robots_in_quadrant(a, b, self.bounds)
             });
 
         quadrants.0 * quadrants.1 * quadrants.2 * quadrants.3
     }
 
-    pub fn easter_egg(&self) -> i64 {
-        let mut robots = self.start.clone();
-        (1..=(BOUNDS.0 * BOUNDS.1))
+    fn easter_egg(start: &[Robot], bounds: (i64, i64), progress: &Progress) -> i64 {
+        let mut robots = start.to_vec();
+        let period = bounds.0 * bounds.1;
+        (1..=period)
+            .take_while(|_| !progress.is_cancelled())
             .map(|i| {
-                robots.iter_mut().for_each(|robot| robot.step(1, BOUNDS));
+                progress.report(i as usize, period as usize);
+                robots.iter_mut().for_each(|robot| robot.step(1, bounds));
                 let quadrants = robots.iter().fold((0, 0, 0, 0), |a, b| {
-                    Self::count_robots_in_quadrant(a, b, BOUNDS)
+                    Self::count_robots_in_quadrant(a, b, bounds)
                 });
                 (
                     i,
@@ -88,7 +113,7 @@ impl Input {
             })
             .max_by_key(|(_, score)| *score)
             .map(|(i, _)| i)
-            .unwrap()
+            .unwrap_or_default()
     }
 
     fn count_robots_in_quadrant(
@@ -117,6 +142,52 @@ impl Input {
     }
 }
 
+impl Simulation for Input {
+    const REVERSIBLE: bool = true;
+
+    fn step(&mut self) -> bool {
+        Input::step(self, 1);
+        true
+    }
+
+    fn step_back(&mut self) -> bool {
+        Input::step(self, -1);
+        true
+    }
+
+    fn reset(&mut self) {
+        self.robots = self.start.clone();
+        self.seconds = 0;
+    }
+}
+
+impl Scrubbable for Input {
+    type Snapshot = i64;
+
+    fn position(&self) -> usize {
+        // Robots are back to the start after every `length` seconds
+        self.seconds.rem_euclid(self.bounds.0 * self.bounds.1) as usize
+    }
+
+    fn length(&self) -> usize {
+        (self.bounds.0 * self.bounds.1) as usize
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.seconds
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        self.reset();
+        self.step(*snapshot);
+    }
+
+    fn seek(&mut self, position: usize) -> bool {
+        self.restore(&(position as i64));
+        true
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Robot {
     pub position: (i64, i64),
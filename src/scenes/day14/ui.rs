@@ -1,4 +1,4 @@
-use std::{collections::BTreeSet, time::Duration};
+use std::collections::BTreeSet;
 
 use bevy::{
     app::Update,
@@ -9,19 +9,25 @@ use bevy::{
         Text, With,
     },
     text::TextColor,
-    time::common_conditions::on_timer,
     ui::{
-        BackgroundColor, BorderColor, BorderRadius, FlexDirection, Node, PositionType, UiRect, Val,
+        BackgroundColor, BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType,
+        UiRect, Val,
     },
 };
 
-use crate::scenes::{
-    days::{build_content, build_header},
-    resources::{FontHandles, GenericDay},
-    states::{UiState, VisualizationState},
+use crate::{
+    loader::Answers,
+    scenes::{
+        answers::build_answer_badge,
+        days::{build_content, build_footer, build_header},
+        playback::{build_playback_controls, build_timeline},
+        resources::{FontHandles, GenericDay},
+        states::{Part, UiState, VisualizationState},
+    },
+    scroll_controls::PanZoom,
 };
 
-use super::input::{Input, BOUNDS};
+use super::input::Input;
 
 pub struct Plugin;
 
@@ -29,17 +35,12 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui.run_if(in_state(VisualizationState::<14>::WaitingUi)),
-        )
-        .add_systems(
-            Update,
-            update_canvas
-                .run_if(in_state(VisualizationState::<14>::Ready).and(resource_changed::<Input>)),
+            build_ui.run_if(in_state(VisualizationState::<2024, 14>::WaitingUi)),
         )
         .add_systems(
             Update,
-            step_robots.run_if(
-                in_state(VisualizationState::<14>::Ready).and(on_timer(Duration::from_millis(100))),
+            update_canvas.run_if(
+                in_state(VisualizationState::<2024, 14>::Ready).and(resource_changed::<Input>),
             ),
         );
     }
@@ -51,26 +52,37 @@ fn build_ui(
     input: Res<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 14");
-    let header = build_header(&mut commands, "day14", false, fonts.font.clone());
+    let header = build_header(&mut commands, "day14", false, true, fonts.font.clone());
     let content = build_content(&mut commands, "day14");
+    let footer = build_footer(&mut commands, "day14");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input));
+        .with_children(|parent| build_visualization(parent, &input, &answers, &fonts));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<Input>(parent, &fonts);
+        build_timeline::<Input>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day14_resource.ui)
         .despawn_descendants()
-        .add_children(&[header, content]);
+        .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
+fn build_visualization(
+    parent: &mut ChildBuilder,
+    input: &Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
     let safety_factor = input.safety_factor(100);
-    let time_to_easter_egg = input.easter_egg();
+    let time_to_easter_egg = input.easter_egg;
 
     parent
         .spawn(Node {
@@ -107,10 +119,19 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child((
-                            Text::new(safety_factor.to_string()),
-                            TextColor(Color::WHITE),
-                        ));
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new(safety_factor.to_string()),
+                                TextColor(Color::WHITE),
+                            ));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (14, Part::Part1),
+                                &safety_factor.to_string(),
+                                fonts.symbol2.clone(),
+                            );
+                        });
                     parent
                         .spawn(Node {
                             padding: UiRect::all(Val::Px(3.)),
@@ -133,10 +154,19 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child((
-                            Text::new(time_to_easter_egg.to_string()),
-                            TextColor(Color::WHITE),
-                        ));
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new(time_to_easter_egg.to_string()),
+                                TextColor(Color::WHITE),
+                            ));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (14, Part::Part2),
+                                &time_to_easter_egg.to_string(),
+                                fonts.symbol2.clone(),
+                            );
+                        });
                     parent
                         .spawn(Node {
                             padding: UiRect::all(Val::Px(3.)),
@@ -146,19 +176,25 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
                 });
 
             parent
-                .spawn(Node {
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Row,
-                    align_self: bevy::ui::AlignSelf::Center,
-                    ..Default::default()
-                })
+                .spawn((
+                    Node {
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
+                        ..Default::default()
+                    },
+                    PanZoom::default(),
+                ))
                 .with_children(|parent| {
                     parent.spawn((
                         Node {
                             height: Val::Percent(100.),
                             flex_direction: FlexDirection::Column,
                             flex_wrap: bevy::ui::FlexWrap::NoWrap,
-                            aspect_ratio: Some(BOUNDS.0 as f32 / BOUNDS.1 as f32),
+                            flex_shrink: 0.,
+                            aspect_ratio: Some(input.bounds.0 as f32 / input.bounds.1 as f32),
                             border: UiRect::all(Val::Px(3.)),
                             ..Default::default()
                         },
@@ -172,10 +208,6 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
 #[derive(Debug, Component)]
 pub struct RobotCanvas;
 
-fn step_robots(mut input: ResMut<Input>) {
-    input.step(1);
-}
-
 fn update_canvas(
     mut commands: Commands,
     canvas: Single<Entity, With<RobotCanvas>>,
@@ -187,16 +219,16 @@ fn update_canvas(
         .entity(*canvas)
         .despawn_descendants()
         .with_children(|parent| {
-            for y in 0..BOUNDS.1 {
+            for y in 0..input.bounds.1 {
                 parent
                     .spawn(Node {
                         width: Val::Percent(100.),
-                        height: Val::Percent(10000. / BOUNDS.1 as f32),
+                        height: Val::Percent(10000. / input.bounds.1 as f32),
                         flex_direction: FlexDirection::Row,
                         ..Default::default()
                     })
                     .with_children(|parent| {
-                        for x in 0..BOUNDS.0 {
+                        for x in 0..input.bounds.0 {
                             let mut tile = parent.spawn(Node {
                                 height: Val::Percent(100.),
                                 aspect_ratio: Some(1.),
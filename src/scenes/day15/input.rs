@@ -2,7 +2,10 @@ use std::collections::BTreeSet;
 
 use bevy::prelude::Resource;
 
-use crate::{loader::RawInput, tools::Direction};
+use crate::{
+    loader::{ParseError, RawInput},
+    tools::Direction,
+};
 
 type Coord = (usize, usize);
 
@@ -12,21 +15,26 @@ pub struct Input {
     pub boxes: BTreeSet<Coord>,
     pub robot: Coord,
     pub instructions: Vec<Direction>,
+    /// GPS sum of the boxes once the robot is done, in each part
+    pub gps: [usize; 2],
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let mut input = input.split(|c| *c == b'\n').enumerate();
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
 
         let mut walls = BTreeSet::new();
         let mut boxes = BTreeSet::new();
-        let mut robot = (0, 0);
+        let mut robot = None;
+        let mut map = Vec::new();
 
         loop {
-            let (y, line) = input.next().unwrap();
+            let line = lines.next().ok_or_else(|| input.unexpected_end())?;
             if line.is_empty() {
                 break;
             }
+            map.push(line);
+            let y = line.number - 1;
 
             for (x, tile) in line.iter().enumerate() {
                 match tile {
@@ -36,30 +44,56 @@ impl Input {
                     b'O' => {
                         boxes.insert((x, y));
                     }
-                    b'@' => robot = (x, y),
+                    b'@' if robot.is_none() => robot = Some((x, y)),
+                    b'@' => return Err(line.error(x, "more than one robot")),
                     b'.' => (),
-                    _ => unreachable!("Invalid tile"),
+                    _ => {
+                        return Err(
+                            line.error(x, format!("unexpected tile `{}`", char::from(*tile)))
+                        )
+                    }
                 }
             }
         }
 
-        let instructions = input
-            .flat_map(|(_, line)| {
-                line.iter().map(|mov| match mov {
-                    b'^' => Direction::North,
-                    b'v' => Direction::South,
-                    b'<' => Direction::West,
-                    b'>' => Direction::East,
-                    _ => unreachable!("Invalid move."),
-                })
+        let Some(robot) = robot else {
+            return Err(ParseError::new(1, 1, "missing robot `@`"));
+        };
+
+        let (top, bottom) = (map[0], map[map.len() - 1]);
+        for line in map.iter() {
+            if line.len() != top.len() || line.first() != Some(&b'#') || line.last() != Some(&b'#')
+            {
+                return Err(line.error(0, "warehouse must be enclosed by walls"));
+            }
+        }
+        for line in [top, bottom] {
+            if let Some(x) = line.iter().position(|tile| *tile != b'#') {
+                return Err(line.error(x, "warehouse must be enclosed by walls"));
+            }
+        }
+
+        let instructions = lines
+            .flat_map(|line| {
+                line.bytes
+                    .iter()
+                    .enumerate()
+                    .map(move |(x, mov)| match mov {
+                        b'^' => Ok(Direction::North),
+                        b'v' => Ok(Direction::South),
+                        b'<' => Ok(Direction::West),
+                        b'>' => Ok(Direction::East),
+                        _ => Err(line.error(x, format!("unexpected move `{}`", char::from(*mov)))),
+                    })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self {
+        Ok(Self {
             walls,
             boxes,
             robot,
             instructions,
-        }
+            gps: [0; 2],
+        })
     }
 }
@@ -6,14 +6,19 @@ use bevy::{
         DespawnRecursiveExt, IntoSystemConfigs, NextState, OnExit, Res, ResMut, Text,
     },
     text::TextColor,
-    ui::{BorderColor, BorderRadius, FlexDirection, Node, PositionType, UiRect, Val},
+    ui::{BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType, UiRect, Val},
 };
 
-use crate::scenes::{
-    day15::controls::build_control,
-    days::{build_content, build_footer, build_header},
-    resources::{FontHandles, GenericDay},
-    states::{Part, Scene, UiState, VisualizationState},
+use crate::{
+    scenes::{
+        days::{build_content, build_footer, build_header},
+        grid_image::GridImage,
+        playback::{build_playback_controls, build_timeline},
+        resources::{FontHandles, GenericDay},
+        states::{Part, Scene, UiState, VisualizationState},
+    },
+    scroll_controls::PanZoom,
+    tools::Coord,
 };
 
 use super::{
@@ -27,12 +32,16 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             OnExit(Part::Part1),
-            tear_down_sokoban.run_if(in_state(Scene::Day(15))),
+            tear_down_sokoban.run_if(in_state(Scene::Day {
+                year: 2024,
+                day: 15,
+            })),
         )
         .add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<15>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 15>::WaitingUi)),
+            ),
         );
     }
 }
@@ -55,18 +64,21 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 15 Part 1");
-    let header = build_header(&mut commands, "day15", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day15", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day15");
     let footer = build_footer(&mut commands, "day15");
 
-    commands.insert_resource(Warehouse::from_input(&input, false));
+    let warehouse = Warehouse::from_input(&input, false);
+    let dimensions = warehouse.dimensions();
+    commands.insert_resource(warehouse);
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &input));
-    commands
-        .entity(footer)
-        .with_children(|parent| build_control(parent, fonts.symbol2.clone()));
+        .with_children(|parent| build_visualization(parent, input.gps[0], dimensions));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<Warehouse>(parent, &fonts);
+        build_timeline::<Warehouse>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day15_resource.ui)
@@ -76,15 +88,7 @@ fn build_ui(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
-    let mut warehouse = Warehouse::from_input(input, false);
-
-    while warehouse.has_instructions() {
-        warehouse.next_move();
-    }
-
-    let gps = warehouse.compute_gps();
-
+fn build_visualization(parent: &mut ChildBuilder, gps: usize, dimensions: Coord) {
     parent
         .spawn(Node {
             top: Val::Px(50.),
@@ -124,26 +128,42 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
                 });
 
             parent
-                .spawn(Node {
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Row,
-                    align_self: bevy::ui::AlignSelf::Center,
-                    ..Default::default()
-                })
+                .spawn((
+                    Node {
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
+                        ..Default::default()
+                    },
+                    PanZoom::default(),
+                ))
                 .with_children(|parent| {
-                    let dimensions = warehouse.dimensions();
-                    parent.spawn((
-                        Node {
-                            height: Val::Percent(100.),
-                            flex_direction: FlexDirection::Column,
-                            flex_wrap: bevy::ui::FlexWrap::NoWrap,
-                            aspect_ratio: Some(dimensions.column as f32 / dimensions.row as f32),
-                            border: UiRect::all(Val::Px(3.)),
-                            ..Default::default()
-                        },
-                        BorderColor(Color::WHITE),
-                        SokobanCanvas,
-                    ));
+                    parent
+                        .spawn((
+                            Node {
+                                height: Val::Percent(100.),
+                                flex_direction: FlexDirection::Column,
+                                flex_wrap: bevy::ui::FlexWrap::NoWrap,
+                                flex_shrink: 0.,
+                                aspect_ratio: Some(
+                                    dimensions.column as f32 / dimensions.row as f32,
+                                ),
+                                border: UiRect::all(Val::Px(3.)),
+                                ..Default::default()
+                            },
+                            BorderColor(Color::WHITE),
+                        ))
+                        .with_child((
+                            Node {
+                                width: Val::Percent(100.),
+                                height: Val::Percent(100.),
+                                ..Default::default()
+                            },
+                            GridImage::new(dimensions, Color::NONE),
+                            SokobanCanvas,
+                        ));
                 });
         });
 }
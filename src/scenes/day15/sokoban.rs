@@ -1,23 +1,23 @@
-use std::time::Duration;
-
 use bevy::{
     app::Update,
-    color::palettes,
+    color::{palettes, Color},
     prelude::{
-        in_state, resource_exists_and_changed, BuildChildren, ChildBuild, Commands, Component,
-        Condition, DespawnRecursiveExt, Entity, IntoSystemConfigs, NextState, Res, ResMut,
+        in_state, resource_exists_and_changed, Component, Condition, IntoSystemConfigs, Res,
         Resource, Single, With,
     },
-    time::common_conditions::on_timer,
-    ui::{BackgroundColor, FlexDirection, Node, Val},
+    ui::Node,
 };
 
 use crate::{
-    scenes::states::VisualizationState,
+    scenes::{
+        grid_image::GridImage,
+        playback::{Scrubbable, Simulation},
+        states::VisualizationState,
+    },
     tools::{Coord, Direction},
 };
 
-use super::{controls::ControlState, input::Input};
+use super::input::Input;
 
 type RobotMove = Direction;
 
@@ -26,17 +26,9 @@ pub struct Plugin;
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
-            Update,
-            update_sokoban.run_if(
-                in_state(VisualizationState::<15>::Ready)
-                    .and(in_state(ControlState::Playing))
-                    .and(on_timer(Duration::from_millis(100))),
-            ),
-        )
-        .add_systems(
             Update,
             update_canvas.run_if(
-                in_state(VisualizationState::<15>::Ready)
+                in_state(VisualizationState::<2024, 15>::Ready)
                     .and(resource_exists_and_changed::<Warehouse>),
             ),
         );
@@ -47,65 +39,23 @@ impl bevy::app::Plugin for Plugin {
 #[require(Node)]
 pub struct SokobanCanvas;
 
-fn update_sokoban(mut sokoban: ResMut<Warehouse>, mut next_state: ResMut<NextState<ControlState>>) {
-    if sokoban.has_instructions() {
-        sokoban.next_move();
-    } else {
-        next_state.set(ControlState::Paused);
-    }
-}
-
-fn update_canvas(
-    mut commands: Commands,
-    canvas: Single<Entity, With<SokobanCanvas>>,
-    sokoban: Res<Warehouse>,
-) {
-    commands
-        .entity(*canvas)
-        .despawn_descendants()
-        .with_children(|parent| {
-            for y in 0..sokoban.dimensions.row {
-                parent
-                    .spawn(Node {
-                        width: Val::Percent(100.),
-                        height: Val::Percent(10000. / sokoban.dimensions.row as f32),
-                        flex_direction: FlexDirection::Row,
-                        ..Default::default()
-                    })
-                    .with_children(|parent| {
-                        for x in 0..sokoban.dimensions.column {
-                            let mut tile = parent.spawn(Node {
-                                height: Val::Percent(100.),
-                                aspect_ratio: Some(1.),
-                                ..Default::default()
-                            });
-
-                            match sokoban.map[y * sokoban.dimensions.column + x] {
-                                WarehouseTile::Empty => (),
-                                WarehouseTile::Robot => {
-                                    tile.insert(BackgroundColor(
-                                        palettes::tailwind::EMERALD_800.into(),
-                                    ));
-                                }
-                                WarehouseTile::Wall => {
-                                    tile.insert(BackgroundColor(
-                                        palettes::tailwind::GRAY_800.into(),
-                                    ));
-                                }
-                                WarehouseTile::BoxLeft | WarehouseTile::BoxRight => {
-                                    tile.insert(BackgroundColor(
-                                        palettes::tailwind::ORANGE_800.into(),
-                                    ));
-                                }
-                            }
-                        }
-                    });
+fn update_canvas(mut canvas: Single<&mut GridImage, With<SokobanCanvas>>, sokoban: Res<Warehouse>) {
+    for (i, tile) in sokoban.map.iter().enumerate() {
+        let coord = Coord::new(i / sokoban.dimensions.column, i % sokoban.dimensions.column);
+        let color = match tile {
+            WarehouseTile::Empty => Color::NONE,
+            WarehouseTile::Robot => palettes::tailwind::EMERALD_800.into(),
+            WarehouseTile::Wall => palettes::tailwind::GRAY_800.into(),
+            WarehouseTile::BoxLeft | WarehouseTile::BoxRight => {
+                palettes::tailwind::ORANGE_800.into()
             }
-        });
+        };
+        canvas.set(coord, color);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum WarehouseTile {
+pub enum WarehouseTile {
     Empty,
     Wall,
     BoxLeft,
@@ -120,6 +70,8 @@ pub struct Warehouse {
     dimensions: Coord,
     wide: bool,
     instructions: Vec<RobotMove>,
+    next_instruction: usize,
+    start: (Coord, Vec<WarehouseTile>),
 }
 
 impl Warehouse {
@@ -152,15 +104,14 @@ impl Warehouse {
             }
         }
 
-        let mut instructions = input.instructions.clone();
-        instructions.reverse();
-
         Self {
             dimensions,
             robot,
-            map: tiles,
+            map: tiles.clone(),
             wide,
-            instructions,
+            instructions: input.instructions.clone(),
+            next_instruction: 0,
+            start: (robot, tiles),
         }
     }
 
@@ -183,15 +134,22 @@ impl Warehouse {
     }
 
     pub fn next_move(&mut self) {
-        let Some(mov) = self.instructions.pop() else {
+        let Some(mov) = self.instructions.get(self.next_instruction).copied() else {
             return;
         };
+        self.next_instruction += 1;
 
         self.move_robot(mov);
     }
 
+    pub fn run(&mut self) {
+        while self.has_instructions() {
+            self.next_move();
+        }
+    }
+
     pub fn has_instructions(&self) -> bool {
-        !self.instructions.is_empty()
+        self.next_instruction < self.instructions.len()
     }
 
     pub fn dimensions(&self) -> Coord {
@@ -293,3 +251,42 @@ impl Warehouse {
         };
     }
 }
+
+impl Simulation for Warehouse {
+    fn step(&mut self) -> bool {
+        if !self.has_instructions() {
+            return false;
+        }
+        self.next_move();
+        true
+    }
+
+    fn reset(&mut self) {
+        (self.robot, self.map) = self.start.clone();
+        self.next_instruction = 0;
+    }
+}
+
+impl Scrubbable for Warehouse {
+    type Snapshot = (Coord, Vec<WarehouseTile>, usize);
+
+    const SNAPSHOT_INTERVAL: usize = 500;
+
+    fn position(&self) -> usize {
+        self.next_instruction
+    }
+
+    fn length(&self) -> usize {
+        self.instructions.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.robot, self.map.clone(), self.next_instruction)
+    }
+
+    fn restore(&mut self, (robot, map, next_instruction): &Self::Snapshot) {
+        self.robot = *robot;
+        self.map.clone_from(map);
+        self.next_instruction = *next_instruction;
+    }
+}
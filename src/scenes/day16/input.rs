@@ -1,6 +1,9 @@
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    tools::{Maze, Vec2d},
+};
 
 #[derive(Debug, Resource)]
 pub struct Input {
@@ -8,9 +11,42 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        Self {
-            input: input.0.clone(),
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let maze = input.grid(|tile| b"#.SE".contains(&tile))?;
+        for tile in [b'S', b'E'] {
+            let count = maze
+                .iter()
+                .flat_map(|row| row.iter())
+                .filter(|t| **t == tile)
+                .count();
+            if count != 1 {
+                return Err(ParseError::new(
+                    1,
+                    1,
+                    format!("expected one `{}` tile, found {count}", char::from(tile)),
+                ));
+            }
         }
+
+        Ok(Self {
+            input: input.0.clone(),
+        })
+    }
+
+    pub fn lowest_score(&mut self) -> usize {
+        let maze = Maze::parse(&mut self.input, 1000);
+
+        let (mut maze_tiles_data, _) = maze.calculate_tile_scores();
+        let maze_tiles = Vec2d::new(maze_tiles_data.as_mut_slice(), maze.width(), maze.height());
+
+        maze_tiles[maze.end()]
+    }
+
+    pub fn tiles_in_best_paths(&mut self) -> usize {
+        let maze = Maze::parse(&mut self.input, 1000);
+
+        let (_, paths) = maze.calculate_tile_scores();
+
+        paths.len()
     }
 }
@@ -5,17 +5,17 @@ use bevy::{
         in_state, Animatable, BuildChildren, ChildBuild, ChildBuilder, Commands, Condition,
         DespawnRecursiveExt, IntoSystemConfigs, NextState, OnExit, Res, ResMut, Text,
     },
-    ui::{
-        BackgroundColor, BorderColor, BorderRadius, FlexDirection, Node, PositionType, UiRect, Val,
-    },
+    ui::{BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType, UiRect, Val},
 };
 
 use crate::{
     scenes::{
         days::{build_content, build_header},
+        grid_image::GridImage,
         resources::{FontHandles, GenericDay},
         states::{Part, Scene, UiState, VisualizationState},
     },
+    scroll_controls::PanZoom,
     tools::{Coord, Maze, Vec2d},
 };
 
@@ -27,12 +27,16 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             OnExit(Part::Part1),
-            tear_down_sokoban.run_if(in_state(Scene::Day(16))),
+            tear_down_sokoban.run_if(in_state(Scene::Day {
+                year: 2024,
+                day: 16,
+            })),
         )
         .add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<16>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 16>::WaitingUi)),
+            ),
         );
     }
 }
@@ -54,7 +58,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 16 Part 1");
-    let header = build_header(&mut commands, "day16", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day16", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day16");
 
     commands
@@ -78,6 +82,30 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
     let start = maze_tiles[maze.start()];
     let end = maze_tiles[maze.end()];
 
+    let mut grid = GridImage::new(
+        Coord::new(maze.height(), maze.width() - 1),
+        palettes::tailwind::GRAY_700.into(),
+    );
+    for row in 0..maze.height() {
+        for column in 0..(maze.width() - 1) {
+            let coord = Coord::new(row, column);
+            let tile = maze_tiles[coord];
+            if tile != usize::MAX {
+                let start_color = palettes::tailwind::BLUE_200;
+                let end_color = palettes::tailwind::PURPLE_700;
+                grid.set(
+                    coord,
+                    Srgba::interpolate(
+                        &start_color,
+                        &end_color,
+                        (tile - start) as f32 / (end - start) as f32,
+                    )
+                    .into(),
+                );
+            }
+        }
+    }
+
     parent
         .spawn(Node {
             top: Val::Px(50.),
@@ -111,12 +139,17 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                 });
 
             parent
-                .spawn(Node {
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Row,
-                    align_self: bevy::ui::AlignSelf::Center,
-                    ..Default::default()
-                })
+                .spawn((
+                    Node {
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
+                        ..Default::default()
+                    },
+                    PanZoom::default(),
+                ))
                 .with_children(|parent| {
                     parent
                         .spawn((
@@ -124,6 +157,7 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                                 height: Val::Percent(100.),
                                 flex_direction: FlexDirection::Column,
                                 flex_wrap: bevy::ui::FlexWrap::NoWrap,
+                                flex_shrink: 0.,
                                 aspect_ratio: Some(
                                     (maze.width() - 1) as f32 / maze.height() as f32,
                                 ),
@@ -132,46 +166,14 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                             },
                             BorderColor(Color::WHITE),
                         ))
-                        .with_children(|parent| {
-                            for y in 0..maze.height() {
-                                parent
-                                    .spawn(Node {
-                                        width: Val::Percent(100.),
-                                        height: Val::Percent(10000. / maze.height() as f32),
-                                        flex_direction: FlexDirection::Row,
-                                        ..Default::default()
-                                    })
-                                    .with_children(|parent| {
-                                        for x in 0..(maze.width() - 1) {
-                                            let background = if maze_tiles[Coord::new(y, x)]
-                                                == usize::MAX
-                                            {
-                                                BackgroundColor(palettes::tailwind::GRAY_700.into())
-                                            } else {
-                                                let tile = maze_tiles[Coord::new(y, x)];
-                                                let start_color = palettes::tailwind::BLUE_200;
-                                                let end_color = palettes::tailwind::PURPLE_700;
-                                                Srgba::interpolate(
-                                                    &start_color,
-                                                    &end_color,
-                                                    (tile - start) as f32 / (end - start) as f32,
-                                                )
-                                                .into()
-                                            };
-
-                                            parent.spawn((
-                                                Node {
-                                                    height: Val::Percent(100.),
-                                                    aspect_ratio: Some(1.),
-
-                                                    ..Default::default()
-                                                },
-                                                background,
-                                            ));
-                                        }
-                                    });
-                            }
-                        });
+                        .with_child((
+                            Node {
+                                width: Val::Percent(100.),
+                                height: Val::Percent(100.),
+                                ..Default::default()
+                            },
+                            grid,
+                        ));
                 });
         });
 }
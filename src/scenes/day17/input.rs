@@ -2,93 +2,154 @@ use std::fmt::Display;
 
 use bevy::prelude::{Component, Resource};
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    scenes::playback::{Scrubbable, Simulation},
+};
+
+/// Instructions executed before giving up on a program that does not halt
+const MAX_INSTRUCTIONS: usize = 100_000;
 
 #[derive(Debug, Clone, Resource, Component)]
 pub struct Input {
     pub registers: Registers,
+    pub initial_registers: Registers,
     pub program: Vec<Instruction>,
     pub raw_program: Vec<u8>,
+    pub executed: usize,
+    length: usize,
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let mut input = input.split(|c| *c == b'\n');
-
-        let a = input
-            .next()
-            .map(|line| {
-                line.split(|c| *c == b':')
-                    .nth(1)
-                    .map(|val| String::from_utf8_lossy(val.trim_ascii()).parse().unwrap())
-                    .unwrap()
-            })
-            .unwrap();
-        let b = input
-            .next()
-            .map(|line| {
-                line.split(|c| *c == b':')
-                    .nth(1)
-                    .map(|val| String::from_utf8_lossy(val.trim_ascii()).parse().unwrap())
-                    .unwrap()
-            })
-            .unwrap();
-        let c = input
-            .next()
-            .map(|line| {
-                line.split(|c| *c == b':')
-                    .nth(1)
-                    .map(|val| String::from_utf8_lossy(val.trim_ascii()).parse().unwrap())
-                    .unwrap()
-            })
-            .unwrap();
-        _ = input.next();
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let mut next_line = || lines.next().ok_or_else(|| input.unexpected_end());
+
+        let mut register = |name: &[u8]| {
+            let line = next_line()?;
+            let value = line.strip_prefix(line.bytes, name)?;
+            line.number(value)
+        };
+        let a = register(b"Register A:")?;
+        let b = register(b"Register B:")?;
+        let c = register(b"Register C:")?;
+
+        let line = next_line()?;
+        if !line.is_empty() {
+            return Err(line.error(0, "expected an empty line"));
+        }
 
         let registers = Registers { pc: 0, a, b, c };
 
-        let raw_program = input
-            .next()
-            .map(|line| {
-                line.split(|c| *c == b':')
-                    .nth(1)
-                    .unwrap()
-                    .split(|c| *c == b',')
-                    .map(|byte| String::from_utf8_lossy(byte.trim_ascii()).parse::<u8>())
-                    .collect::<Result<Vec<u8>, _>>()
-                    .unwrap()
+        let line = next_line()?;
+        let fields = line
+            .strip_prefix(line.bytes, b"Program:")?
+            .split(|c| *c == b',')
+            .collect::<Vec<_>>();
+        let raw_program = fields
+            .iter()
+            .map(|field| {
+                let byte = line.number::<u8>(field)?;
+                if byte < 8 {
+                    Ok(byte)
+                } else {
+                    Err(line.error_at(field, "expected a 3-bit number"))
+                }
             })
-            .unwrap();
+            .collect::<Result<Vec<u8>, _>>()?;
+        if raw_program.len() % 2 != 0 {
+            return Err(line.error(line.len(), "expected an operand"));
+        }
+
         let program = raw_program
             .chunks(2)
-            .map(|chunk| match chunk[0] {
-                0 => Instruction::Adv(chunk[1].into()),
-                1 => Instruction::Bxl(chunk[1]),
-                2 => Instruction::Bst(chunk[1].into()),
-                3 => Instruction::Jnz(chunk[1]),
-                4 => Instruction::Bxc(chunk[1]),
-                5 => Instruction::Out(chunk[1].into()),
-                6 => Instruction::Bdv(chunk[1].into()),
-                7 => Instruction::Cdv(chunk[1].into()),
-                _ => unreachable!("AoC inputs are well formed"),
+            .zip(fields.chunks(2))
+            .map(|(chunk, fields)| {
+                let combo = || {
+                    ComboOperand::new(chunk[1])
+                        .ok_or_else(|| line.error_at(fields[1], "combo operand 7 is reserved"))
+                };
+                Ok(match chunk[0] {
+                    0 => Instruction::Adv(combo()?),
+                    1 => Instruction::Bxl(chunk[1]),
+                    2 => Instruction::Bst(combo()?),
+                    3 => Instruction::Jnz(chunk[1]),
+                    4 => Instruction::Bxc(chunk[1]),
+                    5 => Instruction::Out(combo()?),
+                    6 => Instruction::Bdv(combo()?),
+                    7 => Instruction::Cdv(combo()?),
+                    _ => unreachable!("Opcodes were checked to be 3-bit numbers"),
+                })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, ParseError>>()?;
 
-        Self {
+        let mut input = Self {
             registers,
+            initial_registers: registers,
             program,
             raw_program,
+            executed: 0,
+            length: 0,
+        };
+        input.length = input.instructions_until_halt();
+
+        Ok(input)
+    }
+
+    fn instructions_until_halt(&self) -> usize {
+        let mut registers = self.registers;
+        let mut executed = 0;
+        while executed < MAX_INSTRUCTIONS {
+            let Some(instruction) = self.program.get(registers.pc) else {
+                break;
+            };
+            instruction.execute(&mut registers);
+            executed += 1;
         }
+        executed
     }
 
-    pub fn execute(&mut self) -> Vec<u8> {
+    pub fn execute(&mut self) -> Result<Vec<u8>, NotHalting> {
         let mut output = vec![];
-        while self.registers.pc < self.program.len() {
-            let instruction = self.program[self.registers.pc];
+        for _ in 0..MAX_INSTRUCTIONS {
+            let Some(instruction) = self.program.get(self.registers.pc) else {
+                return Ok(output);
+            };
             if let Some(out) = instruction.execute(&mut self.registers) {
                 output.push(out);
             }
         }
-        output
+        Err(NotHalting)
+    }
+
+    pub fn output(&self) -> Result<String, NotHalting> {
+        Ok(self
+            .clone()
+            .execute()?
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+
+    /// Smallest value of register A for which the program outputs itself, `None` when there is
+    /// no such value
+    pub fn quine(&self) -> Option<usize> {
+        let mut debug = self.debug();
+        while debug.i > 0 {
+            debug.step();
+        }
+        // Candidates are matched digit by digit, a longer output can still match every digit
+        debug
+            .queue
+            .iter()
+            .copied()
+            .filter(|&a| {
+                let mut vm = self.clone();
+                vm.registers.a = a;
+                vm.execute().is_ok_and(|output| output == self.raw_program)
+            })
+            .min()
     }
 
     pub fn debug(&self) -> Debugger {
@@ -103,6 +164,64 @@ impl Input {
     }
 }
 
+impl Simulation for Input {
+    fn steps_per_second(&self) -> f32 {
+        5.
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(instruction) = self.program.get(self.registers.pc).copied() else {
+            return false;
+        };
+        instruction.execute(&mut self.registers);
+        self.executed += 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.registers = self.initial_registers;
+        self.executed = 0;
+    }
+}
+
+impl Scrubbable for Input {
+    type Snapshot = (Registers, usize);
+
+    fn position(&self) -> usize {
+        self.executed
+    }
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn is_finished(&self) -> bool {
+        self.registers.pc >= self.program.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.registers, self.executed)
+    }
+
+    fn restore(&mut self, (registers, executed): &Self::Snapshot) {
+        self.registers = *registers;
+        self.executed = *executed;
+    }
+}
+
+/// The program was still running after [`MAX_INSTRUCTIONS`] instructions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotHalting;
+
+impl Display for NotHalting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the program did not halt after {MAX_INSTRUCTIONS} instructions"
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Registers {
     pub pc: usize,
@@ -130,7 +249,7 @@ impl Instruction {
         let mut res = None;
         match self {
             Instruction::Adv(combo) => {
-                registers.a /= 2usize.pow(u32::try_from(combo.get_value(registers)).unwrap())
+                registers.a = divide(registers.a, combo.get_value(registers))
             }
             Instruction::Bxl(literal) => registers.b ^= usize::from(*literal),
             Instruction::Bst(combo) => registers.b = combo.get_value(registers) % 8,
@@ -141,21 +260,27 @@ impl Instruction {
             }
             Instruction::Bxc(_) => registers.b ^= registers.c,
             Instruction::Out(combo) => {
-                res.replace(combo.get_value(registers) % 8);
+                res.replace((combo.get_value(registers) % 8) as u8);
             }
             Instruction::Bdv(combo) => {
-                registers.b =
-                    registers.a / 2usize.pow(u32::try_from(combo.get_value(registers)).unwrap())
+                registers.b = divide(registers.a, combo.get_value(registers))
             }
             Instruction::Cdv(combo) => {
-                registers.c =
-                    registers.a / 2usize.pow(u32::try_from(combo.get_value(registers)).unwrap())
+                registers.c = divide(registers.a, combo.get_value(registers))
             }
         }
-        res.map(|val| u8::try_from(val).unwrap())
+        res
     }
 }
 
+/// `numerator / 2^exponent`, which is 0 once the power is larger than any register
+fn divide(numerator: usize, exponent: usize) -> usize {
+    u32::try_from(exponent)
+        .ok()
+        .and_then(|exponent| numerator.checked_shr(exponent))
+        .unwrap_or(0)
+}
+
 impl Display for Instruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -177,30 +302,26 @@ pub enum ComboOperand {
     RegisterA,
     RegisterB,
     RegisterC,
-    Reserved,
 }
 
 impl ComboOperand {
+    /// Operand 7 is reserved, it does not appear in valid programs
+    pub fn new(value: u8) -> Option<Self> {
+        match value {
+            val @ 0..=3 => Some(Self::Literal(val)),
+            4 => Some(Self::RegisterA),
+            5 => Some(Self::RegisterB),
+            6 => Some(Self::RegisterC),
+            _ => None,
+        }
+    }
+
     pub fn get_value(&self, registers: &Registers) -> usize {
         match self {
             ComboOperand::Literal(val) => usize::from(*val),
             ComboOperand::RegisterA => registers.a,
             ComboOperand::RegisterB => registers.b,
             ComboOperand::RegisterC => registers.c,
-            ComboOperand::Reserved => unreachable!("Reserved combo operand is not usable"),
-        }
-    }
-}
-
-impl From<u8> for ComboOperand {
-    fn from(value: u8) -> Self {
-        match value {
-            val @ 0..=3 => Self::Literal(val),
-            4 => Self::RegisterA,
-            5 => Self::RegisterB,
-            6 => Self::RegisterC,
-            7 => Self::Reserved,
-            _ => unreachable!("AoC inputs are well formed"),
         }
     }
 }
@@ -212,7 +333,6 @@ impl Display for ComboOperand {
             Self::RegisterA => write!(f, "$A"),
             Self::RegisterB => write!(f, "$B"),
             Self::RegisterC => write!(f, "$C"),
-            Self::Reserved => write!(f, "$X"),
         }
     }
 }
@@ -236,14 +356,19 @@ impl Debugger {
 
             let mut vm = self.vm.clone();
 
-            let test = j << (i * 3);
-            vm.registers.a = a | test;
+            // Digits past the width of the register can't be produced by any value
+            if let Some(test) = u32::try_from(i * 3)
+                .ok()
+                .and_then(|shift| j.checked_shl(shift))
+            {
+                vm.registers.a = a | test;
 
-            let out = vm.execute();
+                let out = vm.execute().unwrap_or_default();
 
-            if let Some(digit) = out.get(i).copied() {
-                if u64::from(digit) == u64::from(self.vm.raw_program[i]) {
-                    self.buffer.push(a | test);
+                if let Some(digit) = out.get(i).copied() {
+                    if u64::from(digit) == u64::from(self.vm.raw_program[i]) {
+                        self.buffer.push(a | test);
+                    }
                 }
             }
 
@@ -255,10 +380,41 @@ impl Debugger {
                 } else {
                     self.buffer.reverse();
                     std::mem::swap(&mut self.buffer, &mut self.queue);
-                    self.a = self.queue.pop().unwrap();
                     self.i -= 1;
+                    // Once every digit matches the candidates are all quines, stop on the smallest
+                    let next = if self.i == 0 {
+                        self.queue.iter().min().copied()
+                    } else {
+                        self.queue.pop()
+                    };
+                    // Without candidates no value of register A outputs the program
+                    match next {
+                        Some(a) => self.a = a,
+                        None => self.i = 0,
+                    }
                 }
             }
         }
     }
 }
+
+impl Simulation for Debugger {
+    fn steps_per_second(&self) -> f32 {
+        5.
+    }
+
+    fn step(&mut self) -> bool {
+        if self.i == 0 {
+            return false;
+        }
+        Debugger::step(self);
+        while self.j != 0 {
+            Debugger::step(self);
+        }
+        true
+    }
+
+    fn reset(&mut self) {
+        *self = self.vm.debug();
+    }
+}
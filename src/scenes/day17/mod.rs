@@ -2,24 +2,12 @@ mod input;
 mod part1;
 mod part2;
 
-use bevy::{
-    app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
-};
-
-use crate::loader::RawInput as InputAsset;
+use crate::loader::{ParseError, PuzzleSizes, RawInput as InputAsset};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    states::Part,
 };
 
 use self::input::Input;
@@ -28,61 +16,93 @@ pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin));
-
-        app.add_computed_state::<VisualizationState<17>>();
-
-        app.add_systems(OnEnter(Scene::Day(17)), build_day_17)
-            .add_systems(OnExit(Scene::Day(17)), destroy_day_17)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<17>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(17))),
-            );
+        app.add_plugins((
+            DayPlugin::<2024, 17, Input>::default(),
+            part1::Plugin,
+            part2::Plugin,
+        ));
     }
 }
 
-fn build_day_17(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day17_camera"), Camera2d)).id();
-    let day17_resource = GenericDay {
-        input: asset_server.load("inputs/day17.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day17_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &InputAsset,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day17_resource);
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.output().unwrap_or_else(|error| error.to_string()),
+            Part::Part2 => self
+                .quine()
+                .map_or_else(|| String::from("no solution"), |a| a.to_string()),
+        }
+    }
 }
 
-fn destroy_day_17(mut commands: Commands, day17_resource: Res<GenericDay>) {
-    commands.entity(day17_resource.camera).despawn_recursive();
-    commands.entity(day17_resource.ui).despawn_recursive();
-
-    commands.remove_resource::<GenericDay>();
+pub fn solve(
+    input: &InputAsset,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn process_input(
-    mut commands: Commands,
-    day17_resource: Res<GenericDay>,
-    inputs: Res<Assets<InputAsset>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day17_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
+
+    use super::input::{Input, NotHalting};
+
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day17.txt").to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["5,7,3,0", "117440"]
+        );
+    }
+
+    #[test]
+    fn reserved_combo_operand() {
+        let input =
+            RawInput(b"Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,5,7\n".to_vec());
+
+        let error = Input::parse(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 16));
+        assert_eq!(error.reason, "combo operand 7 is reserved");
+    }
+
+    #[test]
+    fn no_quine() {
+        let input = RawInput(
+            b"Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,0,3,3,0\n".to_vec(),
+        );
+
+        assert_eq!(Input::parse(&input).unwrap().quine(), None);
+    }
+
+    #[test]
+    fn endless_program() {
+        let input =
+            RawInput(b"Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n".to_vec());
+
+        assert_eq!(Input::parse(&input).unwrap().output(), Err(NotHalting));
+    }
+
+    #[test]
+    fn large_shift() {
+        let input = RawInput(
+            b"Register A: 100\nRegister B: 0\nRegister C: 0\n\nProgram: 0,4,5,4\n".to_vec(),
+        );
+
+        assert_eq!(Input::parse(&input).unwrap().output().unwrap(), "0");
     }
 }
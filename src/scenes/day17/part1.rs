@@ -5,7 +5,8 @@ use bevy::{
     color::{palettes, Color},
     prelude::{
         in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, Component, Condition,
-        DespawnRecursiveExt, Entity, IntoSystemConfigs, NextState, Res, ResMut, Single, Text, With,
+        DespawnRecursiveExt, DetectChanges, Entity, IntoSystemConfigs, NextState, Ref, Res, ResMut,
+        Single, Text, With,
     },
     text::{TextColor, TextFont},
     time::common_conditions::on_timer,
@@ -15,10 +16,15 @@ use bevy::{
     },
 };
 
-use crate::scenes::{
-    days::{build_content, build_header},
-    resources::{FontHandles, GenericDay},
-    states::{Part, UiState, VisualizationState},
+use crate::{
+    loader::Answers,
+    scenes::{
+        answers::build_answer_badge,
+        days::{build_content, build_footer, build_header},
+        playback::{self, build_playback_controls, build_timeline},
+        resources::{FontHandles, GenericDay},
+        states::{Part, UiState, VisualizationState},
+    },
 };
 
 use super::input::Input;
@@ -29,16 +35,25 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<17>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 17>::WaitingUi)),
+            ),
         )
         .add_systems(
             Update,
             (
                 update_blinking_cursor.run_if(on_timer(Duration::from_secs(1))),
-                update_screen.run_if(on_timer(Duration::from_millis(200))),
+                playback::component_timeline_systems::<Input>(),
             )
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<17>::Ready))),
+                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<2024, 17>::Ready))),
+        )
+        .add_systems(
+            Update,
+            update_screen.run_if(
+                in_state(Part::Part1)
+                    .and(in_state(VisualizationState::<2024, 17>::Ready))
+                    .and(update_screen_condition),
+            ),
         );
     }
 }
@@ -49,30 +64,36 @@ fn build_ui(
     mut input: ResMut<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 17 Part 1");
-    let header = build_header(&mut commands, "day17", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day17", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day17");
+    let footer = build_footer(&mut commands, "day17");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &mut input));
+        .with_children(|parent| build_visualization(parent, &mut input, &answers, &fonts));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<Input>(parent, &fonts);
+        build_timeline::<Input>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day17_resource.ui)
         .despawn_descendants()
-        .add_children(&[header, content]);
+        .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
-    let output = input
-        .clone()
-        .execute()
-        .iter()
-        .map(u8::to_string)
-        .collect::<Vec<_>>();
+fn build_visualization(
+    parent: &mut ChildBuilder,
+    input: &mut Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
+    let output = input.output().unwrap_or_else(|error| error.to_string());
 
     parent
         .spawn(Node {
@@ -105,7 +126,16 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child(Text::new(output.join(",")));
+                        .with_children(|parent| {
+                            parent.spawn(Text::new(output.to_string()));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (17, Part::Part1),
+                                &output.to_string(),
+                                fonts.symbol2.clone(),
+                            );
+                        });
                 });
 
             parent
@@ -160,18 +190,17 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
         });
 }
 
+fn update_screen_condition(screen: Single<Ref<Input>>) -> bool {
+    screen.is_changed()
+}
+
 fn update_screen(
     mut commands: Commands,
-    mut screen: Single<(Entity, &mut Input)>,
+    screen: Single<(Entity, &Input)>,
     fonts: Res<FontHandles>,
 ) {
     let entity = screen.0;
-
-    let program = &mut screen.1;
-    if program.registers.pc < program.program.len() {
-        let instruction = program.program[program.registers.pc];
-        instruction.execute(&mut program.registers);
-    }
+    let program = screen.1;
 
     commands
         .entity(entity)
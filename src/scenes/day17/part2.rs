@@ -1,23 +1,26 @@
-use std::time::Duration;
-
 use bevy::{
     app::Update,
     color::Color,
     prelude::{
         in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, Condition,
-        DespawnRecursiveExt, Entity, IntoSystemConfigs, NextState, Res, ResMut, Single, Text,
+        DespawnRecursiveExt, DetectChanges, Entity, IntoSystemConfigs, NextState, Ref, Res, ResMut,
+        Single, Text,
     },
-    time::common_conditions::on_timer,
     ui::{
         BorderColor, BorderRadius, Display, FlexDirection, GridPlacement, Node, PositionType,
         UiRect, Val,
     },
 };
 
-use crate::scenes::{
-    days::{build_content, build_header},
-    resources::{FontHandles, GenericDay},
-    states::{Part, UiState, VisualizationState},
+use crate::{
+    loader::Answers,
+    scenes::{
+        answers::build_answer_badge,
+        days::{build_content, build_footer, build_header},
+        playback::{self, build_playback_controls},
+        resources::{FontHandles, GenericDay},
+        states::{Part, UiState, VisualizationState},
+    },
 };
 
 use super::input::{Debugger, Input};
@@ -28,15 +31,21 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<17>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 17>::WaitingUi)),
+            ),
+        )
+        .add_systems(
+            Update,
+            playback::component_systems::<Debugger>()
+                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<2024, 17>::Ready))),
         )
         .add_systems(
             Update,
             update_debugger.run_if(
                 in_state(Part::Part2)
-                    .and(in_state(VisualizationState::<17>::Ready))
-                    .and(on_timer(Duration::from_millis(200))),
+                    .and(in_state(VisualizationState::<2024, 17>::Ready))
+                    .and(update_debugger_condition),
             ),
         );
     }
@@ -48,31 +57,37 @@ fn build_ui(
     mut input: ResMut<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 17 Part 2");
-    let header = build_header(&mut commands, "day17", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day17", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day17");
+    let footer = build_footer(&mut commands, "day17");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &mut input));
+        .with_children(|parent| build_visualization(parent, &mut input, &answers, &fonts));
+    commands
+        .entity(footer)
+        .with_children(|parent| build_playback_controls::<Debugger>(parent, &fonts));
 
     commands
         .entity(day17_resource.ui)
         .despawn_descendants()
-        .add_children(&[header, content]);
+        .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
-    let result = {
-        let mut debug = input.debug();
-        while debug.i > 0 {
-            debug.step();
-        }
-        debug.queue.iter().min().copied().unwrap()
-    };
+fn build_visualization(
+    parent: &mut ChildBuilder,
+    input: &mut Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
+    let result = input
+        .quine()
+        .map_or_else(|| String::from("no solution"), |a| a.to_string());
     let debugger = input.debug();
 
     parent
@@ -106,7 +121,16 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child(Text::new(result.to_string()));
+                        .with_children(|parent| {
+                            parent.spawn(Text::new(result.clone()));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (17, Part::Part2),
+                                &result,
+                                fonts.symbol2.clone(),
+                            );
+                        });
                 });
 
             parent.spawn((
@@ -119,14 +143,11 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
         });
 }
 
-fn update_debugger(mut commands: Commands, mut debugger: Single<(Entity, &mut Debugger)>) {
-    if debugger.1.i != 0 {
-        debugger.1.step();
-        while debugger.1.j != 0 {
-            debugger.1.step();
-        }
-    }
+fn update_debugger_condition(debugger: Single<Ref<Debugger>>) -> bool {
+    debugger.is_changed()
+}
 
+fn update_debugger(mut commands: Commands, debugger: Single<(Entity, &Debugger)>) {
     commands
         .entity(debugger.0)
         .despawn_descendants()
@@ -139,7 +160,9 @@ fn update_debugger(mut commands: Commands, mut debugger: Single<(Entity, &mut De
                 .with_children(|grid| {
                     let mut step_debugger = debugger.1.vm.clone();
                     step_debugger.registers.a = debugger.1.a;
-                    let output = step_debugger.execute();
+                    let output = step_debugger
+                        .output()
+                        .unwrap_or_else(|error| error.to_string());
 
                     grid.spawn((
                         Node {
@@ -180,13 +203,7 @@ fn update_debugger(mut commands: Commands, mut debugger: Single<(Entity, &mut De
                         },
                         BorderColor(Color::WHITE),
                     ))
-                    .with_child(Text::new(
-                        output
-                            .iter()
-                            .map(u8::to_string)
-                            .collect::<Vec<_>>()
-                            .join(","),
-                    ));
+                    .with_child(Text::new(output));
                     grid.spawn((
                         Node {
                             border: UiRect::all(Val::Px(1.)),
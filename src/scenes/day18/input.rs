@@ -1,28 +1,120 @@
-use bevy::prelude::{Component, Resource};
+use std::collections::BTreeSet;
 
-use crate::{loader::RawInput, tools::Coord};
+use bevy::prelude::{Component, Resource};
 
-pub const BOUNDS: Coord = Coord::new(71, 71);
+use crate::{
+    loader::{ParseError, PuzzleSizes, RawInput},
+    scenes::compute::Progress,
+    tools::{Coord, Maze, Vec2d},
+};
 
 #[derive(Debug, Clone, Resource, Component)]
 pub struct Input {
+    pub bounds: Coord,
+    pub exit: Coord,
+    pub fallen: usize,
     pub bytes: Vec<Coord>,
+    /// First byte that cuts the exit off, `None` when the exit stays reachable
+    pub blockage: Option<Coord>,
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input.split(|c| *c == b'\n').filter(|line| !line.is_empty());
+    pub fn parse(input: &RawInput, sizes: PuzzleSizes) -> Result<Self, ParseError> {
+        let (width, height) = sizes.memory_space;
+        if width == 0 || height == 0 {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!("the {width}x{height} memory space is empty"),
+            ));
+        }
+        let (bounds, fallen) = (Coord::new(height, width), sizes.fallen_bytes);
+        let input = input.lines().filter(|line| !line.is_empty());
 
         let bytes = input
             .map(|line| {
-                let vals = line.split(|c| *c == b',').collect::<Vec<_>>();
-                Coord::new(
-                    String::from_utf8_lossy(vals[1]).parse().unwrap(),
-                    String::from_utf8_lossy(vals[0]).parse().unwrap(),
-                )
+                let (x, y) = line.split_field(line.bytes, b",")?;
+                let byte = Coord::new(line.number(y)?, line.number(x)?);
+                if byte.row < bounds.row && byte.column < bounds.column {
+                    Ok(byte)
+                } else {
+                    Err(line.error(0, "byte falls outside of the memory space"))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if bytes.len() < fallen {
+            return Err(ParseError::new(
+                bytes.len() + 1,
+                1,
+                format!("expected at least {fallen} bytes, found {}", bytes.len()),
+            ));
+        }
+
+        Ok(Self {
+            bounds,
+            exit: Coord::new(bounds.row - 1, bounds.column - 1),
+            fallen,
+            bytes,
+            blockage: None,
+        })
+    }
+
+    pub fn solve_blockage(&mut self, progress: &Progress) -> Option<Coord> {
+        self.blockage = self.find_blockage(progress);
+        self.blockage
+    }
+
+    /// Tiles of a shortest path from the top left corner to the exit, `None` when the exit can't
+    /// be reached
+    pub fn shortest_path(&self, fallen: usize) -> Option<BTreeSet<Coord>> {
+        let obstacles = BTreeSet::from_iter(self.bytes[..fallen].iter());
+        if obstacles.contains(&Coord::new(0, 0)) || obstacles.contains(&self.exit) {
+            return None;
+        }
+        let obstacles_ref = &obstacles;
+        let exit = self.exit;
+        let mut maze_tiles = (0..self.bounds.row)
+            .flat_map(|y| {
+                (0..self.bounds.column).map(move |x| {
+                    let coord = Coord::new(y, x);
+                    if obstacles_ref.contains(&coord) {
+                        b'#'
+                    } else if coord == Coord::new(0, 0) {
+                        b'S'
+                    } else if coord == exit {
+                        b'E'
+                    } else {
+                        b'.'
+                    }
+                })
             })
-            .collect();
+            .collect::<Vec<_>>();
+        let maze_vec = Vec2d::new(&mut maze_tiles, self.bounds.column, self.bounds.row);
+        let maze = Maze::new(maze_vec, Coord::new(0, 0), self.exit, self.bounds, 0);
+
+        let (mut scores_data, path) = maze.calculate_tile_scores();
+        let scores = Vec2d::new(&mut scores_data, self.bounds.column, self.bounds.row);
+        let mut used_scores = BTreeSet::new();
+        Some(BTreeSet::from_iter(
+            path.into_iter()
+                .filter(|coord| used_scores.insert(scores[*coord])),
+        ))
+        .filter(|path| path.contains(&Coord::new(0, 0)))
+    }
+
+    /// Steps of the shortest path once the first `fallen` bytes are down
+    pub fn steps(&self, fallen: usize) -> Option<usize> {
+        self.shortest_path(fallen).map(|path| path.len() - 1)
+    }
 
-        Self { bytes }
+    fn find_blockage(&self, progress: &Progress) -> Option<Coord> {
+        let len = self.bytes.len();
+        // The byte after the most bytes that still leave a path, there is none when all of them do
+        (0..=len)
+            .rev()
+            .take_while(|_| !progress.is_cancelled())
+            .inspect(|step| progress.report(len - step, len))
+            .find(|step| self.shortest_path(*step).is_some())
+            .and_then(|step| self.bytes.get(step).copied())
     }
 }
@@ -2,24 +2,12 @@ mod input;
 mod part1;
 mod part2;
 
-use bevy::{
-    app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
-};
-
-use crate::loader::RawInput as InputAsset;
+use crate::loader::{ParseError, PuzzleSizes, RawInput as InputAsset};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    states::Part,
 };
 
 use self::input::Input;
@@ -28,61 +16,108 @@ pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin));
+        app.add_plugins((
+            DayPlugin::<2024, 18, Input>::default(),
+            part1::Plugin,
+            part2::Plugin,
+        ));
+    }
+}
 
-        app.add_computed_state::<VisualizationState<18>>();
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &InputAsset,
+        sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input, sizes)
+    }
 
-        app.add_systems(OnEnter(Scene::Day(18)), build_day_18)
-            .add_systems(OnExit(Scene::Day(18)), destroy_day_18)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<18>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(18))),
-            );
+    fn solve_part(&mut self, part: Part, progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self
+                .steps(self.fallen)
+                .map_or_else(|| String::from("no solution"), |steps| steps.to_string()),
+            Part::Part2 => self.solve_blockage(progress).map_or_else(
+                || String::from("no solution"),
+                |blockage| format!("{},{}", blockage.column, blockage.row),
+            ),
+        }
     }
 }
 
-fn build_day_18(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day18_camera"), Camera2d)).id();
-    let day18_resource = GenericDay {
-        input: asset_server.load("inputs/day18.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day18_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
+pub fn solve(
+    input: &InputAsset,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
     };
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day18_resource);
-}
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day18.txt").to_vec());
 
-fn destroy_day_18(mut commands: Commands, day18_resource: Res<GenericDay>) {
-    commands.entity(day18_resource.camera).despawn_recursive();
-    commands.entity(day18_resource.ui).despawn_recursive();
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["22", "6,1"]
+        );
+    }
 
-    commands.remove_resource::<GenericDay>();
-}
+    #[test]
+    fn exit_cut_off() {
+        let mut bytes = b"1,0\n0,1\n".to_vec();
+        bytes.extend(b"3,3\n".repeat(10));
+
+        assert_eq!(
+            super::solve(&RawInput(bytes), PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["no solution", "0,1"]
+        );
+    }
+
+    #[test]
+    fn no_bytes() {
+        let sizes = PuzzleSizes {
+            fallen_bytes: 0,
+            ..PuzzleSizes::EXAMPLE
+        };
 
-fn process_input(
-    mut commands: Commands,
-    day18_resource: Res<GenericDay>,
-    inputs: Res<Assets<InputAsset>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day18_resource.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+        assert_eq!(
+            super::solve(&RawInput(Vec::new()), sizes, &Progress::default()).unwrap(),
+            ["12", "no solution"]
+        );
+    }
+
+    #[test]
+    fn custom_sizes() {
+        let sizes = PuzzleSizes {
+            memory_space: (3, 2),
+            fallen_bytes: 1,
+            ..PuzzleSizes::REAL
+        };
+
+        assert_eq!(
+            super::solve(
+                &RawInput(b"1,0\n1,1\n".to_vec()),
+                sizes,
+                &Progress::default()
+            )
+            .unwrap(),
+            ["3", "1,1"]
+        );
+        let error = super::solve(
+            &RawInput(b"1,0\n3,0\n".to_vec()),
+            sizes,
+            &Progress::default(),
+        )
+        .unwrap_err();
+        assert_eq!(error.line, 2);
     }
 }
@@ -1,5 +1,3 @@
-use std::collections::BTreeSet;
-
 use bevy::{
     app::Update,
     color::{palettes, Color},
@@ -7,21 +5,20 @@ use bevy::{
         in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, Condition,
         DespawnRecursiveExt, IntoSystemConfigs, NextState, Res, ResMut, Text,
     },
-    ui::{
-        BackgroundColor, BorderColor, BorderRadius, FlexDirection, Node, PositionType, UiRect, Val,
-    },
+    ui::{BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType, UiRect, Val},
 };
 
 use crate::{
     scenes::{
         days::{build_content, build_header},
+        grid_image::GridImage,
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
-    tools::{Coord, Maze, Vec2d},
+    scroll_controls::PanZoom,
 };
 
-use super::input::{Input, BOUNDS};
+use super::input::Input;
 
 pub struct Plugin;
 
@@ -29,8 +26,9 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<18>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 18>::WaitingUi)),
+            ),
         );
     }
 }
@@ -43,7 +41,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 18 Part 1");
-    let header = build_header(&mut commands, "day18", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day18", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day18");
 
     commands
@@ -59,36 +57,18 @@ fn build_ui(
 }
 
 fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
-    let obstacles = BTreeSet::from_iter(input.bytes[..1024].iter());
-    let obstacles_ref = &obstacles;
-    let mut maze_tiles = (0..BOUNDS.row)
-        .flat_map(|y| {
-            (0..BOUNDS.column).map(move |x| {
-                let coord = Coord::new(y, x);
-                if obstacles_ref.contains(&coord) {
-                    b'#'
-                } else if coord == Coord::new(0, 0) {
-                    b'S'
-                } else if coord == Coord::new(70, 70) {
-                    b'E'
-                } else {
-                    b'.'
-                }
-            })
-        })
-        .collect::<Vec<_>>();
-    let maze_vec = Vec2d::new(&mut maze_tiles, BOUNDS.row, BOUNDS.column);
-    let maze = Maze::new(maze_vec, Coord::new(0, 0), Coord::new(70, 70), BOUNDS, 0);
+    let path = input.shortest_path(input.fallen).unwrap_or_default();
+    let steps = input
+        .steps(input.fallen)
+        .map_or_else(|| String::from("no solution"), |steps| steps.to_string());
 
-    let (mut scores_data, path) = maze.calculate_tile_scores();
-    let path = {
-        let scores = Vec2d::new(&mut scores_data, BOUNDS.row, BOUNDS.column);
-        let mut used_scores = BTreeSet::new();
-        BTreeSet::from_iter(
-            path.iter()
-                .filter(|coord| used_scores.insert(scores[**coord])),
-        )
-    };
+    let mut grid = GridImage::new(input.bounds, palettes::tailwind::YELLOW_300.into());
+    for coord in path.iter() {
+        grid.set(*coord, palettes::tailwind::GREEN_300.into());
+    }
+    for coord in input.bytes[..input.fallen].iter() {
+        grid.set(*coord, palettes::tailwind::GRAY_700.into());
+    }
 
     parent
         .spawn(Node {
@@ -119,16 +99,21 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child(Text::new((path.len() - 1).to_string()));
+                        .with_child(Text::new(steps));
                 });
 
             parent
-                .spawn(Node {
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Row,
-                    align_self: bevy::ui::AlignSelf::Center,
-                    ..Default::default()
-                })
+                .spawn((
+                    Node {
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
+                        ..Default::default()
+                    },
+                    PanZoom::default(),
+                ))
                 .with_children(|parent| {
                     parent
                         .spawn((
@@ -136,44 +121,24 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                                 height: Val::Percent(100.),
                                 flex_direction: FlexDirection::Column,
                                 flex_wrap: bevy::ui::FlexWrap::NoWrap,
-                                aspect_ratio: Some(BOUNDS.column as f32 / BOUNDS.row as f32),
+                                flex_shrink: 0.,
+                                aspect_ratio: Some(
+                                    input.bounds.column as f32 / input.bounds.row as f32,
+                                ),
                                 border: UiRect::all(Val::Px(3.)),
                                 ..Default::default()
                             },
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_children(|parent| {
-                            for y in 0..BOUNDS.row {
-                                parent
-                                    .spawn(Node {
-                                        width: Val::Percent(100.),
-                                        height: Val::Percent(10000. / maze.height() as f32),
-                                        flex_direction: FlexDirection::Row,
-                                        ..Default::default()
-                                    })
-                                    .with_children(|parent| {
-                                        for x in 0..BOUNDS.column {
-                                            let coord = Coord::new(y, x);
-                                            let color = if obstacles.contains(&coord) {
-                                                palettes::tailwind::GRAY_700.into()
-                                            } else if path.contains(&coord) {
-                                                palettes::tailwind::GREEN_300.into()
-                                            } else {
-                                                palettes::tailwind::YELLOW_300.into()
-                                            };
-                                            parent.spawn((
-                                                Node {
-                                                    height: Val::Percent(100.),
-                                                    aspect_ratio: Some(1.),
-                                                    ..Default::default()
-                                                },
-                                                BackgroundColor(color),
-                                            ));
-                                        }
-                                    });
-                            }
-                        });
+                        .with_child((
+                            Node {
+                                width: Val::Percent(100.),
+                                height: Val::Percent(100.),
+                                ..Default::default()
+                            },
+                            grid,
+                        ));
                 });
         });
 }
@@ -1,29 +1,29 @@
-use std::{collections::BTreeSet, time::Duration};
+use std::collections::BTreeSet;
 
 use bevy::{
     app::Update,
     color::{palettes, Color},
     prelude::{
-        in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, Component, Condition,
-        DespawnRecursiveExt, Entity, IntoSystemConfigs, Local, NextState, Res, ResMut, Single,
-        Text,
-    },
-    time::common_conditions::on_timer,
-    ui::{
-        BackgroundColor, BorderColor, BorderRadius, FlexDirection, Node, PositionType, UiRect, Val,
+        in_state, BuildChildren, ChildBuild, ChildBuilder, Children, Commands, Component,
+        Condition, DespawnRecursiveExt, DetectChanges, IntoSystemConfigs, NextState, Query, Ref,
+        Res, ResMut, Single, Text,
     },
+    ui::{BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType, UiRect, Val},
 };
 
 use crate::{
     scenes::{
-        days::{build_content, build_header},
+        days::{build_content, build_footer, build_header},
+        grid_image::GridImage,
+        playback::{self, build_playback_controls, build_timeline, Scrubbable, Simulation},
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
-    tools::{Coord, Maze, Vec2d},
+    scroll_controls::PanZoom,
+    tools::Coord,
 };
 
-use super::input::{Input, BOUNDS};
+use super::input::Input;
 
 pub struct Plugin;
 
@@ -31,15 +31,21 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<18>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 18>::WaitingUi)),
+            ),
+        )
+        .add_systems(
+            Update,
+            playback::component_timeline_systems::<Falling>()
+                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<2024, 18>::Ready))),
         )
         .add_systems(
             Update,
             update_screen.run_if(
                 in_state(Part::Part2)
-                    .and(in_state(VisualizationState::<18>::Ready))
-                    .and(on_timer(Duration::from_millis(50))),
+                    .and(in_state(VisualizationState::<2024, 18>::Ready))
+                    .and(update_screen_condition),
             ),
         );
     }
@@ -53,60 +59,35 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 18 Part 2");
-    let header = build_header(&mut commands, "day18", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day18", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day18");
+    let footer = build_footer(&mut commands, "day18");
 
     commands
         .entity(content)
         .with_children(|parent| build_visualization(parent, &mut input));
+    commands.entity(footer).with_children(|parent| {
+        build_playback_controls::<Falling>(parent, &fonts);
+        build_timeline::<Falling>(parent, fonts.font.clone());
+    });
 
     commands
         .entity(day18_resource.ui)
         .despawn_descendants()
-        .add_children(&[header, content]);
+        .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
 }
 
 fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
-    let mut blockage = input.bytes[input.bytes.len() - 1];
-    for step in (0..input.bytes.len()).rev() {
-        let obstacles = BTreeSet::from_iter(input.bytes[..step].iter());
-        let obstacles_ref = &obstacles;
-        let mut maze_tiles = (0..BOUNDS.row)
-            .flat_map(|y| {
-                (0..BOUNDS.column).map(move |x| {
-                    let coord = Coord::new(y, x);
-                    if obstacles_ref.contains(&coord) {
-                        b'#'
-                    } else if coord == Coord::new(0, 0) {
-                        b'S'
-                    } else if coord == Coord::new(70, 70) {
-                        b'E'
-                    } else {
-                        b'.'
-                    }
-                })
-            })
-            .collect::<Vec<_>>();
-        let maze_vec = Vec2d::new(&mut maze_tiles, BOUNDS.row, BOUNDS.column);
-        let maze = Maze::new(maze_vec, Coord::new(0, 0), Coord::new(70, 70), BOUNDS, 0);
-
-        let (mut scores_data, path) = maze.calculate_tile_scores();
-        let path = {
-            let scores = Vec2d::new(&mut scores_data, BOUNDS.row, BOUNDS.column);
-            let mut used_scores = BTreeSet::new();
-            BTreeSet::from_iter(
-                path.iter()
-                    .filter(|coord| used_scores.insert(scores[**coord])),
-            )
-        };
-
-        if path.contains(&maze.start()) {
-            blockage = input.bytes[step];
-            break;
-        }
-    }
+    let blockage = input.blockage;
+    let until = blockage
+        .and_then(|blockage| input.bytes.iter().position(|byte| *byte == blockage))
+        .map_or(input.bytes.len(), |index| index + 1);
+    let blockage_text = blockage.map_or_else(
+        || String::from("none"),
+        |blockage| format!("{},{}", blockage.column, blockage.row),
+    );
 
     parent
         .spawn(Node {
@@ -139,116 +120,147 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child(Text::new(format!("{},{}", blockage.column, blockage.row)));
+                        .with_child(Text::new(blockage_text));
                 });
 
             parent
-                .spawn(Node {
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Row,
-                    align_self: bevy::ui::AlignSelf::Center,
-                    ..Default::default()
-                })
+                .spawn((
+                    Node {
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
+                        ..Default::default()
+                    },
+                    PanZoom::default(),
+                ))
                 .with_children(|parent| {
-                    parent.spawn((
-                        Node {
-                            height: Val::Percent(100.),
-                            flex_direction: FlexDirection::Column,
-                            flex_wrap: bevy::ui::FlexWrap::NoWrap,
-                            aspect_ratio: Some(BOUNDS.column as f32 / BOUNDS.row as f32),
-                            border: UiRect::all(Val::Px(3.)),
-                            ..Default::default()
-                        },
-                        BorderColor(Color::WHITE),
-                        BorderRadius::all(Val::Px(5.)),
-                        input.clone(),
-                        Blockage(blockage),
-                    ));
+                    parent
+                        .spawn((
+                            Node {
+                                height: Val::Percent(100.),
+                                flex_direction: FlexDirection::Column,
+                                flex_wrap: bevy::ui::FlexWrap::NoWrap,
+                                flex_shrink: 0.,
+                                aspect_ratio: Some(
+                                    input.bounds.column as f32 / input.bounds.row as f32,
+                                ),
+                                border: UiRect::all(Val::Px(3.)),
+                                ..Default::default()
+                            },
+                            BorderColor(Color::WHITE),
+                            BorderRadius::all(Val::Px(5.)),
+                            input.clone(),
+                            Falling {
+                                fallen: 0,
+                                until,
+                                blockage,
+                            },
+                        ))
+                        .with_child((
+                            Node {
+                                width: Val::Percent(100.),
+                                height: Val::Percent(100.),
+                                ..Default::default()
+                            },
+                            GridImage::new(input.bounds, palettes::tailwind::YELLOW_300.into()),
+                        ));
                 });
         });
 }
 
-fn update_screen(
-    mut commands: Commands,
-    screen: Single<(Entity, &mut Input, &Blockage)>,
-    mut step: Local<usize>,
-) {
-    let obstacles = BTreeSet::from_iter(screen.1.bytes[..*step].iter());
-    let obstacles_ref = &obstacles;
-    let mut maze_tiles = (0..BOUNDS.row)
-        .flat_map(|y| {
-            (0..BOUNDS.column).map(move |x| {
-                let coord = Coord::new(y, x);
-                if obstacles_ref.contains(&coord) {
-                    b'#'
-                } else if coord == Coord::new(0, 0) {
-                    b'S'
-                } else if coord == Coord::new(70, 70) {
-                    b'E'
+fn update_screen_condition(falling: Single<Ref<Falling>>) -> bool {
+    falling.is_changed()
+}
+
+fn update_screen(screen: Single<(&Input, &Falling, &Children)>, mut grids: Query<&mut GridImage>) {
+    let (input, falling, children) = screen.into_inner();
+    let Some(mut grid) = children
+        .first()
+        .and_then(|child| grids.get_mut(*child).ok())
+    else {
+        return;
+    };
+
+    let obstacles = BTreeSet::from_iter(input.bytes[..falling.fallen].iter());
+    let path = input.shortest_path(falling.fallen).unwrap_or_default();
+
+    for row in 0..input.bounds.row {
+        for column in 0..input.bounds.column {
+            let coord = Coord::new(row, column);
+            let color = if obstacles.contains(&coord) {
+                if Some(coord) == falling.blockage {
+                    palettes::tailwind::RED_700
                 } else {
-                    b'.'
+                    palettes::tailwind::GRAY_700
                 }
-            })
-        })
-        .collect::<Vec<_>>();
-    let maze_vec = Vec2d::new(&mut maze_tiles, BOUNDS.row, BOUNDS.column);
-    let maze = Maze::new(maze_vec, Coord::new(0, 0), Coord::new(70, 70), BOUNDS, 0);
-
-    let (mut scores_data, path) = maze.calculate_tile_scores();
-    let path = {
-        let scores = Vec2d::new(&mut scores_data, BOUNDS.row, BOUNDS.column);
-        let mut used_scores = BTreeSet::new();
-        BTreeSet::from_iter(
-            path.iter()
-                .filter(|coord| used_scores.insert(scores[**coord])),
-        )
-    };
+            } else if path.contains(&coord) {
+                palettes::tailwind::GREEN_300
+            } else {
+                palettes::tailwind::YELLOW_300
+            };
+            grid.set(coord, color.into());
+        }
+    }
+}
 
-    if !path.contains(&maze.start()) && screen.2 .0 != screen.1.bytes[*step - 1] {
-        return;
+#[derive(Debug, Component)]
+struct Falling {
+    fallen: usize,
+    until: usize,
+    blockage: Option<Coord>,
+}
+
+impl Simulation for Falling {
+    const REVERSIBLE: bool = true;
+
+    fn steps_per_second(&self) -> f32 {
+        20.
     }
 
-    *step += 1;
+    fn step(&mut self) -> bool {
+        if self.fallen >= self.until {
+            return false;
+        }
+        self.fallen += 1;
+        true
+    }
 
-    commands
-        .entity(screen.0)
-        .despawn_descendants()
-        .with_children(|parent| {
-            for y in 0..BOUNDS.row {
-                parent
-                    .spawn(Node {
-                        width: Val::Percent(100.),
-                        height: Val::Percent(10000. / BOUNDS.row as f32),
-                        flex_direction: FlexDirection::Row,
-                        ..Default::default()
-                    })
-                    .with_children(|parent| {
-                        for x in 0..BOUNDS.column {
-                            let coord = Coord::new(y, x);
-                            let color = if obstacles.contains(&coord) {
-                                if coord == screen.2 .0 {
-                                    palettes::tailwind::RED_700.into()
-                                } else {
-                                    palettes::tailwind::GRAY_700.into()
-                                }
-                            } else if path.contains(&coord) {
-                                palettes::tailwind::GREEN_300.into()
-                            } else {
-                                palettes::tailwind::YELLOW_300.into()
-                            };
-                            parent.spawn((
-                                Node {
-                                    height: Val::Percent(100.),
-                                    aspect_ratio: Some(1.),
-                                    ..Default::default()
-                                },
-                                BackgroundColor(color),
-                            ));
-                        }
-                    });
-            }
-        });
+    fn step_back(&mut self) -> bool {
+        if self.fallen == 0 {
+            return false;
+        }
+        self.fallen -= 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.fallen = 0;
+    }
 }
 
-#[derive(Debug, Component)]
-struct Blockage(Coord);
+impl Scrubbable for Falling {
+    type Snapshot = usize;
+
+    fn position(&self) -> usize {
+        self.fallen
+    }
+
+    fn length(&self) -> usize {
+        self.until
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.fallen
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        self.fallen = *snapshot;
+    }
+
+    fn seek(&mut self, position: usize) -> bool {
+        self.fallen = position.min(self.until);
+        true
+    }
+}
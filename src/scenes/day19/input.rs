@@ -8,7 +8,7 @@ use bevy::{
     render::render_resource::{Extent3d, TextureDimension, TextureFormat},
 };
 
-use crate::loader::RawInput;
+use crate::loader::{Line, ParseError, RawInput};
 
 pub const IMAGE_HEIGHT: usize = 5;
 
@@ -20,49 +20,69 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput, asset_server: &AssetServer) -> Self {
-        let mut input = input.split(|c| *c == b'\n').filter(|line| !line.is_empty());
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let mut lines = input.lines().filter(|line| !line.is_empty());
 
-        let towels = input
-            .next()
-            .map(|line| {
-                line.split(|c| *c == b',')
-                    .map(|towel| String::from_utf8_lossy(towel.trim_ascii()))
-                    .collect::<Vec<_>>()
-            })
-            .unwrap();
+        let line = lines.next().ok_or_else(|| input.unexpected_end())?;
+        let towels = line
+            .split(|c| *c == b',')
+            .map(|towel| Self::stripes(line, towel.trim_ascii()))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        let longest_towel = towels.iter().max_by_key(|towel| towel.len()).unwrap().len();
+        let longest_towel = towels.iter().map(String::len).max().unwrap_or_default();
 
         let towels = towels
             .into_iter()
-            .map(|towel| {
-                let (stripes, image) = Self::towel_to_image(&towel, longest_towel, asset_server);
-                Towel { stripes, image }
+            .map(|stripes| Towel {
+                stripes,
+                image: Handle::default(),
             })
             .collect();
 
-        let _ = input.next().unwrap();
-
-        let patterns = {
-            let patterns = input
-                .map(|pattern| String::from_utf8_lossy(pattern))
-                .collect::<Vec<_>>();
-
-            patterns
-                .into_iter()
-                .map(|pattern| {
-                    let (pattern, image) = Self::pattern_to_image(&pattern, asset_server);
-                    Pattern { pattern, image }
+        let patterns = lines
+            .map(|line| {
+                Ok(Pattern {
+                    pattern: Self::stripes(line, line.bytes)?,
+                    image: Handle::default(),
                 })
-                .collect()
-        };
+            })
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             longest_towel,
             towels,
             patterns,
+        })
+    }
+
+    fn stripes(line: Line, stripes: &[u8]) -> Result<String, ParseError> {
+        if stripes.is_empty() {
+            return Err(line.error_at(stripes, "expected stripes"));
         }
+        match stripes.iter().position(|stripe| !b"wubrg".contains(stripe)) {
+            Some(i) => Err(line.error_at(
+                &stripes[i..],
+                format!("unexpected stripe `{}`", char::from(stripes[i])),
+            )),
+            None => Ok(String::from_utf8_lossy(stripes).to_string()),
+        }
+    }
+
+    pub fn load_images(&mut self, asset_server: &AssetServer) {
+        for towel in self.towels.iter_mut() {
+            towel.image =
+                asset_server.add(Self::towel_to_image(&towel.stripes, self.longest_towel));
+        }
+        for pattern in self.patterns.iter_mut() {
+            pattern.image = asset_server.add(Self::pattern_to_image(&pattern.pattern));
+        }
+    }
+
+    pub fn matched_patterns(&self) -> usize {
+        self.patterns
+            .iter()
+            .filter(|pattern| !self.match_pattern(pattern).is_empty())
+            .count()
     }
 
     pub fn match_pattern<'a>(&'a self, pattern: &Pattern) -> Vec<Vec<&'a Towel>> {
@@ -162,11 +182,7 @@ impl Input {
         }
     }
 
-    fn towel_to_image(
-        pattern: &str,
-        longest: usize,
-        asset_server: &AssetServer,
-    ) -> (String, Handle<Image>) {
+    fn towel_to_image(pattern: &str, longest: usize) -> Image {
         let mut image = Image::new(
             Extent3d {
                 width: u32::try_from(longest + 2).unwrap(),
@@ -194,10 +210,10 @@ impl Input {
         );
         image.sampler = ImageSampler::nearest();
 
-        (pattern.to_string(), asset_server.add(image))
+        image
     }
 
-    fn pattern_to_image(pattern: &str, asset_server: &AssetServer) -> (String, Handle<Image>) {
+    fn pattern_to_image(pattern: &str) -> Image {
         let length = pattern.len();
         let mut image = Image::new(
             Extent3d {
@@ -225,7 +241,7 @@ impl Input {
         );
         image.sampler = ImageSampler::nearest();
 
-        (pattern.to_string(), asset_server.add(image))
+        image
     }
 }
 
@@ -2,23 +2,16 @@ mod input;
 mod ui;
 
 use bevy::{
-    app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
+    asset::AssetServer,
+    prelude::{Commands, OnExit},
 };
 
-use crate::loader::RawInput as InputAsset;
+use crate::loader::{ParseError, PuzzleSizes, RawInput as InputAsset};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, Scene, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    states::{Part, Scene},
 };
 
 use self::input::Input;
@@ -27,63 +20,74 @@ pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins(ui::Plugin);
-
-        app.add_computed_state::<VisualizationState<19>>();
-
-        app.add_systems(OnEnter(Scene::Day(19)), build_day_19)
-            .add_systems(OnExit(Scene::Day(19)), destroy_day_19)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<19>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(19))),
-            );
+        app.add_plugins((DayPlugin::<2024, 19, Input>::default(), ui::Plugin));
+
+        app.add_systems(
+            OnExit(Scene::Day {
+                year: 2024,
+                day: 19,
+            }),
+            |mut commands: Commands| {
+                commands.remove_resource::<ui::SelectedPattern>();
+            },
+        );
     }
 }
 
-fn build_day_19(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day19_camera"), Camera2d)).id();
-    let day19_resource = GenericDay {
-        input: asset_server.load("inputs/day19.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day19_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &InputAsset,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day19_resource);
-}
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.matched_patterns().to_string(),
+            Part::Part2 => self.count_patterns().to_string(),
+        }
+    }
 
-fn destroy_day_19(mut commands: Commands, day19_resource: Res<GenericDay>) {
-    commands.entity(day19_resource.camera).despawn_recursive();
-    commands.entity(day19_resource.ui).despawn_recursive();
+    fn load_assets(&mut self, asset_server: &AssetServer) {
+        self.load_images(asset_server);
+    }
+}
 
-    commands.remove_resource::<GenericDay>();
-    commands.remove_resource::<ui::SelectedPattern>();
+pub fn solve(
+    input: &InputAsset,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn process_input(
-    mut commands: Commands,
-    day19_resource: Res<GenericDay>,
-    inputs: Res<Assets<InputAsset>>,
-    asset_server: Res<AssetServer>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day19_resource.input.id()) {
-        commands.insert_resource(Input::parse(input, &asset_server));
-        next_state.set(InputState::Loaded);
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
+
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day19.txt").to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["6", "16"]
+        );
+    }
+
+    /// The first pattern follows the blank line and is counted like the others
+    #[test]
+    fn first_pattern() {
+        let input = RawInput(b"r, wr, b\n\nrwrb\nggg\n".to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["1", "1"]
+        );
     }
 }
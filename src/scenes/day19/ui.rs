@@ -30,29 +30,32 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui::<false>
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<19>::WaitingUi))),
+            build_ui::<false>.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 19>::WaitingUi)),
+            ),
         )
         .add_systems(
             Update,
-            build_ui::<true>
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<19>::WaitingUi))),
+            build_ui::<true>.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 19>::WaitingUi)),
+            ),
         )
         .add_systems(
             Update,
-            pattern_button_interaction.run_if(in_state(VisualizationState::<19>::Ready)),
+            pattern_button_interaction.run_if(in_state(VisualizationState::<2024, 19>::Ready)),
         )
         .add_systems(
             Update,
             update_pattern_buttons.run_if(
-                in_state(VisualizationState::<19>::Ready).and(resource_changed::<SelectedPattern>),
+                in_state(VisualizationState::<2024, 19>::Ready)
+                    .and(resource_changed::<SelectedPattern>),
             ),
         )
         .add_systems(
             Update,
             update_canvas::<1>.run_if(
                 in_state(Part::Part1)
-                    .and(in_state(VisualizationState::<19>::Ready))
+                    .and(in_state(VisualizationState::<2024, 19>::Ready))
                     .and(resource_changed::<SelectedPattern>),
             ),
         )
@@ -60,7 +63,7 @@ impl bevy::app::Plugin for Plugin {
             Update,
             update_canvas::<16>.run_if(
                 in_state(Part::Part2)
-                    .and(in_state(VisualizationState::<19>::Ready))
+                    .and(in_state(VisualizationState::<2024, 19>::Ready))
                     .and(resource_changed::<SelectedPattern>),
             ),
         );
@@ -75,7 +78,7 @@ fn build_ui<const PART2: bool>(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 19");
-    let header = build_header(&mut commands, "day19", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day19", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day19");
 
     commands.insert_resource(SelectedPattern(input.patterns[0].clone()));
@@ -97,11 +100,7 @@ fn build_visualization<const PART2: bool>(parent: &mut ChildBuilder, input: &Inp
         let count: usize = input.count_patterns();
         ("Possible patterns", count)
     } else {
-        let count: usize = input
-            .patterns
-            .iter()
-            .filter(|pattern| !input.match_pattern(pattern).is_empty())
-            .count();
+        let count = input.matched_patterns();
         ("Possible patterns", count)
     };
 
@@ -6,35 +6,79 @@ use std::{
 use bevy::prelude::{Component, Resource};
 
 use crate::{
-    loader::RawInput,
+    loader::{ParseError, PuzzleSizes, RawInput},
+    scenes::compute::Progress,
     tools::{Coord, Maze, Vec2d},
 };
 
 #[derive(Debug, Clone, Resource, Component)]
 pub struct Input {
     pub data: Vec<u8>,
+    /// Picoseconds a cheat has to save to be counted, in each part
+    pub min_saving: [usize; 2],
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        Self {
-            data: input.0.clone(),
+    pub fn parse(input: &RawInput, sizes: PuzzleSizes) -> Result<Self, ParseError> {
+        let maze = input.grid(|tile| b"#.SE".contains(&tile))?;
+        for tile in [b'S', b'E'] {
+            let count = maze
+                .iter()
+                .flat_map(|row| row.iter())
+                .filter(|t| **t == tile)
+                .count();
+            if count != 1 {
+                return Err(ParseError::new(
+                    1,
+                    1,
+                    format!("expected one `{}` tile, found {count}", char::from(tile)),
+                ));
+            }
         }
+
+        Ok(Self {
+            data: input.0.clone(),
+            min_saving: sizes.min_cheat_saving,
+        })
     }
 
-    pub fn cheat(&mut self, cheat_len: usize) -> BTreeMap<Coord, Vec<(Coord, usize)>> {
+    pub fn cheat(
+        &mut self,
+        cheat_len: usize,
+        progress: &Progress,
+    ) -> BTreeMap<Coord, Vec<(Coord, usize)>> {
         let maze = Maze::parse(&mut self.data, 0);
 
         let (mut tile_cost_data, main_path) = maze.calculate_tile_scores();
         let tile_cost = Vec2d::new(tile_cost_data.as_mut_slice(), maze.width(), maze.height());
 
-        main_path.iter().fold(BTreeMap::new(), |mut map, start| {
-            map.insert(
-                *start,
-                Vec::from_iter(Self::find_reachable(*start, &tile_cost, cheat_len)),
-            );
-            map
-        })
+        main_path
+            .iter()
+            .enumerate()
+            .take_while(|_| !progress.is_cancelled())
+            .inspect(|(i, _)| progress.report(*i, main_path.len()))
+            .fold(BTreeMap::new(), |mut map, (_, start)| {
+                map.insert(
+                    *start,
+                    Vec::from_iter(Self::find_reachable(*start, &tile_cost, cheat_len)),
+                );
+                map
+            })
+    }
+
+    pub fn shortcuts(&mut self, cheat_len: usize, min_saving: usize, progress: &Progress) -> usize {
+        Self::count_shortcuts(&self.cheat(cheat_len, progress), min_saving)
+    }
+
+    /// Cheats among `cheats` that save at least `min_saving` picoseconds
+    pub fn count_shortcuts(
+        cheats: &BTreeMap<Coord, Vec<(Coord, usize)>>,
+        min_saving: usize,
+    ) -> usize {
+        cheats
+            .values()
+            .map(|ends| ends.iter().filter(|len| len.1 >= min_saving).count())
+            .sum()
     }
 
     fn find_reachable(
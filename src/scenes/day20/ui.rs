@@ -1,23 +1,29 @@
+use std::collections::BTreeMap;
+
 use bevy::{
     app::Update,
     color::{palettes, Color, Srgba},
     prelude::{
-        in_state, Animatable, BuildChildren, Button, ChildBuild, ChildBuilder, Commands, Component,
-        Condition, DespawnRecursiveExt, Entity, IntoSystemConfigs, NextState, Query, Res, ResMut,
-        Text, With,
+        in_state, Animatable, BuildChildren, ChildBuild, ChildBuilder, Commands, Component,
+        Condition, DespawnRecursiveExt, IntoSystemConfigs, NextState, Res, ResMut, Single, Text,
     },
     ui::{
-        BackgroundColor, BorderColor, BorderRadius, FlexDirection, Interaction, Node, PositionType,
-        UiRect, Val,
+        BorderColor, BorderRadius, FlexDirection, Node, Overflow, PositionType,
+        RelativeCursorPosition, UiRect, Val,
     },
 };
 
 use crate::{
+    loader::Answers,
     scenes::{
+        answers::build_answer_badge,
+        compute::Progress,
         days::{build_content, build_header},
+        grid_image::GridImage,
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
     },
+    scroll_controls::PanZoom,
     tools::Coord,
 };
 
@@ -35,17 +41,19 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui::<false>
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<20>::WaitingUi))),
+            build_ui::<false>.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 20>::WaitingUi)),
+            ),
         )
         .add_systems(
             Update,
-            build_ui::<true>
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<20>::WaitingUi))),
+            build_ui::<true>.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 20>::WaitingUi)),
+            ),
         )
         .add_systems(
             Update,
-            update_tiles.run_if(in_state(VisualizationState::<20>::Ready)),
+            update_tiles.run_if(in_state(VisualizationState::<2024, 20>::Ready)),
         );
     }
 }
@@ -56,14 +64,15 @@ fn build_ui<const PART2: bool>(
     mut input: ResMut<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 20");
-    let header = build_header(&mut commands, "day20", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day20", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day20");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization::<PART2>(parent, &mut input));
+        .with_children(|parent| build_visualization::<PART2>(parent, &mut input, &answers, &fonts));
 
     commands
         .entity(day20_resource.ui)
@@ -73,18 +82,45 @@ fn build_ui<const PART2: bool>(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization<const PART2: bool>(parent: &mut ChildBuilder, input: &mut Input) {
+fn build_visualization<const PART2: bool>(
+    parent: &mut ChildBuilder,
+    input: &mut Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
     let shortcuts = if PART2 {
-        input.cheat(20)
+        input.cheat(20, &Progress::default())
     } else {
-        input.cheat(2)
+        input.cheat(2, &Progress::default())
     };
-    let max = shortcuts.keys().max().unwrap();
+    let max = *shortcuts.keys().max().unwrap();
     let max_shortcut = shortcuts
         .values()
         .filter_map(|ends| ends.iter().map(|end| end.1).max())
         .max()
         .unwrap_or_default() as f32;
+    let min_saving = input.min_saving[usize::from(PART2)];
+    let long_shortcuts = Input::count_shortcuts(&shortcuts, min_saving).to_string();
+    let part = if PART2 { Part::Part2 } else { Part::Part1 };
+
+    let racetrack = Racetrack {
+        shortcuts: shortcuts
+            .iter()
+            .map(|(start, ends)| {
+                (
+                    *start,
+                    ends.iter()
+                        .map(|(end, len)| (*end, *len as f32 / max_shortcut))
+                        .collect(),
+                )
+            })
+            .collect(),
+        hovered: None,
+    };
+    let mut grid = GridImage::new(max + (1, 1), WALL_COLOR.into());
+    for start in racetrack.shortcuts.keys() {
+        grid.set(*start, racetrack.base_color(*start).into());
+    }
 
     parent
         .spawn(Node {
@@ -107,7 +143,7 @@ fn build_visualization<const PART2: bool>(parent: &mut ChildBuilder, input: &mut
                 .with_children(|parent| {
                     parent
                         .spawn(Node::default())
-                        .with_child(Text::new("Shortcuts"));
+                        .with_child(Text::new(format!("Shortcuts saving at least {min_saving}")));
                     parent
                         .spawn((
                             Node {
@@ -117,22 +153,30 @@ fn build_visualization<const PART2: bool>(parent: &mut ChildBuilder, input: &mut
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child(Text::new(
-                            shortcuts
-                                .values()
-                                .map(|ends| ends.iter().filter(|len| len.1 >= 100).count())
-                                .sum::<usize>()
-                                .to_string(),
-                        ));
+                        .with_children(|parent| {
+                            parent.spawn(Text::new(long_shortcuts.clone()));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (20, part),
+                                &long_shortcuts,
+                                fonts.symbol2.clone(),
+                            );
+                        });
                 });
 
             parent
-                .spawn(Node {
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Row,
-                    align_self: bevy::ui::AlignSelf::Center,
-                    ..Default::default()
-                })
+                .spawn((
+                    Node {
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: bevy::ui::AlignSelf::Center,
+                        overflow: Overflow::scroll(),
+                        ..Default::default()
+                    },
+                    PanZoom::default(),
+                ))
                 .with_children(|parent| {
                     parent
                         .spawn((
@@ -140,6 +184,7 @@ fn build_visualization<const PART2: bool>(parent: &mut ChildBuilder, input: &mut
                                 height: Val::Percent(100.),
                                 flex_direction: FlexDirection::Column,
                                 flex_wrap: bevy::ui::FlexWrap::NoWrap,
+                                flex_shrink: 0.,
                                 aspect_ratio: Some(max.column as f32 / max.row as f32),
                                 border: UiRect::all(Val::Px(3.)),
                                 ..Default::default()
@@ -147,94 +192,65 @@ fn build_visualization<const PART2: bool>(parent: &mut ChildBuilder, input: &mut
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_children(|parent| {
-                            for row in 0..=max.row {
-                                parent
-                                    .spawn(Node {
-                                        width: Val::Percent(100.),
-                                        height: Val::Percent(10000. / max.row as f32),
-                                        flex_direction: FlexDirection::Row,
-                                        ..Default::default()
-                                    })
-                                    .with_children(|parent| {
-                                        for column in 0..=max.column {
-                                            let coord = Coord::new(row, column);
-
-                                            let shortcuts_starting_from =
-                                                shortcuts.get(&coord).cloned();
-                                            let ends = shortcuts_starting_from
-                                                .as_ref()
-                                                .map(|ends| {
-                                                    ends.iter()
-                                                        .map(|(a, b)| {
-                                                            (*a, *b as f32 / max_shortcut)
-                                                        })
-                                                        .collect::<Vec<_>>()
-                                                })
-                                                .unwrap_or_default();
-
-                                            let background_color =
-                                                if shortcuts_starting_from.is_some() {
-                                                    if shortcuts_starting_from
-                                                        .as_ref()
-                                                        .filter(|ends| !ends.is_empty())
-                                                        .is_some()
-                                                    {
-                                                        MAIN_PATH_HAS_SHORTCUT_COLOR
-                                                    } else {
-                                                        MAIN_PATH_ZERO_SHORTCUT_COLOR
-                                                    }
-                                                } else {
-                                                    WALL_COLOR
-                                                };
-                                            parent.spawn((
-                                                Node {
-                                                    height: Val::Percent(100.),
-                                                    aspect_ratio: Some(1.),
-                                                    ..Default::default()
-                                                },
-                                                BackgroundColor(background_color.into()),
-                                                Tile(coord, background_color.into()),
-                                                Ends(ends),
-                                            ));
-                                        }
-                                    });
-                            }
-                        });
+                        .with_child((
+                            Node {
+                                width: Val::Percent(100.),
+                                height: Val::Percent(100.),
+                                ..Default::default()
+                            },
+                            grid,
+                            racetrack,
+                        ));
                 });
         });
 }
 
 #[derive(Debug, Component)]
-struct Tile(Coord, Color);
-
-#[derive(Debug, Component)]
-#[require(Button)]
-struct Ends(Vec<(Coord, f32)>);
+struct Racetrack {
+    /// Ends of the shortcuts starting from each tile of the main path, with their length
+    /// relative to the longest shortcut
+    shortcuts: BTreeMap<Coord, Vec<(Coord, f32)>>,
+    hovered: Option<Coord>,
+}
 
-fn update_tiles(
-    ends: Query<&Ends>,
-    interactions: Query<(Entity, &Interaction), With<Ends>>,
-    mut backgrounds: Query<(&Tile, &mut BackgroundColor), With<Ends>>,
-) {
-    if let Some((hovered, _)) = interactions
-        .iter()
-        .find(|(_, interaction)| matches!(interaction, Interaction::Hovered | Interaction::Pressed))
-    {
-        let ends = ends.get(hovered).unwrap();
-        for (tile, mut background) in backgrounds.iter_mut() {
-            if let Some((_, percent_of_max_shortcut)) =
-                ends.0.iter().find(|(coord, _)| coord == &tile.0)
-            {
-                background.0 = Srgba::interpolate(
-                    &SHORT_SHORTCUT_COLOR,
-                    &LONG_SHORTCUT_COLOR,
-                    *percent_of_max_shortcut,
-                )
-                .into();
-            } else {
-                background.0 = tile.1;
-            }
+impl Racetrack {
+    fn base_color(&self, coord: Coord) -> Srgba {
+        match self.shortcuts.get(&coord) {
+            Some(ends) if !ends.is_empty() => MAIN_PATH_HAS_SHORTCUT_COLOR,
+            Some(_) => MAIN_PATH_ZERO_SHORTCUT_COLOR,
+            None => WALL_COLOR,
         }
     }
 }
+
+fn update_tiles(racetrack: Single<(&mut Racetrack, &mut GridImage, &RelativeCursorPosition)>) {
+    let (mut racetrack, mut grid, cursor) = racetrack.into_inner();
+    let Some(hovered) = grid.hovered_cell(cursor) else {
+        return;
+    };
+    if racetrack.hovered == Some(hovered) {
+        return;
+    }
+    let previous = racetrack.hovered.replace(hovered);
+
+    // Only the ends of the previous shortcuts go back to their color
+    let previous_ends = previous.and_then(|previous| racetrack.shortcuts.get(&previous));
+    for (end, _) in previous_ends.into_iter().flatten() {
+        grid.set(*end, racetrack.base_color(*end).into());
+    }
+    paint_shortcuts(&racetrack, &mut grid, hovered);
+}
+
+fn paint_shortcuts(racetrack: &Racetrack, grid: &mut GridImage, start: Coord) {
+    for (end, percent_of_max_shortcut) in racetrack.shortcuts.get(&start).into_iter().flatten() {
+        grid.set(
+            *end,
+            Srgba::interpolate(
+                &SHORT_SHORTCUT_COLOR,
+                &LONG_SHORTCUT_COLOR,
+                *percent_of_max_shortcut,
+            )
+            .into(),
+        );
+    }
+}
@@ -5,7 +5,10 @@ use std::{
 
 use bevy::prelude::{Component, Resource};
 
-use crate::{loader::RawInput, tools::Coord};
+use crate::{
+    loader::{ParseError, RawInput},
+    tools::Coord,
+};
 
 #[derive(Debug, Clone, Resource, Component)]
 pub struct Input {
@@ -13,15 +16,23 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
         let codes = input
-            .split(|c| *c == b'\n')
+            .lines()
             .filter(|line| !line.is_empty())
-            .map(|line| Code(line.try_into().unwrap()))
-            .collect::<Vec<_>>();
-        let codes = codes.try_into().unwrap();
-
-        Self { codes }
+            .map(|line| match line.bytes {
+                [a, b, c, b'A'] if [a, b, c].iter().all(|digit| digit.is_ascii_digit()) => {
+                    Ok(Code([*a, *b, *c, b'A']))
+                }
+                _ => Err(line.error(0, "expected a code of three digits followed by `A`")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let found = codes.len();
+        let codes = codes
+            .try_into()
+            .map_err(|_| ParseError::new(1, 1, format!("expected 5 codes, found {found}")))?;
+
+        Ok(Self { codes })
     }
 
     pub fn run(&self, indirections: usize) -> (Vec<usize>, BTreeMap<(u8, u8), usize>) {
@@ -71,6 +82,10 @@ impl Input {
         )
     }
 
+    pub fn complexity(&self, indirections: usize) -> usize {
+        self.run(indirections).0.into_iter().sum()
+    }
+
     fn indirection<'a>(
         segment: &'a [u8],
         directional: &'a KeyPad,
@@ -211,3 +226,19 @@ impl KeyPad {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::loader::RawInput;
+
+    use super::Input;
+
+    #[test]
+    fn run() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day21.txt").to_vec());
+        let input = Input::parse(&input).unwrap();
+
+        let (complexities, _) = input.run(2);
+        assert_eq!(complexities, [1972, 58800, 12172, 29184, 24256]);
+    }
+}
@@ -25,13 +25,15 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui::<false>
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<21>::WaitingUi))),
+            build_ui::<false>.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 21>::WaitingUi)),
+            ),
         )
         .add_systems(
             Update,
-            build_ui::<true>
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<21>::WaitingUi))),
+            build_ui::<true>.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 21>::WaitingUi)),
+            ),
         );
     }
 }
@@ -44,7 +46,7 @@ fn build_ui<const PART2: bool>(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 21");
-    let header = build_header(&mut commands, "day21", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day21", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day21");
 
     commands
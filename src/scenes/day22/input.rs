@@ -2,22 +2,39 @@ use std::collections::{BTreeMap, BTreeSet};
 
 use bevy::prelude::{Component, Resource};
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    scenes::compute::Progress,
+};
 
 #[derive(Debug, Clone, Resource, Component)]
 pub struct Input {
     pub rng_seeds: Vec<usize>,
+    /// Price changes to sell at, and the bananas they bring
+    pub best_sell: ([isize; 4], usize),
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let input = input.split(|c| *c == b'\n').filter(|line| !line.is_empty());
-
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
         let rng_seeds = input
-            .map(|line| String::from_utf8_lossy(line).parse().unwrap())
-            .collect();
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.number(line.bytes))
+            .collect::<Result<Vec<_>, _>>()?;
+        if rng_seeds.is_empty() {
+            return Err(input.unexpected_end());
+        }
+
+        Ok(Self {
+            rng_seeds,
+            best_sell: ([0; 4], 0),
+        })
+    }
 
-        Self { rng_seeds }
+    /// Most bananas a single sequence of price changes brings
+    pub fn solve_best_sell(&mut self, progress: &Progress) -> usize {
+        self.best_sell = self.find_best_sell(progress);
+        self.best_sell.1
     }
 
     pub fn rngs(&self) -> Vec<Rng> {
@@ -27,7 +44,14 @@ impl Input {
             .collect()
     }
 
-    pub fn part2(&self) -> ([isize; 4], usize) {
+    pub fn sum_of_secrets(&self) -> usize {
+        self.rngs()
+            .into_iter()
+            .map(|mut rng| rng.nth(1999).unwrap())
+            .sum()
+    }
+
+    fn find_best_sell(&self, progress: &Progress) -> ([isize; 4], usize) {
         let time_series = self.time_series(2000);
 
         let unique_windows = BTreeSet::from_iter(
@@ -35,10 +59,14 @@ impl Input {
                 .iter()
                 .flat_map(|time_series| time_series.price_changes_windows.keys()),
         );
+        let windows_count = unique_windows.len();
 
         unique_windows
             .into_iter()
-            .map(|windows| {
+            .enumerate()
+            .take_while(|_| !progress.is_cancelled())
+            .map(|(i, windows)| {
+                progress.report(i, windows_count);
                 (
                     *windows,
                     time_series
@@ -48,7 +76,7 @@ impl Input {
                 )
             })
             .max_by_key(|(_, sum)| *sum)
-            .unwrap()
+            .unwrap_or_default()
     }
 
     fn time_series(&self, updates: usize) -> Vec<TimeSeries> {
@@ -156,3 +184,31 @@ struct TimeSeries {
     price_changes: Vec<isize>,
     price_changes_windows: BTreeMap<[isize; 4], usize>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{loader::RawInput, scenes::compute::Progress};
+
+    use super::{Input, Rng};
+
+    #[test]
+    fn rng() {
+        let secrets = Rng { secret: 123 }.take(10).collect::<Vec<_>>();
+        assert_eq!(
+            secrets,
+            [
+                15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+                5908254
+            ]
+        );
+    }
+
+    #[test]
+    fn part2() {
+        let input = RawInput(b"1\n2\n3\n2024\n".to_vec());
+        let mut input = Input::parse(&input).unwrap();
+        input.solve_best_sell(&Progress::default());
+
+        assert_eq!(input.best_sell, ([-2, 1, -1, 3], 23));
+    }
+}
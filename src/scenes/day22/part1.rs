@@ -31,8 +31,9 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part1).and(in_state(VisualizationState::<22>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part1).and(in_state(VisualizationState::<2024, 22>::WaitingUi)),
+            ),
         );
     }
 }
@@ -45,7 +46,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 22 Part 1");
-    let header = build_header(&mut commands, "day22", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day22", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day22");
 
     commands
@@ -62,11 +63,7 @@ fn build_ui(
 
 fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
     let rngs = input.rngs();
-    let rng_sum = rngs
-        .iter()
-        .cloned()
-        .map(|mut rng| rng.nth(1999).unwrap())
-        .sum::<usize>();
+    let rng_sum = input.sum_of_secrets();
 
     parent
         .spawn(Node {
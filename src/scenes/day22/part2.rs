@@ -29,8 +29,9 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui
-                .run_if(in_state(Part::Part2).and(in_state(VisualizationState::<22>::WaitingUi))),
+            build_ui.run_if(
+                in_state(Part::Part2).and(in_state(VisualizationState::<2024, 22>::WaitingUi)),
+            ),
         );
     }
 }
@@ -43,7 +44,7 @@ fn build_ui(
     fonts: Res<FontHandles>,
 ) {
     bevy::log::trace!("Day 22 Part 2");
-    let header = build_header(&mut commands, "day22", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day22", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day22");
 
     commands
@@ -59,7 +60,7 @@ fn build_ui(
 }
 
 fn build_visualization(parent: &mut ChildBuilder, input: &mut Input, fonts: &FontHandles) {
-    let (best_sell_change, best_sell) = input.part2();
+    let (best_sell_change, best_sell) = input.best_sell;
     let best_sell_change_string = best_sell_change.map(|change| change.to_string());
 
     parent
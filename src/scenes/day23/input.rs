@@ -5,7 +5,10 @@ use std::{
 
 use bevy::prelude::{Component, Resource};
 
-use crate::loader::RawInput;
+use crate::{
+    loader::{ParseError, RawInput},
+    scenes::compute::Progress,
+};
 
 #[derive(Debug, Clone, Resource, Component)]
 pub struct Input {
@@ -16,36 +19,52 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let connections: BTreeMap<[u8; 2], Vec<[u8; 2]>> = input
-            .0
-            .split(|c| *c == b'\n')
-            .filter(|line| !line.is_empty())
-            .fold(BTreeMap::new(), |mut map, line| {
-                let (l, r) = line.split_at(2);
-
-                let l: [u8; 2] = l.try_into().unwrap();
-                let r: [u8; 2] = (r[1..]).try_into().unwrap();
-
-                map.entry(l)
-                    .and_modify(|connected_to| connected_to.push(r))
-                    .or_insert(vec![r]);
-                map.entry(r)
-                    .and_modify(|connected_to| connected_to.push(l))
-                    .or_insert(vec![l]);
-
-                map
-            });
-
-        let triples = Self::get_triplets(&connections);
-        let (password, fully_connected) = Self::fully_connected(&connections);
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
+        let mut connections: BTreeMap<[u8; 2], Vec<[u8; 2]>> = BTreeMap::new();
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let (l, r) = line.split_field(line.bytes, b"-")?;
+
+            let computer = |name: &[u8]| {
+                name.try_into()
+                    .ok()
+                    .filter(|name: &[u8; 2]| name.iter().all(u8::is_ascii_lowercase))
+                    .ok_or_else(|| line.error_at(name, "expected a two letter computer name"))
+            };
+            let l = computer(l)?;
+            let r = computer(r)?;
+
+            connections
+                .entry(l)
+                .and_modify(|connected_to| connected_to.push(r))
+                .or_insert(vec![r]);
+            connections
+                .entry(r)
+                .and_modify(|connected_to| connected_to.push(l))
+                .or_insert(vec![l]);
+        }
+        if connections.is_empty() {
+            return Err(input.unexpected_end());
+        }
 
-        Self {
+        Ok(Self {
             connections,
-            triples,
-            fully_connected,
-            password,
-        }
+            triples: BTreeSet::new(),
+            fully_connected: vec![],
+            password: String::new(),
+        })
+    }
+
+    /// Triples of connected computers with one whose name starts with `t`
+    pub fn solve_triples(&mut self) -> usize {
+        self.triples = Self::get_triplets(&self.connections);
+        self.triples.len()
+    }
+
+    /// Names of the computers of the largest fully connected set
+    pub fn solve_password(&mut self, progress: &Progress) -> String {
+        (self.password, self.fully_connected) = Self::fully_connected(&self.connections, progress);
+        self.password.clone()
     }
 
     fn get_triplets(connections: &BTreeMap<[u8; 2], Vec<[u8; 2]>>) -> BTreeSet<[[u8; 2]; 3]> {
@@ -65,17 +84,25 @@ impl Input {
         triplets
     }
 
-    fn fully_connected(connections: &BTreeMap<[u8; 2], Vec<[u8; 2]>>) -> (String, Vec<[u8; 2]>) {
+    fn fully_connected(
+        connections: &BTreeMap<[u8; 2], Vec<[u8; 2]>>,
+        progress: &Progress,
+    ) -> (String, Vec<[u8; 2]>) {
         let mut computer_groups = Vec::with_capacity(500);
 
-        connections.iter().for_each(|(pc, node_connections)| {
-            Self::get_fully_connected(
-                node_connections.as_slice(),
-                vec![pc],
-                connections,
-                &mut computer_groups,
-            );
-        });
+        connections
+            .iter()
+            .enumerate()
+            .take_while(|_| !progress.is_cancelled())
+            .for_each(|(i, (pc, node_connections))| {
+                progress.report(i, connections.len());
+                Self::get_fully_connected(
+                    node_connections.as_slice(),
+                    vec![pc],
+                    connections,
+                    &mut computer_groups,
+                );
+            });
 
         computer_groups
             .into_iter()
@@ -90,7 +117,7 @@ impl Input {
                     group.into_iter().cloned().collect(),
                 )
             })
-            .unwrap()
+            .unwrap_or_default()
     }
 
     fn get_triple_connections<'a>(
@@ -9,16 +9,21 @@ use bevy::{
     },
     text::TextFont,
     ui::{
-        AlignSelf, BorderColor, BorderRadius, FlexDirection, FlexWrap, Interaction, Node,
+        AlignSelf, BorderColor, BorderRadius, FlexDirection, FlexWrap, Interaction, Node, Overflow,
         PositionType, UiRect, Val,
     },
     window::Window,
 };
 
-use crate::scenes::{
-    days::{build_content, build_header},
-    resources::{FontHandles, GenericDay},
-    states::{UiState, VisualizationState},
+use crate::{
+    loader::Answers,
+    scenes::{
+        answers::build_answer_badge,
+        days::{build_content, build_header},
+        resources::{FontHandles, GenericDay},
+        states::{Part, UiState, VisualizationState},
+    },
+    scroll_controls::PanZoom,
 };
 
 use super::input::Input;
@@ -29,11 +34,11 @@ impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(
             Update,
-            build_ui.run_if(in_state(VisualizationState::<23>::WaitingUi)),
+            build_ui.run_if(in_state(VisualizationState::<2024, 23>::WaitingUi)),
         )
         .add_systems(
             Update,
-            draw_gizmos.run_if(in_state(VisualizationState::<23>::Ready)),
+            draw_gizmos.run_if(in_state(VisualizationState::<2024, 23>::Ready)),
         );
     }
 }
@@ -44,14 +49,15 @@ fn build_ui(
     mut input: ResMut<Input>,
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
+    answers: Res<Answers>,
 ) {
     bevy::log::trace!("Day 23");
-    let header = build_header(&mut commands, "day23", false, fonts.font.clone());
+    let header = build_header(&mut commands, "day23", false, false, fonts.font.clone());
     let content = build_content(&mut commands, "day23");
 
     commands
         .entity(content)
-        .with_children(|parent| build_visualization(parent, &mut input));
+        .with_children(|parent| build_visualization(parent, &mut input, &answers, &fonts));
 
     commands
         .entity(day23_resource.ui)
@@ -61,7 +67,13 @@ fn build_ui(
     next_state.set(UiState::Loaded);
 }
 
-fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
+fn build_visualization(
+    parent: &mut ChildBuilder,
+    input: &mut Input,
+    answers: &Answers,
+    fonts: &FontHandles,
+) {
+    let triplets = input.triples.len().to_string();
     parent
         .spawn(Node {
             top: Val::Px(50.),
@@ -93,7 +105,16 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child(Text::new(input.triples.len().to_string()));
+                        .with_children(|parent| {
+                            parent.spawn(Text::new(triplets.clone()));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (23, Part::Part1),
+                                &triplets,
+                                fonts.symbol2.clone(),
+                            );
+                        });
                 });
             parent
                 .spawn(Node {
@@ -114,16 +135,30 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                             BorderColor(Color::WHITE),
                             BorderRadius::all(Val::Px(5.)),
                         ))
-                        .with_child(Text::new(input.password.clone()));
+                        .with_children(|parent| {
+                            parent.spawn(Text::new(input.password.clone()));
+                            build_answer_badge(
+                                parent,
+                                answers,
+                                (23, Part::Part2),
+                                &input.password,
+                                fonts.symbol2.clone(),
+                            );
+                        });
                 });
 
             parent
-                .spawn(Node {
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Row,
-                    align_self: AlignSelf::Center,
-                    ..Default::default()
-                })
+                .spawn((
+                    Node {
+                        height: Val::Percent(100.),
+                        max_width: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        align_self: AlignSelf::Center,
+                        overflow: Overflow::scroll(),
+                        ..Default::default()
+                    },
+                    PanZoom::default(),
+                ))
                 .with_children(|parent| {
                     parent
                         .spawn((
@@ -131,6 +166,7 @@ fn build_visualization(parent: &mut ChildBuilder, input: &mut Input) {
                                 height: Val::Percent(100.),
                                 flex_direction: FlexDirection::Column,
                                 flex_wrap: FlexWrap::NoWrap,
+                                flex_shrink: 0.,
                                 aspect_ratio: Some(30. / 26.),
                                 border: UiRect::all(Val::Px(3.)),
                                 ..Default::default()
@@ -1,12 +1,4 @@
-use bevy::prelude::{Button, Component, Deref};
-
-#[derive(Debug, Clone, Copy, Component)]
-#[require(Button)]
-pub enum Controls {
-    Reset,
-    Step,
-    FastForward,
-}
+use bevy::prelude::{Component, Deref};
 
 #[derive(Debug, Clone, Copy, Component, Deref)]
 pub struct Wire(pub [u8; 3]);
@@ -20,6 +12,3 @@ pub struct Gate {
     pub right: [u8; 3],
     pub out: [u8; 3],
 }
-
-#[derive(Debug, Component)]
-pub struct GizmosCamera;
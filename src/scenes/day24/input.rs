@@ -1,69 +1,127 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use bevy::prelude::{Component, Resource};
+use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{Line, ParseError, RawInput};
 
 use super::operation::{Operation, Operator};
 
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone)]
 pub enum ExecutionResult {
     Success([u8; 3], [u8; 3], [u8; 3]),
     Failure([u8; 3], [u8; 3]),
 }
 
-#[derive(Debug, Clone, Resource, Component)]
+#[derive(Debug, Clone, Resource)]
 pub struct Input {
-    pub x: [u8; 45],
-    pub y: [u8; 45],
-    pub z: [u8; 46],
+    pub x: Vec<u8>,
+    pub y: Vec<u8>,
+    pub z: Vec<u8>,
     pub intermediate: BTreeMap<[u8; 3], u8>,
     pub operations: Vec<Operation>,
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let mut lines = input.split(|c| *c == b'\n');
+    pub fn parse(raw_input: &RawInput) -> Result<Self, ParseError> {
+        let mut lines = raw_input.lines();
 
-        let mut input = Input::default();
+        let mut x = Vec::new();
+        let mut y = Vec::new();
+        let mut end = 1;
 
         for line in &mut lines {
+            end = line.number;
             if line.is_empty() {
                 break;
             }
 
-            let (l, r) = line.split_at(3);
-            match l {
-                [b'x', d, u] => input.x[usize::from(ascii_to_num(*d, *u))] = r[2] - b'0',
-                [b'y', d, u] => input.y[usize::from(ascii_to_num(*d, *u))] = r[2] - b'0',
-                _ => unreachable!("First section must only contain x and y."),
+            let (wire, value) = line.split_field(line.bytes, b": ")?;
+            let bit = match value {
+                [value @ (b'0' | b'1')] => value - b'0',
+                _ => return Err(line.error_at(value, "expected `0` or `1`")),
+            };
+            let (wires, index) = match (wire, wire_index(wire)) {
+                ([b'x', _, _], Some(index)) => (&mut x, index),
+                ([b'y', _, _], Some(index)) => (&mut y, index),
+                _ => return Err(line.error(0, "expected an `x` or `y` wire like `x00`")),
+            };
+            if wires.len() <= index {
+                wires.resize(index + 1, None);
             }
+            wires[index] = Some(bit);
+        }
+
+        let bits = x.len();
+        let (Some(x), Some(y)) = (
+            x.into_iter().collect::<Option<Vec<_>>>(),
+            y.into_iter().collect::<Option<Vec<_>>>(),
+        ) else {
+            return Err(ParseError::new(
+                end,
+                1,
+                "input wires must be numbered without gaps",
+            ));
+        };
+        if y.len() != bits || !(1..64).contains(&bits) {
+            return Err(ParseError::new(
+                end,
+                1,
+                "expected between 1 and 63 `x` wires and as many `y` wires",
+            ));
         }
 
+        let mut input = Input {
+            x,
+            y,
+            z: vec![0; bits + 1],
+            intermediate: BTreeMap::new(),
+            operations: Vec::new(),
+        };
+
+        let mut circuit_start = None;
+
         for line in lines.filter(|line| !line.is_empty()) {
-            let line = line.split(|c| *c == b' ').collect::<Vec<_>>();
-            let [l, op, r, _, out] = line.as_slice() else {
-                unreachable!("Line on second section must have 5 items.");
+            circuit_start.get_or_insert(line.number);
+
+            let fields = line.split(|c| *c == b' ').collect::<Vec<_>>();
+            let [l, op, r, b"->", out] = fields.as_slice() else {
+                return Err(line.error(0, "expected a gate like `a AND b -> c`"));
             };
 
             let op = match op {
                 [b'A', b'N', b'D'] => Operator::And,
                 [b'O', b'R'] => Operator::Or,
                 [b'X', b'O', b'R'] => Operator::Xor,
-                _ => unreachable!("Invalid operator"),
+                _ => return Err(line.error_at(op, "expected `AND`, `OR` or `XOR`")),
             };
 
-            let l: [u8; 3] = (*l).try_into().expect("Should be able to convert");
-            if !matches!(l, [b'x', _, _] | [b'y', _, _] | [b'z', _, _]) {
-                input.intermediate.insert(l, u8::MAX);
-            }
-            let r: [u8; 3] = (*r).try_into().expect("Should be able to convert");
-            if !matches!(r, [b'x', _, _] | [b'y', _, _] | [b'z', _, _]) {
-                input.intermediate.insert(r, u8::MAX);
-            }
-            let out: [u8; 3] = (*out).try_into().expect("Should be able to convert");
+            let [l, r] = [l, r].map(|field| {
+                let wire = wire(line, field)?;
+                match (wire, wire_index(&wire)) {
+                    ([b'x' | b'y', _, _], Some(index)) if index < bits => (),
+                    ([b'x' | b'y', _, _], _) => {
+                        return Err(line.error_at(field, "input wire does not exist"));
+                    }
+                    ([b'z', _, _], _) => {
+                        return Err(line.error_at(field, "`z` wires can not be gate inputs"));
+                    }
+                    _ => {
+                        input.intermediate.insert(wire, u8::MAX);
+                    }
+                }
+                Ok(wire)
+            });
+            let (l, r) = (l?, r?);
+            let out = wire(line, out)?;
             if !matches!(out, [b'x', _, _] | [b'y', _, _] | [b'z', _, _]) {
                 input.intermediate.insert(out, u8::MAX);
+            } else if !matches!(out, [b'z', _, _])
+                || wire_index(&out).is_none_or(|index| index > bits)
+            {
+                return Err(line.error_at(
+                    fields[4],
+                    format!("expected an output from `z00` to `z{bits:02}`"),
+                ));
             }
 
             input.operations.push(Operation {
@@ -76,15 +134,21 @@ impl Input {
 
         let mut operations = std::mem::take(&mut input.operations);
 
+        let circuit_start = circuit_start.ok_or_else(|| raw_input.unexpected_end())?;
+        let not_an_adder = |index: usize| {
+            ParseError::new(
+                circuit_start,
+                1,
+                format!("bit {index} is not wired as a ripple-carry adder"),
+            )
+        };
+
         let mut set = BTreeSet::new();
 
         let mut carry = [0; 3];
 
-        for index in 0u8..45 {
-            let inputs = [
-                [b'x', (index / 10) + b'0', (index % 10) + b'0'],
-                [b'y', (index / 10) + b'0', (index % 10) + b'0'],
-            ];
+        for index in 0..bits {
+            let inputs = [wire_name(b'x', index), wire_name(b'y', index)];
             set.extend(inputs);
 
             let xor = {
@@ -95,7 +159,7 @@ impl Input {
                             && inputs.contains(&op.r)
                             && matches!(op.operator, Operator::Xor)
                     })
-                    .unwrap();
+                    .ok_or_else(|| not_an_adder(index))?;
                 operations.remove(xor_pos)
             };
             let and = {
@@ -106,7 +170,7 @@ impl Input {
                             && inputs.contains(&op.r)
                             && matches!(op.operator, Operator::And)
                     })
-                    .unwrap();
+                    .ok_or_else(|| not_an_adder(index))?;
                 operations.remove(and_pos)
             };
 
@@ -133,7 +197,7 @@ impl Input {
                                     && matches!(op.operator, Operator::Xor)
                             })
                         })
-                        .unwrap();
+                        .ok_or_else(|| not_an_adder(index))?;
                     operations.remove(xor_pos)
                 };
                 let and_b = {
@@ -151,7 +215,7 @@ impl Input {
                                     && matches!(op.operator, Operator::And)
                             })
                         })
-                        .unwrap();
+                        .ok_or_else(|| not_an_adder(index))?;
                     operations.remove(and_pos)
                 };
 
@@ -176,7 +240,7 @@ impl Input {
                                     && matches!(op.operator, Operator::Or)
                             })
                         })
-                        .unwrap();
+                        .ok_or_else(|| not_an_adder(index))?;
                     operations.remove(or_pos)
                 };
 
@@ -187,9 +251,15 @@ impl Input {
             }
         }
 
-        assert!(operations.is_empty());
+        if !operations.is_empty() {
+            return Err(ParseError::new(
+                circuit_start,
+                1,
+                format!("{} gates are not part of the adder", operations.len()),
+            ));
+        }
 
-        input
+        Ok(input)
     }
 
     pub fn run_program(&mut self) {
@@ -198,6 +268,16 @@ impl Input {
         }
     }
 
+    pub fn z_value(&self) -> u64 {
+        let mut input = self.clone();
+        input.run_program();
+        input
+            .z
+            .iter()
+            .rev()
+            .fold(0, |value, bit| (value << 1) | u64::from(*bit))
+    }
+
     pub fn execute_top(&mut self) -> ExecutionResult {
         let top = self.operations.remove(0);
 
@@ -239,18 +319,25 @@ impl Input {
     }
 }
 
-impl Default for Input {
-    fn default() -> Self {
-        Self {
-            x: [0; 45],
-            y: [0; 45],
-            z: [0; 46],
-            intermediate: BTreeMap::new(),
-            operations: Vec::new(),
-        }
+fn ascii_to_num(d: u8, u: u8) -> u8 {
+    (d - b'0') * 10 + (u - b'0')
+}
+
+fn wire_index(wire: &[u8]) -> Option<usize> {
+    match wire {
+        [_, d @ b'0'..=b'9', u @ b'0'..=b'9'] => Some(usize::from(ascii_to_num(*d, *u))),
+        _ => None,
     }
 }
 
-fn ascii_to_num(d: u8, u: u8) -> u8 {
-    (d - b'0') * 10 + (u - b'0')
+/// Name of the `index`th wire with `prefix`, like `x07`.
+pub fn wire_name(prefix: u8, index: usize) -> [u8; 3] {
+    [prefix, (index / 10) as u8 + b'0', (index % 10) as u8 + b'0']
+}
+
+fn wire(line: Line, wire: &[u8]) -> Result<[u8; 3], ParseError> {
+    wire.try_into()
+        .ok()
+        .filter(|wire: &[u8; 3]| wire.iter().all(u8::is_ascii_alphanumeric))
+        .ok_or_else(|| line.error_at(wire, "expected a three character wire name"))
 }
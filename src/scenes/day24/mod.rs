@@ -4,24 +4,12 @@ mod operation;
 mod part1;
 mod part2;
 
-use bevy::{
-    app::Update,
-    asset::{AssetServer, Assets},
-    color::Color,
-    core::Name,
-    prelude::{
-        in_state, AppExtStates, Camera2d, ClearColor, Commands, DespawnRecursiveExt,
-        IntoSystemConfigs, NextState, OnEnter, OnExit, Res, ResMut,
-    },
-    ui::{FlexDirection, Node, TargetCamera, Val},
-};
-
-use crate::{loader::RawInput, scenes::states::Scene};
+use crate::loader::{ParseError, PuzzleSizes, RawInput, NO_SOLVER};
 
 use super::{
-    resources::GenericDay,
-    state_button_interactions,
-    states::{InputState, VisualizationState},
+    compute::Progress,
+    days::{self, DayPlugin, PuzzleInput},
+    states::Part,
 };
 
 use self::input::Input;
@@ -30,61 +18,54 @@ pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins((part1::Plugin, part2::Plugin));
+        app.add_plugins((
+            DayPlugin::<2024, 24, Input>::default(),
+            part1::Plugin,
+            part2::Plugin,
+        ));
+    }
+}
 
-        app.add_computed_state::<VisualizationState<24>>();
+impl PuzzleInput for Input {
+    fn parse_input(
+        input: &RawInput,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
 
-        app.add_systems(OnEnter(Scene::Day(24)), build_day_24)
-            .add_systems(OnExit(Scene::Day(24)), destroy_day_24)
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(Scene::Day(24))),
-            )
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<24>::WaitingInput)),
-            );
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.z_value().to_string(),
+            // The swapped wires are found by inspecting the circuit
+            Part::Part2 => NO_SOLVER.to_string(),
+        }
     }
 }
 
-fn build_day_24(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day24_camera"), Camera2d)).id();
-    let day24_resource = GenericDay {
-        input: asset_server.load("inputs/day24.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day24_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
-
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day24_resource);
+pub fn solve(
+    input: &RawInput,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<Input>(input, sizes, progress)
 }
 
-fn destroy_day_24(mut commands: Commands, day24_resource: Res<GenericDay>) {
-    commands.entity(day24_resource.camera).despawn_recursive();
-    commands.entity(day24_resource.ui).despawn_recursive();
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
 
-    commands.remove_resource::<GenericDay>();
-}
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day24.txt").to_vec());
 
-fn process_input(
-    mut commands: Commands,
-    day24: Res<GenericDay>,
-    inputs: Res<Assets<RawInput>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day24.input.id()) {
-        commands.insert_resource(Input::parse(input));
-        next_state.set(InputState::Loaded);
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["20", super::NO_SOLVER]
+        );
     }
 }
@@ -6,26 +6,27 @@ use bevy::{
     color::{palettes, Color},
     core::Name,
     prelude::{
-        in_state, BuildChildren, Button, Changed, ChildBuild, ChildBuilder, Commands, Condition,
-        DespawnRecursiveExt, DetectChanges, Entity, IntoSystemConfigs, NextState, Query, Ref, Res,
-        ResMut, Single, Text, With,
+        in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, Component, Condition,
+        DespawnRecursiveExt, DetectChanges, Entity, IntoSystemConfigs, NextState, Ref, Res, ResMut,
+        Single, Text,
     },
     text::{Font, TextColor, TextFont},
     ui::{
-        AlignItems, BackgroundColor, BorderColor, FlexDirection, FlexWrap, Interaction,
-        JustifyContent, Node, PositionType, UiRect, Val,
+        AlignItems, BackgroundColor, BorderColor, FlexDirection, FlexWrap, JustifyContent, Node,
+        PositionType, UiRect, Val,
     },
 };
 
 use crate::scenes::{
-    days::{build_content, build_footer, build_header, button_node},
+    days::{build_content, build_footer, build_header},
+    playback::{self, build_playback_controls, Simulation},
     resources::{FontHandles, GenericDay},
     states::{Part, UiState, VisualizationState},
-    BUTTON_BACKGROUND_COLOR, BUTTON_HOVERED_BACKGROUND_COLOR,
+    BUTTON_BACKGROUND_COLOR,
 };
 
 use super::{
-    components::{Controls, Wire},
+    components::Wire,
     input::{ExecutionResult, Input},
 };
 
@@ -37,63 +38,25 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part1))
-                .run_if(in_state(VisualizationState::<24>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 24>::WaitingUi)),
         )
         .add_systems(
             Update,
-            controls_interaction
+            playback::component_systems::<Circuit>()
                 .run_if(in_state(Part::Part1))
-                .run_if(in_state(VisualizationState::<24>::Ready)),
+                .run_if(in_state(VisualizationState::<2024, 24>::Ready)),
         )
         .add_systems(
             Update,
             update_visualization.run_if(
                 in_state(Part::Part1)
-                    .and(in_state(VisualizationState::<24>::Ready))
+                    .and(in_state(VisualizationState::<2024, 24>::Ready))
                     .and(update_visualization_condition),
             ),
         );
     }
 }
 
-type ControlWithChangedInteractionQuery<'a, 'b> = Query<
-    'a,
-    'b,
-    (
-        &'static mut BackgroundColor,
-        &'static Interaction,
-        &'static Controls,
-    ),
-    (With<Button>, Changed<Interaction>),
->;
-
-fn controls_interaction(
-    mut commands: Commands,
-    mut controls: ControlWithChangedInteractionQuery,
-    mut input: Single<(Entity, &mut Input)>,
-    original_input: Res<Input>,
-) {
-    for (mut background_color, interaction, control) in controls.iter_mut() {
-        match interaction {
-            Interaction::None => background_color.0 = BUTTON_BACKGROUND_COLOR,
-            Interaction::Hovered => background_color.0 = BUTTON_HOVERED_BACKGROUND_COLOR,
-            Interaction::Pressed => match control {
-                Controls::Reset => {
-                    *input.1 = original_input.clone();
-                }
-                Controls::Step => {
-                    if !input.1.operations.is_empty() {
-                        commands.entity(input.0).insert(input.1.execute_top());
-                    }
-                }
-                Controls::FastForward => {
-                    input.1.run_program();
-                }
-            },
-        }
-    }
-}
-
 fn build_ui(
     mut commands: Commands,
     day24_resource: Res<GenericDay>,
@@ -101,7 +64,7 @@ fn build_ui(
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
 ) {
-    let header = build_header(&mut commands, "day24", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day24", true, true, fonts.font.clone());
     let content = build_content(&mut commands, "day24");
     let footer = build_footer(&mut commands, "day24");
 
@@ -110,7 +73,7 @@ fn build_ui(
         .with_children(|parent| build_visualization(parent, &input));
     commands
         .entity(footer)
-        .with_children(|parent| build_control_buttons(parent, fonts.symbol2.clone()));
+        .with_children(|parent| build_playback_controls::<Circuit>(parent, &fonts));
 
     commands
         .entity(day24_resource.ui)
@@ -132,25 +95,29 @@ fn build_visualization(parent: &mut ChildBuilder, input: &Input) {
             row_gap: Val::Px(12.),
             ..Default::default()
         },
-        input.clone(),
+        Circuit {
+            start: input.clone(),
+            current: input.clone(),
+            last_result: None,
+        },
     ));
 }
 
-fn update_visualization_condition(visualization: Single<Ref<Input>>) -> bool {
+fn update_visualization_condition(visualization: Single<Ref<Circuit>>) -> bool {
     visualization.is_changed()
 }
 
 fn update_visualization(
     mut commands: Commands,
-    visualization: Single<(Entity, &Input, Option<&ExecutionResult>)>,
+    visualization: Single<(Entity, &Circuit)>,
     fonts: Res<FontHandles>,
 ) {
     commands
         .entity(visualization.0)
         .despawn_descendants()
         .with_children(|parent| {
-            let input = &visualization.1;
-            let execution_result = visualization.2.cloned();
+            let input = &visualization.1.current;
+            let execution_result = visualization.1.last_result.clone();
             parent
                 .spawn((
                     Name::new("day_24_part1_visualization"),
@@ -363,52 +330,6 @@ fn build_intermediates(
         });
 }
 
-fn build_control_buttons(parent: &mut ChildBuilder, symbol: Handle<Font>) {
-    parent
-        .spawn((
-            button_node(),
-            Controls::Reset,
-            BackgroundColor(BUTTON_BACKGROUND_COLOR),
-        ))
-        .with_child((
-            Text::new("⏮"),
-            TextFont {
-                font: symbol.clone(),
-                ..Default::default()
-            },
-            TextColor(Color::BLACK),
-        ));
-    parent
-        .spawn((
-            button_node(),
-            Controls::Step,
-            BackgroundColor(BUTTON_BACKGROUND_COLOR),
-        ))
-        .with_child((
-            Text::new("⏵"),
-            TextFont {
-                font: symbol.clone(),
-
-                ..Default::default()
-            },
-            TextColor(Color::BLACK),
-        ));
-    parent
-        .spawn((
-            button_node(),
-            Controls::FastForward,
-            BackgroundColor(BUTTON_BACKGROUND_COLOR),
-        ))
-        .with_child((
-            Text::new("⏭"),
-            TextFont {
-                font: symbol.clone(),
-                ..Default::default()
-            },
-            TextColor(Color::BLACK),
-        ));
-}
-
 fn build_operation_wires(parent: &mut ChildBuilder, text: &[u8], font: Handle<Font>) {
     parent
         .spawn((
@@ -446,3 +367,29 @@ fn build_operation_symbol(parent: &mut ChildBuilder, text: &[u8], font: Handle<F
             },
         ));
 }
+
+#[derive(Debug, Component)]
+struct Circuit {
+    start: Input,
+    current: Input,
+    last_result: Option<ExecutionResult>,
+}
+
+impl Simulation for Circuit {
+    fn steps_per_second(&self) -> f32 {
+        5.
+    }
+
+    fn step(&mut self) -> bool {
+        if self.current.operations.is_empty() {
+            return false;
+        }
+        self.last_result = Some(self.current.execute_top());
+        true
+    }
+
+    fn reset(&mut self) {
+        self.current = self.start.clone();
+        self.last_result = None;
+    }
+}
@@ -8,11 +8,10 @@ use bevy::{
     image::Image,
     math::{UVec2, Vec2, Vec3},
     prelude::{
-        in_state, BuildChildren, Camera, Camera2d, ChildBuild, ChildBuilder, Commands,
-        DespawnRecursiveExt, Entity, Gizmos, GlobalTransform, ImageNode, IntoSystemConfigs,
-        NextState, OnEnter, OnExit, Query, Res, ResMut, Single, Text, With, Without,
+        in_state, BuildChildren, ChildBuild, ChildBuilder, Commands, DespawnRecursiveExt, Gizmos,
+        GlobalTransform, ImageNode, IntoSystemConfigs, NextState, OnEnter, OnExit, Query, Res,
+        ResMut, Single, Text, Without,
     },
-    render::view::RenderLayers,
     sprite::{TextureAtlas, TextureAtlasLayout},
     text::TextColor,
     ui::{
@@ -25,7 +24,7 @@ use bevy::{
 use crate::{
     scenes::{
         day24::{components::Gate, operation::Operator},
-        days::{build_content, build_header},
+        days::{build_content, build_header, despawn_gizmos_camera, spawn_gizmos_camera},
         resources::{FontHandles, GenericDay},
         states::{Part, UiState, VisualizationState},
         BUTTON_BACKGROUND_COLOR,
@@ -34,8 +33,8 @@ use crate::{
 };
 
 use super::{
-    components::{Adder, GizmosCamera},
-    input::Input,
+    components::Adder,
+    input::{wire_name, Input},
 };
 
 const SCROLL_SPEED: f32 = 512.;
@@ -51,15 +50,18 @@ impl bevy::app::Plugin for Plugin {
             Update,
             build_ui
                 .run_if(in_state(Part::Part2))
-                .run_if(in_state(VisualizationState::<24>::WaitingUi)),
+                .run_if(in_state(VisualizationState::<2024, 24>::WaitingUi)),
         )
         .add_systems(
             Update,
             draw_connections
                 .run_if(in_state(Part::Part2))
-                .run_if(in_state(VisualizationState::<24>::Ready)),
+                .run_if(in_state(VisualizationState::<2024, 24>::Ready)),
+        )
+        .add_systems(
+            OnEnter(Part::Part2),
+            spawn_gizmos_camera("day24_part2_gizmos_camera"),
         )
-        .add_systems(OnEnter(Part::Part2), spawn_gizmos_camera)
         .add_systems(OnExit(Part::Part2), despawn_gizmos_camera);
     }
 }
@@ -116,7 +118,7 @@ fn build_ui(
 ) {
     bevy::log::trace!("Day 24 Part 2");
 
-    let header = build_header(&mut commands, "day24", true, fonts.font.clone());
+    let header = build_header(&mut commands, "day24", true, false, fonts.font.clone());
     let content = build_content(&mut commands, "day24");
 
     let gates = asset_server.load("gates.png");
@@ -140,25 +142,6 @@ fn build_ui(
     ui_state.set(UiState::Loaded);
 }
 
-fn spawn_gizmos_camera(mut commands: Commands) {
-    commands.spawn((
-        Name::new("day24_part2_gizmos_camera"),
-        Camera2d,
-        Camera {
-            order: 100,
-            ..Default::default()
-        },
-        RenderLayers::from_layers(&[1]),
-        GizmosCamera,
-    ));
-}
-
-fn despawn_gizmos_camera(mut commands: Commands, cameras: Query<Entity, With<GizmosCamera>>) {
-    for camera in cameras.iter() {
-        commands.entity(camera).despawn_recursive();
-    }
-}
-
 fn build_visualization(
     parent: &mut ChildBuilder,
     input: &Input,
@@ -193,8 +176,10 @@ fn build_visualization(
                 .with_children(|parent| {
                     let mut reverse_operations = input.operations.clone();
                     reverse_operations.reverse();
+                    let last = input.x.len() - 1;
+                    let last_carry = wire_name(b'z', input.x.len());
                     for (i, chunk) in reverse_operations.chunks(5).enumerate() {
-                        let index = u8::try_from(44 - i).unwrap();
+                        let index = u8::try_from(last - i).unwrap();
                         let xkey = [b'x', (index / 10) + b'0', (index % 10) + b'0'];
                         let ykey = [b'y', (index / 10) + b'0', (index % 10) + b'0'];
                         let zkey = [b'z', (index / 10) + b'0', (index % 10) + b'0'];
@@ -444,7 +429,7 @@ fn build_visualization(
                                                 out: or.out,
                                             },
                                         ));
-                                        if index == 44 {
+                                        if usize::from(index) == last {
                                             parent
                                                 .spawn((
                                                     Node {
@@ -464,13 +449,13 @@ fn build_visualization(
                                                         ..Default::default()
                                                     },
                                                     Gate {
-                                                        left: b"z45".to_owned(),
+                                                        left: last_carry,
                                                         right: [0; 3],
                                                         out: [0; 3],
                                                     },
                                                 ))
                                                 .with_child((
-                                                    Text::new("z45"),
+                                                    Text::new(String::from_utf8_lossy(&last_carry)),
                                                     TextColor(Color::BLACK),
                                                 ));
                                         }
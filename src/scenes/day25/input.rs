@@ -1,8 +1,8 @@
 use bevy::prelude::Resource;
 
-use crate::loader::RawInput;
+use crate::loader::{Line, ParseError, RawInput};
 
-use super::components::{Key, Lock};
+use super::components::{usable_key_on_lock, Key, Lock};
 
 #[derive(Debug, Resource)]
 pub struct Input {
@@ -11,59 +11,65 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(input: &RawInput) -> Self {
-        let lines = input.split(|c| *c == b'\n');
-
+    pub fn parse(input: &RawInput) -> Result<Self, ParseError> {
         let mut keys = Vec::new();
         let mut locks = Vec::new();
 
-        let mut reading_lock = false;
-        let mut temp = None;
+        let mut schematic = Vec::new();
 
-        for line in lines {
-            if line.is_empty() {
-                if reading_lock {
-                    let pins: (u8, u8, u8, u8, u8) = temp.take().unwrap();
-                    locks.push(Lock(pins.0, pins.1, pins.2, pins.3, pins.4));
-                } else {
-                    let pins = temp.take().unwrap();
-                    keys.push(Key(pins.0, pins.1, pins.2, pins.3, pins.4));
+        for line in input.lines().chain([Line {
+            number: 0,
+            bytes: &[],
+        }]) {
+            if !line.is_empty() {
+                if let Some(x) = line.iter().position(|c| !b"#.".contains(c)) {
+                    return Err(line.error(x, format!("unexpected tile `{}`", char::from(line[x]))));
                 }
-            } else {
-                let row = line
-                    .iter()
-                    .enumerate()
-                    .fold((0, 0, 0, 0, 0), |mut accum, (i, c)| {
-                        if *c == b'#' {
-                            match i {
-                                0 => accum.0 = 1,
-                                1 => accum.1 = 1,
-                                2 => accum.2 = 1,
-                                3 => accum.3 = 1,
-                                4 => accum.4 = 1,
-                                _ => unreachable!("Line too long"),
-                            };
-                        }
-                        accum
-                    });
-
-                if temp.is_none() {
-                    reading_lock = row == (1, 1, 1, 1, 1);
-                    temp.replace(row);
-                } else {
-                    temp = temp.map(|accum| {
-                        (
-                            accum.0 + row.0,
-                            accum.1 + row.1,
-                            accum.2 + row.2,
-                            accum.3 + row.3,
-                            accum.4 + row.4,
-                        )
-                    });
+                if line.len() != 5 {
+                    return Err(line.error(0, "expected 5 pins"));
                 }
+                schematic.push(line);
+                continue;
+            }
+            let Some(first) = schematic.first().copied() else {
+                continue;
+            };
+            if schematic.len() != 7 {
+                return Err(first.error(0, "expected a schematic 7 rows tall"));
+            }
+
+            let pins = schematic.drain(..).fold((0, 0, 0, 0, 0), |accum, row| {
+                let pin = |i: usize| u8::from(row[i] == b'#');
+                (
+                    accum.0 + pin(0),
+                    accum.1 + pin(1),
+                    accum.2 + pin(2),
+                    accum.3 + pin(3),
+                    accum.4 + pin(4),
+                )
+            });
+
+            if first.bytes == b"#####" {
+                locks.push(Lock(pins.0, pins.1, pins.2, pins.3, pins.4));
+            } else if first.bytes == b"....." {
+                keys.push(Key(pins.0, pins.1, pins.2, pins.3, pins.4));
+            } else {
+                return Err(first.error(0, "expected a lock or a key"));
             }
         }
 
-        Self { keys, locks }
+        Ok(Self { keys, locks })
+    }
+
+    pub fn fitting_pairs(&self) -> usize {
+        self.locks
+            .iter()
+            .map(|lock| {
+                self.keys
+                    .iter()
+                    .filter(|key| usable_key_on_lock(key, lock))
+                    .count()
+            })
+            .sum()
     }
 }
@@ -4,33 +4,31 @@ mod resources;
 
 use bevy::{
     app::Update,
-    asset::{AssetServer, Assets, RenderAssetUsages},
+    asset::{Assets, RenderAssetUsages},
     color::{palettes, Color},
-    core::Name,
     image::{Image, ImageSampler},
     prelude::{
-        in_state, resource_exists, resource_removed, AppExtStates, BuildChildren, Button, Camera2d,
-        Changed, ChildBuild, ChildBuilder, ClearColor, Commands, DespawnRecursiveExt, Entity,
-        ImageNode, IntoSystemConfigs, NextState, OnEnter, OnExit, Query, Res, ResMut, Text, With,
-        Without,
+        in_state, resource_exists, resource_removed, BuildChildren, Button, Changed, ChildBuild,
+        ChildBuilder, Commands, DespawnRecursiveExt, Entity, ImageNode, IntoSystemConfigs,
+        NextState, Query, Res, ResMut, Text, With, Without,
     },
     render::render_resource::{Extent3d, TextureDimension, TextureFormat},
     text::TextColor,
     ui::{
         BackgroundColor, BorderColor, BorderRadius, FlexDirection, FlexWrap, Interaction, Node,
-        PositionType, TargetCamera, UiRect, Val,
+        PositionType, UiRect, Val,
     },
 };
 use components::{usable_key_on_lock, Key, Lock};
 use resources::Hovered;
 
-use crate::{loader::RawInput, scenes::states::Scene as SceneStates};
+use crate::loader::{ParseError, PuzzleSizes, RawInput};
 
 use super::{
-    days::{build_content, build_footer, build_header},
+    compute::Progress,
+    days::{self, build_content, build_footer, build_header, DayPlugin, PuzzleInput},
     resources::{FontHandles, GenericDay},
-    state_button_interactions,
-    states::{InputState, UiState, VisualizationState},
+    states::{Part, UiState, VisualizationState},
 };
 
 const PIXEL_PER_UNIT: u32 = 1;
@@ -41,40 +39,30 @@ pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_computed_state::<VisualizationState<25>>();
+        app.add_plugins(DayPlugin::<2024, 25, input::Input>::default());
 
-        app.add_systems(OnEnter(SceneStates::Day(25)), build_day_25)
-            .add_systems(OnExit(SceneStates::Day(25)), destroy_day_25)
-            .add_systems(
-                Update,
-                process_input.run_if(in_state(VisualizationState::<25>::WaitingInput)),
-            )
-            .add_systems(
-                Update,
-                build_ui.run_if(in_state(VisualizationState::<25>::WaitingUi)),
-            )
-            .add_systems(
-                Update,
-                state_button_interactions.run_if(in_state(VisualizationState::<25>::Ready)),
-            )
-            .add_systems(
-                Update,
-                check_hovered.run_if(in_state(VisualizationState::<25>::Ready)),
-            )
-            .add_systems(
-                Update,
-                update_keys_and_locks
-                    .after(check_hovered)
-                    .run_if(in_state(VisualizationState::<25>::Ready))
-                    .run_if(resource_exists::<Hovered>),
-            )
-            .add_systems(
-                Update,
-                (clear_locks, clear_keys)
-                    .after(check_hovered)
-                    .run_if(in_state(VisualizationState::<25>::Ready))
-                    .run_if(resource_removed::<Hovered>),
-            );
+        app.add_systems(
+            Update,
+            build_ui.run_if(in_state(VisualizationState::<2024, 25>::WaitingUi)),
+        )
+        .add_systems(
+            Update,
+            check_hovered.run_if(in_state(VisualizationState::<2024, 25>::Ready)),
+        )
+        .add_systems(
+            Update,
+            update_keys_and_locks
+                .after(check_hovered)
+                .run_if(in_state(VisualizationState::<2024, 25>::Ready))
+                .run_if(resource_exists::<Hovered>),
+        )
+        .add_systems(
+            Update,
+            (clear_locks, clear_keys)
+                .after(check_hovered)
+                .run_if(in_state(VisualizationState::<2024, 25>::Ready))
+                .run_if(resource_removed::<Hovered>),
+        );
     }
 }
 
@@ -97,30 +85,29 @@ fn check_hovered(
         match interaction {
             Interaction::None => match hovered.as_deref() {
                 None => (),
-                Some(Hovered::Lock(lock)) => {
+                Some(Hovered::Lock(lock))
                     if locks
                         .get(button)
                         .ok()
                         .filter(|button| *button == lock)
                         .is_some()
-                        && !hovered_changed
-                    {
-                        bevy::log::trace!("Removing Lock hovered");
-                        commands.remove_resource::<Hovered>();
-                    }
+                        && !hovered_changed =>
+                {
+                    bevy::log::trace!("Removing Lock hovered");
+                    commands.remove_resource::<Hovered>();
                 }
-                Some(Hovered::Key(key)) => {
+                Some(Hovered::Key(key))
                     if keys
                         .get(button)
                         .ok()
                         .filter(|button| *button == key)
                         .is_some()
-                        && !hovered_changed
-                    {
-                        bevy::log::trace!("Removing Key hovered");
-                        commands.remove_resource::<Hovered>();
-                    }
+                        && !hovered_changed =>
+                {
+                    bevy::log::trace!("Removing Key hovered");
+                    commands.remove_resource::<Hovered>();
                 }
+                Some(_) => (),
             },
             Interaction::Hovered | Interaction::Pressed => {
                 if let Ok(lock) = locks.get(button) {
@@ -182,48 +169,6 @@ fn clear_keys(mut keys: Query<&mut BorderColor, (With<Key>, Without<Lock>)>) {
     }
 }
 
-fn build_day_25(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let camera = commands.spawn((Name::new("day25_camera"), Camera2d)).id();
-    let day25_resource = GenericDay {
-        input: asset_server.load("inputs/day25.txt"),
-        camera,
-        ui: commands
-            .spawn((
-                Name::new("day25_ui"),
-                Node {
-                    width: Val::Percent(100.),
-                    height: Val::Percent(100.),
-                    flex_direction: FlexDirection::Column,
-                    ..Default::default()
-                },
-                TargetCamera(camera),
-            ))
-            .id(),
-    };
-
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
-    commands.insert_resource(day25_resource);
-}
-
-fn destroy_day_25(mut commands: Commands, day25_resource: Res<GenericDay>) {
-    commands.entity(day25_resource.camera).despawn_recursive();
-    commands.entity(day25_resource.ui).despawn_recursive();
-
-    commands.remove_resource::<GenericDay>();
-}
-
-fn process_input(
-    mut commands: Commands,
-    day25: Res<GenericDay>,
-    inputs: Res<Assets<RawInput>>,
-    mut next_state: ResMut<NextState<InputState>>,
-) {
-    if let Some(input) = inputs.get(day25.input.id()) {
-        commands.insert_resource(input::Input::parse(input));
-        next_state.set(InputState::Loaded);
-    }
-}
-
 fn build_ui(
     mut commands: Commands,
     day25_resource: Res<GenericDay>,
@@ -232,7 +177,7 @@ fn build_ui(
     mut next_state: ResMut<NextState<UiState>>,
     fonts: Res<FontHandles>,
 ) {
-    let header = build_header(&mut commands, "day25", false, fonts.font.clone());
+    let header = build_header(&mut commands, "day25", false, false, fonts.font.clone());
     let content = build_content(&mut commands, "day25");
     let footer = build_footer(&mut commands, "day25");
 
@@ -241,6 +186,7 @@ fn build_ui(
         .with_children(|parent| build_visualization(parent, &input, &mut images));
     commands
         .entity(day25_resource.ui)
+        .despawn_descendants()
         .add_children(&[header, content, footer]);
 
     next_state.set(UiState::Loaded);
@@ -414,3 +360,46 @@ fn build_keys(parent: &mut ChildBuilder, input: &input::Input, images: &mut Asse
                 });
         });
 }
+
+impl PuzzleInput for input::Input {
+    fn parse_input(
+        input: &RawInput,
+        _sizes: PuzzleSizes,
+        _progress: &Progress,
+    ) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
+
+    fn solve_part(&mut self, part: Part, _progress: &Progress) -> String {
+        match part {
+            Part::Part1 => self.fitting_pairs().to_string(),
+            Part::Part2 => "Merry Christmas!".to_string(),
+        }
+    }
+}
+
+pub fn solve(
+    input: &RawInput,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    days::solve::<input::Input>(input, sizes, progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        loader::{PuzzleSizes, RawInput},
+        scenes::compute::Progress,
+    };
+
+    #[test]
+    fn example() {
+        let input = RawInput(include_bytes!("../../../assets/examples/2024/day25.txt").to_vec());
+
+        assert_eq!(
+            super::solve(&input, PuzzleSizes::EXAMPLE, &Progress::default()).unwrap(),
+            ["3", "Merry Christmas!"]
+        );
+    }
+}
@@ -1,25 +1,150 @@
+use std::marker::PhantomData;
+
 use bevy::{
-    asset::Handle,
+    app::Update,
+    asset::{AssetServer, Assets, Handle},
     color::Color,
     core::Name,
-    prelude::{BuildChildren, Button, ChildBuild, ChildBuilder, Commands, Entity, Text},
+    prelude::{
+        in_state, AppExtStates, BuildChildren, Button, Camera, Camera2d, ChildBuild, ChildBuilder,
+        Commands, DespawnRecursiveExt, Entity, IntoSystemConfigs, NextState, OnEnter, OnExit,
+        Query, Res, ResMut, Resource, Text, With,
+    },
+    render::view::RenderLayers,
     text::{Font, TextColor, TextFont},
     ui::{
-        AlignItems, BackgroundColor, FlexDirection, JustifyContent, Node, PositionType, UiRect, Val,
+        AlignItems, BackgroundColor, FlexDirection, JustifyContent, Node, PositionType,
+        TargetCamera, UiRect, Val,
     },
 };
 
-use crate::scroll_controls::BUTTON_BACKGROUND_COLOR;
+use crate::{
+    loader::{CustomSizes, InputDirectory, InputSource, ParseError, PuzzleSizes, RawInput},
+    scroll_controls::BUTTON_BACKGROUND_COLOR,
+};
 
 use super::{
-    components::{PartChange, SceneChange},
-    states::Scene,
+    capture::build_capture_buttons,
+    components::{GizmosCamera, InputSourceChange, PartChange, SceneChange},
+    compute::{compute_input, Progress},
+    playback::build_speed_slider,
+    resources::GenericDay,
+    state_button_interactions,
+    states::{InputState, Part, Scene, VisualizationState},
 };
 
+/// Input of a day, parsed then solved on a background task whenever the day opens or its input
+/// changes
+pub trait PuzzleInput: Resource + Sized {
+    fn parse_input(
+        input: &RawInput,
+        sizes: PuzzleSizes,
+        progress: &Progress,
+    ) -> Result<Self, ParseError>;
+
+    /// Answer of `part`, keeping on the input what its visualization shows of the solution
+    fn solve_part(&mut self, part: Part, progress: &Progress) -> String;
+
+    /// Creates the assets shown by the visualization, still on the background task
+    fn load_assets(&mut self, _asset_server: &AssetServer) {}
+}
+
+/// Camera, UI root and input of day `N` of the `YEAR` event, parsed into `I`.
+///
+/// Each day adds the plugins building the UI of its parts on top, once its
+/// [`VisualizationState`] is waiting for it.
+pub struct DayPlugin<const YEAR: u16, const N: u8, I: PuzzleInput>(PhantomData<I>);
+
+impl<const YEAR: u16, const N: u8, I: PuzzleInput> Default for DayPlugin<YEAR, N, I> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<const YEAR: u16, const N: u8, I: PuzzleInput> bevy::app::Plugin for DayPlugin<YEAR, N, I> {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_computed_state::<VisualizationState<YEAR, N>>();
+
+        let scene = Scene::Day { year: YEAR, day: N };
+        app.add_systems(OnEnter(scene), build_day::<YEAR, N>)
+            .add_systems(OnExit(scene), destroy_day::<I>)
+            .add_systems(
+                Update,
+                process_input::<I>.run_if(in_state(VisualizationState::<YEAR, N>::WaitingInput)),
+            )
+            .add_systems(Update, state_button_interactions.run_if(in_state(scene)));
+    }
+}
+
+pub fn build_day<const YEAR: u16, const N: u8>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    input_source: Res<InputSource>,
+    input_directory: Res<InputDirectory>,
+) {
+    let camera = commands
+        .spawn((Name::new(format!("day{N}_camera")), Camera2d))
+        .id();
+    let day_resource = GenericDay {
+        input: asset_server.load(input_source.path(&input_directory, YEAR, N)),
+        camera,
+        ui: commands
+            .spawn((
+                Name::new(format!("day{N}_ui")),
+                Node {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    ..Default::default()
+                },
+                TargetCamera(camera),
+            ))
+            .id(),
+    };
+
+    commands.insert_resource(day_resource);
+}
+
+fn destroy_day<I: PuzzleInput>(mut commands: Commands, day_resource: Res<GenericDay>) {
+    commands.entity(day_resource.camera).despawn_recursive();
+    commands.entity(day_resource.ui).despawn_recursive();
+
+    commands.remove_resource::<GenericDay>();
+    commands.remove_resource::<I>();
+}
+
+fn process_input<I: PuzzleInput>(
+    mut commands: Commands,
+    day_resource: Res<GenericDay>,
+    inputs: Res<Assets<RawInput>>,
+    input_source: Res<InputSource>,
+    custom_sizes: Res<CustomSizes>,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<InputState>>,
+) {
+    if let Some(input) = inputs.get(day_resource.input.id()) {
+        let sizes = input_source.sizes(&custom_sizes);
+        compute_input::<I>(&mut commands, input, sizes, asset_server.clone());
+        next_state.set(InputState::Computing);
+    }
+}
+
+/// Answers of both parts, from the same input the visualization uses
+pub fn solve<I: PuzzleInput>(
+    input: &RawInput,
+    sizes: PuzzleSizes,
+    progress: &Progress,
+) -> Result<[String; 2], ParseError> {
+    let mut input = I::parse_input(input, sizes, progress)?;
+
+    Ok([Part::Part1, Part::Part2].map(|part| input.solve_part(part, progress)))
+}
+
 pub fn build_header(
     commands: &mut Commands,
     day: &str,
     part_change: bool,
+    speed_control: bool,
     font: Handle<Font>,
 ) -> Entity {
     let mut header = commands.spawn((
@@ -40,7 +165,14 @@ pub fn build_header(
             ..Default::default()
         },
     ));
-    header.with_children(|parent| build_state_buttons(parent, part_change, font));
+    header.with_children(|parent| {
+        build_state_buttons(parent, part_change, font.clone());
+        build_input_source_buttons(parent, font.clone());
+        build_capture_buttons(parent, speed_control, font.clone());
+        if speed_control {
+            build_speed_slider(parent, font);
+        }
+    });
     header.id()
 }
 
@@ -85,6 +217,28 @@ pub fn build_footer(commands: &mut Commands, day: &str) -> Entity {
         .id()
 }
 
+/// Spawns a camera rendering the gizmos of render layer 1 over the UI
+pub fn spawn_gizmos_camera(name: &'static str) -> impl FnMut(Commands) {
+    move |mut commands: Commands| {
+        commands.spawn((
+            Name::new(name),
+            Camera2d,
+            Camera {
+                order: 100,
+                ..Default::default()
+            },
+            RenderLayers::from_layers(&[1]),
+            GizmosCamera,
+        ));
+    }
+}
+
+pub fn despawn_gizmos_camera(mut commands: Commands, cameras: Query<Entity, With<GizmosCamera>>) {
+    for camera in cameras.iter() {
+        commands.entity(camera).despawn_recursive();
+    }
+}
+
 fn build_state_buttons(parent: &mut ChildBuilder, part_change: bool, font: Handle<Font>) {
     parent
         .spawn((
@@ -133,6 +287,33 @@ fn build_state_buttons(parent: &mut ChildBuilder, part_change: bool, font: Handl
     }
 }
 
+fn build_input_source_buttons(parent: &mut ChildBuilder, font: Handle<Font>) {
+    parent
+        .spawn(Node {
+            margin: UiRect::left(Val::Px(20.)),
+            column_gap: Val::Px(5.),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for source in InputSource::ALL {
+                parent
+                    .spawn((
+                        button_node(),
+                        BackgroundColor(BUTTON_BACKGROUND_COLOR),
+                        InputSourceChange(source),
+                    ))
+                    .with_child((
+                        Text::new(source.name()),
+                        TextFont {
+                            font: font.clone(),
+                            ..Default::default()
+                        },
+                        TextColor(Color::BLACK),
+                    ));
+            }
+        });
+}
+
 pub fn button_node() -> Node {
     Node {
         width: Val::Px(75.),
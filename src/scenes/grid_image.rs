@@ -0,0 +1,203 @@
+use std::ops::Range;
+
+use bevy::{
+    app::PostUpdate,
+    asset::{AssetId, Assets, RenderAssetUsages},
+    color::{Color, ColorToPacked},
+    image::{Image, ImageSampler},
+    prelude::{
+        resource_exists, Added, Changed, Component, IntoSystemConfigs, Query, Res, ResMut, Resource,
+    },
+    render::{
+        render_asset::RenderAssets,
+        render_resource::{
+            Extent3d, ImageCopyTexture, ImageDataLayout, Origin3d, TextureAspect, TextureDimension,
+            TextureFormat,
+        },
+        renderer::RenderQueue,
+        texture::GpuImage,
+        ExtractSchedule, MainWorld, Render, RenderApp, RenderSet,
+    },
+    ui::{widget::ImageNode, Node, RelativeCursorPosition},
+};
+
+use crate::tools::Coord;
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                create_images,
+                queue_changed_rows.run_if(resource_exists::<RowUploads>),
+            )
+                .chain(),
+        );
+
+        // Without a renderer, as in the UI tests, only the cells are kept
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .init_resource::<RowUploads>()
+            .add_systems(ExtractSchedule, extract_row_uploads)
+            .add_systems(
+                Render,
+                write_row_uploads.in_set(RenderSet::PrepareResources),
+            );
+        app.init_resource::<RowUploads>();
+    }
+}
+
+/// Grid drawn into a single texture, one pixel per cell.
+///
+/// Cells are painted on the CPU side, the rows that changed are written straight into the
+/// texture. Modifying the image asset would upload it whole again, Bevy 0.15 has no partial
+/// asset updates, so the asset keeps the pixels the grid was created with.
+#[derive(Debug, Component)]
+#[require(Node, ImageNode, RelativeCursorPosition)]
+pub struct GridImage {
+    dimensions: Coord,
+    cells: Vec<[u8; 4]>,
+    changed_rows: Option<Range<usize>>,
+}
+
+impl GridImage {
+    pub fn new(dimensions: Coord, background: Color) -> Self {
+        Self {
+            dimensions,
+            cells: vec![background.to_srgba().to_u8_array(); dimensions.row * dimensions.column],
+            changed_rows: None,
+        }
+    }
+
+    pub fn set(&mut self, coord: Coord, color: Color) {
+        let index = coord.row * self.dimensions.column + coord.column;
+        let color = color.to_srgba().to_u8_array();
+        if self.cells[index] != color {
+            self.cells[index] = color;
+            self.changed_rows = Some(match self.changed_rows.take() {
+                Some(rows) => rows.start.min(coord.row)..rows.end.max(coord.row + 1),
+                None => coord.row..coord.row + 1,
+            });
+        }
+    }
+
+    /// Maps the cursor back to the cell below it
+    pub fn hovered_cell(&self, cursor: &RelativeCursorPosition) -> Option<Coord> {
+        if !cursor.mouse_over() {
+            return None;
+        }
+        let normalized = cursor.normalized?;
+        let row = (normalized.y * self.dimensions.row as f32) as usize;
+        let column = (normalized.x * self.dimensions.column as f32) as usize;
+
+        (row < self.dimensions.row && column < self.dimensions.column)
+            .then_some(Coord::new(row, column))
+    }
+
+    fn to_image(&self) -> Image {
+        let mut image = Image::new(
+            Extent3d {
+                width: u32::try_from(self.dimensions.column).unwrap(),
+                height: u32::try_from(self.dimensions.row).unwrap(),
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            self.cells.concat(),
+            TextureFormat::Rgba8UnormSrgb,
+            // The UI measures the image from the main world copy
+            RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD,
+        );
+        image.sampler = ImageSampler::nearest();
+
+        image
+    }
+}
+
+fn create_images(
+    mut grids: Query<(&mut GridImage, &mut ImageNode), Added<GridImage>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for (mut grid, mut image_node) in grids.iter_mut() {
+        image_node.image = images.add(grid.to_image());
+        grid.changed_rows = None;
+    }
+}
+
+/// Consecutive rows of a grid to write into its texture
+#[derive(Debug)]
+struct RowUpload {
+    image: AssetId<Image>,
+    first_row: u32,
+    size: Extent3d,
+    data: Vec<u8>,
+}
+
+/// Rows waiting to be extracted in the main world, or written in the render world
+#[derive(Debug, Default, Resource)]
+struct RowUploads(Vec<RowUpload>);
+
+fn queue_changed_rows(
+    mut grids: Query<(&mut GridImage, &ImageNode), Changed<GridImage>>,
+    mut uploads: ResMut<RowUploads>,
+) {
+    for (mut grid, image_node) in grids.iter_mut() {
+        let Some(rows) = grid.changed_rows.clone() else {
+            continue;
+        };
+        grid.changed_rows = None;
+
+        let columns = grid.dimensions.column;
+        uploads.0.push(RowUpload {
+            image: image_node.image.id(),
+            first_row: u32::try_from(rows.start).unwrap(),
+            size: Extent3d {
+                width: u32::try_from(columns).unwrap(),
+                height: u32::try_from(rows.len()).unwrap(),
+                depth_or_array_layers: 1,
+            },
+            data: grid.cells[rows.start * columns..rows.end * columns].concat(),
+        });
+    }
+}
+
+fn extract_row_uploads(mut main_world: ResMut<MainWorld>, mut uploads: ResMut<RowUploads>) {
+    uploads
+        .0
+        .append(&mut main_world.resource_mut::<RowUploads>().0);
+}
+
+fn write_row_uploads(
+    mut uploads: ResMut<RowUploads>,
+    images: Res<RenderAssets<GpuImage>>,
+    queue: Res<RenderQueue>,
+) {
+    for upload in uploads.0.drain(..) {
+        // Images are prepared the frame they are created, before any of their rows change
+        let Some(image) = images.get(upload.image) else {
+            continue;
+        };
+        queue.write_texture(
+            ImageCopyTexture {
+                texture: &image.texture,
+                mip_level: 0,
+                origin: Origin3d {
+                    x: 0,
+                    y: upload.first_row,
+                    z: 0,
+                },
+                aspect: TextureAspect::All,
+            },
+            &upload.data,
+            ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(upload.size.width * 4),
+                rows_per_image: None,
+            },
+            upload.size,
+        );
+    }
+}
@@ -2,9 +2,8 @@ mod resources;
 mod ui;
 
 use bevy::{
-    color::Color,
     core::Name,
-    prelude::{Camera2d, ClearColor, Commands, DespawnRecursiveExt, OnEnter, OnExit, Res},
+    prelude::{Camera2d, Commands, DespawnRecursiveExt, OnEnter, OnExit, Res},
 };
 
 use crate::scenes::states::Scene;
@@ -30,7 +29,6 @@ fn build_main_menu(mut commands: Commands) {
         ..Default::default()
     };
 
-    commands.insert_resource(ClearColor(Color::srgb_u8(0x0f, 0x0f, 0x23)));
     commands.insert_resource(main_menu_resource);
 }
 
@@ -14,3 +14,7 @@ impl Default for MainMenu {
         }
     }
 }
+
+/// Day selected with the keyboard
+#[derive(Debug, Default, Resource)]
+pub struct FocusedDay(pub Option<u8>);
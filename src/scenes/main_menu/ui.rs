@@ -1,68 +1,298 @@
 use bevy::{
     app::Update,
+    asset::{AssetServer, Handle},
     color::Color,
     core::Name,
+    image::Image,
+    input::keyboard::KeyCode,
     prelude::{
-        in_state, BuildChildren, Button, Changed, ChildBuild, ChildBuilder, Commands,
-        IntoSystemConfigs, NextState, OnEnter, Query, ResMut, Text, With, Without,
+        in_state, not, resource_added, resource_changed, BuildChildren, Button, ButtonInput,
+        Changed, ChildBuild, ChildBuilder, Commands, Component, Condition, DespawnRecursiveExt,
+        Entity, Has, IntoSystemConfigs, NextState, OnEnter, Query, Res, ResMut, Single, Text, With,
+        Without,
+    },
+    text::{Font, TextColor, TextFont, TextLayout},
+    ui::{
+        widget::ImageNode, AlignItems, BackgroundColor, BorderColor, BorderRadius, Display,
+        FlexDirection, Interaction, JustifyContent, Node, Outline, Overflow, RepeatedGridTrack,
+        UiRect, Val,
     },
-    text::{TextColor, TextFont},
-    ui::{FlexDirection, Interaction, JustifyContent, Node, UiRect, Val},
 };
 
-use crate::scenes::{
-    components::{Disabled, SceneChange},
-    states::Scene,
+use crate::{
+    loader::{InputDirectory, InputSource},
+    scenes::{
+        answers::{Solution, Solutions, INCORRECT_COLOR},
+        components::{Disabled, InputSourceChange, SceneChange},
+        main_menu::resources::FocusedDay,
+        resources::Year,
+        settings::{Settings, Theme},
+        states::Scene,
+        thumbnails::Thumbnails,
+        EVENTS,
+    },
 };
 
 const DISABLED_COLOR: Color = Color::srgb(0.3, 0.3, 0.3);
 const HOVERED_COLOR: Color = Color::srgb(0.7, 1.0, 1.0);
+const SELECTED_COLOR: Color = Color::srgb(1.0, 1.0, 0.4);
+const STAR_COLOR: Color = Color::srgb(1.0, 1.0, 0.4);
+const TILE_BORDER_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+const TILE_BACKGROUND_COLOR: Color = Color::srgb(0.09, 0.09, 0.2);
+const THUMBNAIL_PLACEHOLDER_COLOR: Color = Color::srgb(0.15, 0.15, 0.28);
+/// Shown instead of the thumbnail of a day that was not opened since launch
+const THUMBNAIL_PLACEHOLDER: &str = "Open to preview";
+/// Days in each row of the calendar
+const ROW_LENGTH: u8 = 5;
 
 type ButtonWithChangedInteractionQuery<'a, 'b> = Query<
     'a,
     'b,
     (
         &'static Interaction,
-        &'static mut TextColor,
+        &'static mut BorderColor,
         &'static SceneChange,
     ),
     (With<Button>, Without<Disabled>, Changed<Interaction>),
 >;
 
+type InputSourceWithChangedInteractionQuery<'a, 'b> = Query<
+    'a,
+    'b,
+    (
+        &'static Interaction,
+        &'static mut TextColor,
+        &'static InputSourceChange,
+    ),
+    (With<Button>, Changed<Interaction>),
+>;
+
+type ThemeWithChangedInteractionQuery<'a, 'b> = Query<
+    'a,
+    'b,
+    (
+        &'static Interaction,
+        &'static mut TextColor,
+        &'static ThemeChange,
+    ),
+    (With<Button>, Changed<Interaction>),
+>;
+
+type YearWithChangedInteractionQuery<'a, 'b> = Query<
+    'a,
+    'b,
+    (
+        &'static Interaction,
+        &'static mut TextColor,
+        &'static YearChange,
+    ),
+    (With<Button>, Changed<Interaction>),
+>;
+
+#[derive(Debug, Clone, Copy, Component)]
+#[require(Button)]
+struct ThemeChange(Theme);
+
+#[derive(Debug, Clone, Copy, Component)]
+#[require(Button)]
+struct YearChange(u16);
+
+#[derive(Debug, Component)]
+struct TitleCard;
+
+/// Grid of the day tiles, rebuilt when another year is selected
+#[derive(Debug, Component)]
+struct Calendar;
+
+/// Star of a part on a day tile, lit when its answer verifies
+#[derive(Debug, Component)]
+struct Star {
+    day: u8,
+    part: usize,
+}
+
+#[derive(Debug, Component)]
+struct StarCount;
+
+#[derive(Debug, Component)]
+struct TileTitle(u8);
+
+/// Whether the input of a day is present
+#[derive(Debug, Component)]
+struct TileInput(u8);
+
+/// Answer of a part as solved in the background
+#[derive(Debug, Component)]
+struct TileAnswer {
+    day: u8,
+    part: usize,
+}
+
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<FocusedDay>();
+
         app.add_systems(
             OnEnter(Scene::MainMenu),
-            (build_ui, update_disabled_text_color)
+            (
+                build_ui,
+                update_disabled_days,
+                update_focused_day,
+                update_solutions,
+            )
                 .chain()
                 .after(super::build_main_menu),
         );
-        app.add_systems(Update, button_interaction.run_if(in_state(Scene::MainMenu)));
+        app.add_systems(
+            Update,
+            (
+                button_interaction,
+                input_source_interaction,
+                theme_interaction,
+                year_interaction,
+                keyboard_navigation,
+                (
+                    rebuild_calendar,
+                    update_title_card,
+                    update_year_text_color,
+                    update_disabled_days,
+                    update_focused_day,
+                )
+                    .chain()
+                    .run_if(resource_changed::<Year>.and(not(resource_added::<Year>))),
+                (update_disabled_days, update_input_source_text_color)
+                    .run_if(resource_changed::<InputSource>),
+                update_disabled_days.run_if(resource_changed::<InputDirectory>),
+                update_theme_text_color.run_if(resource_changed::<Settings>),
+                update_focused_day.run_if(resource_changed::<FocusedDay>),
+                update_solutions.run_if(resource_changed::<Solutions>),
+            )
+                .run_if(in_state(Scene::MainMenu)),
+        );
     }
 }
 
-fn build_ui(mut commands: Commands, mut main_menu_resource: ResMut<super::resources::MainMenu>) {
+fn build_ui(
+    mut commands: Commands,
+    mut main_menu_resource: ResMut<super::resources::MainMenu>,
+    asset_server: Res<AssetServer>,
+    thumbnails: Res<Thumbnails>,
+    year: Res<Year>,
+) {
+    let symbols = asset_server.load("NotoSansSymbols2-Regular.ttf");
     let ui = commands
         .spawn((
             Name::new("main_menu_ui"),
             Node {
                 width: Val::Percent(100.),
                 height: Val::Percent(100.),
-                flex_direction: FlexDirection::Row,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(20.)),
+                row_gap: Val::Px(12.),
                 ..Default::default()
             },
         ))
-        .with_children(build_ui_divs)
+        .with_children(|parent| {
+            build_ui_header(parent, year.0, symbols.clone());
+            build_ui_calendar(parent, year.0, symbols, &thumbnails);
+        })
         .id();
 
     main_menu_resource.ui = ui;
 }
 
-fn update_disabled_text_color(mut buttons: Query<&mut TextColor, (With<Button>, With<Disabled>)>) {
-    for mut button in buttons.iter_mut() {
-        button.0 = DISABLED_COLOR;
+fn update_disabled_days(
+    mut commands: Commands,
+    mut days: Query<(Entity, &SceneChange, &mut BorderColor, Has<Disabled>)>,
+    mut titles: Query<(&TileTitle, &mut TextColor)>,
+    mut inputs: Query<(&TileInput, &mut Text, &mut TextColor), Without<TileTitle>>,
+    input_source: Res<InputSource>,
+    input_directory: Res<InputDirectory>,
+    year: Res<Year>,
+) {
+    let present: [bool; 25] =
+        std::array::from_fn(|index| input_source.exists(&input_directory, year.0, index as u8 + 1));
+
+    for (entity, scene_change, mut border_color, disabled) in days.iter_mut() {
+        let Scene::Day { day, .. } = scene_change.0 else {
+            continue;
+        };
+        match (present[usize::from(day - 1)], disabled) {
+            (true, true) => {
+                commands.entity(entity).remove::<Disabled>();
+                border_color.0 = TILE_BORDER_COLOR;
+            }
+            (false, false) => {
+                commands.entity(entity).insert(Disabled);
+                border_color.0 = DISABLED_COLOR;
+            }
+            _ => (),
+        }
+    }
+    for (title, mut text_color) in titles.iter_mut() {
+        text_color.0 = if present[usize::from(title.0 - 1)] {
+            Color::WHITE
+        } else {
+            DISABLED_COLOR
+        };
+    }
+    for (input, mut text, mut text_color) in inputs.iter_mut() {
+        if present[usize::from(input.0 - 1)] {
+            text.0 = String::from("input");
+            text_color.0 = TILE_BORDER_COLOR;
+        } else {
+            text.0 = String::from("no input");
+            text_color.0 = DISABLED_COLOR;
+        }
+    }
+}
+
+fn update_solutions(
+    mut stars: Query<(&Star, &mut TextColor)>,
+    mut answers: Query<(&TileAnswer, &mut Text, &mut TextColor), Without<Star>>,
+    mut star_count: Query<&mut Text, (With<StarCount>, Without<TileAnswer>)>,
+    solutions: Res<Solutions>,
+) {
+    for (star, mut text_color) in stars.iter_mut() {
+        text_color.0 = match solutions.stars[usize::from(star.day - 1)][star.part - 1] {
+            Some(true) => STAR_COLOR,
+            Some(false) => INCORRECT_COLOR,
+            None => DISABLED_COLOR,
+        };
+    }
+
+    for (answer, mut text, mut text_color) in answers.iter_mut() {
+        (text.0, text_color.0) = match &solutions.solutions[usize::from(answer.day - 1)] {
+            Solution::Pending => (String::from("..."), DISABLED_COLOR),
+            Solution::Missing => (String::from("-"), DISABLED_COLOR),
+            Solution::Solved(results) => (results[answer.part - 1].clone(), Color::WHITE),
+            Solution::Failed => (String::from("error"), INCORRECT_COLOR),
+        };
+    }
+
+    let count = solutions
+        .stars
+        .iter()
+        .flatten()
+        .filter(|star| **star == Some(true))
+        .count();
+    for mut text in star_count.iter_mut() {
+        text.0 = format!("{count}/50");
+    }
+}
+
+fn update_input_source_text_color(
+    mut buttons: Query<(&mut TextColor, &InputSourceChange)>,
+    input_source: Res<InputSource>,
+) {
+    for (mut text_color, source_change) in buttons.iter_mut() {
+        text_color.0 = if source_change.0 == *input_source {
+            SELECTED_COLOR
+        } else {
+            Color::WHITE
+        };
     }
 }
 
@@ -70,138 +300,475 @@ fn button_interaction(
     mut buttons: ButtonWithChangedInteractionQuery,
     mut next_state: ResMut<NextState<Scene>>,
 ) {
-    for (interaction, mut text_color, button_next_state) in buttons.iter_mut() {
+    for (interaction, mut border_color, button_next_state) in buttons.iter_mut() {
+        match interaction {
+            Interaction::None => border_color.0 = TILE_BORDER_COLOR,
+            Interaction::Hovered => border_color.0 = HOVERED_COLOR,
+            Interaction::Pressed => next_state.set(button_next_state.0),
+        }
+    }
+}
+
+/// Arrows move through the calendar, Enter or Space opens the focused day
+fn keyboard_navigation(
+    keys: Res<ButtonInput<KeyCode>>,
+    days: Query<(&SceneChange, Has<Disabled>)>,
+    mut focused: ResMut<FocusedDay>,
+    year: Res<Year>,
+    mut next_state: ResMut<NextState<Scene>>,
+) {
+    if keys.any_just_pressed([KeyCode::Enter, KeyCode::Space]) {
+        let Some(day) = focused.0 else {
+            return;
+        };
+        let scene = Scene::Day { year: year.0, day };
+        if days
+            .iter()
+            .any(|(scene_change, disabled)| scene_change.0 == scene && !disabled)
+        {
+            next_state.set(scene);
+        }
+        return;
+    }
+
+    let Some(day) = focused.0 else {
+        if keys.any_just_pressed([
+            KeyCode::ArrowUp,
+            KeyCode::ArrowDown,
+            KeyCode::ArrowLeft,
+            KeyCode::ArrowRight,
+        ]) {
+            focused.0 = Some(1);
+        }
+        return;
+    };
+
+    let column = (day - 1) % ROW_LENGTH;
+    let next_day = if keys.just_pressed(KeyCode::ArrowUp) && day > ROW_LENGTH {
+        day - ROW_LENGTH
+    } else if keys.just_pressed(KeyCode::ArrowDown) && day <= 25 - ROW_LENGTH {
+        day + ROW_LENGTH
+    } else if keys.just_pressed(KeyCode::ArrowLeft) && column > 0 {
+        day - 1
+    } else if keys.just_pressed(KeyCode::ArrowRight) && column < ROW_LENGTH - 1 {
+        day + 1
+    } else {
+        return;
+    };
+    if next_day != day {
+        focused.0 = Some(next_day);
+    }
+}
+
+fn update_focused_day(
+    mut commands: Commands,
+    days: Query<(Entity, &SceneChange)>,
+    focused: Res<FocusedDay>,
+) {
+    for (entity, scene_change) in days.iter() {
+        if matches!(scene_change.0, Scene::Day { day, .. } if focused.0 == Some(day)) {
+            commands
+                .entity(entity)
+                .insert(Outline::new(Val::Px(2.), Val::Px(2.), HOVERED_COLOR));
+        } else {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+}
+
+fn input_source_interaction(
+    mut buttons: InputSourceWithChangedInteractionQuery,
+    mut input_source: ResMut<InputSource>,
+) {
+    for (interaction, mut text_color, source_change) in buttons.iter_mut() {
         match interaction {
+            Interaction::None if source_change.0 == *input_source => {
+                text_color.0 = SELECTED_COLOR;
+            }
             Interaction::None => text_color.0 = Color::WHITE,
             Interaction::Hovered => text_color.0 = HOVERED_COLOR,
-            Interaction::Pressed => next_state.set(button_next_state.0),
+            Interaction::Pressed => *input_source = source_change.0,
+        }
+    }
+}
+
+fn theme_interaction(
+    mut buttons: ThemeWithChangedInteractionQuery,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut text_color, theme_change) in buttons.iter_mut() {
+        match interaction {
+            Interaction::None if theme_change.0 == settings.theme => {
+                text_color.0 = SELECTED_COLOR;
+            }
+            Interaction::None => text_color.0 = Color::WHITE,
+            Interaction::Hovered => text_color.0 = HOVERED_COLOR,
+            Interaction::Pressed => settings.theme = theme_change.0,
         }
     }
 }
 
-fn build_ui_divs(parent: &mut ChildBuilder) {
+fn year_interaction(mut buttons: YearWithChangedInteractionQuery, mut year: ResMut<Year>) {
+    for (interaction, mut text_color, year_change) in buttons.iter_mut() {
+        match interaction {
+            Interaction::None if year_change.0 == year.0 => text_color.0 = SELECTED_COLOR,
+            Interaction::None => text_color.0 = Color::WHITE,
+            Interaction::Hovered => text_color.0 = HOVERED_COLOR,
+            Interaction::Pressed if year_change.0 != year.0 => year.0 = year_change.0,
+            Interaction::Pressed => (),
+        }
+    }
+}
+
+fn update_year_text_color(mut buttons: Query<(&mut TextColor, &YearChange)>, year: Res<Year>) {
+    for (mut text_color, year_change) in buttons.iter_mut() {
+        text_color.0 = if year_change.0 == year.0 {
+            SELECTED_COLOR
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+fn update_title_card(mut title_card: Single<&mut Text, With<TitleCard>>, year: Res<Year>) {
+    title_card.0 = format!("AoC {}", year.0);
+}
+
+fn rebuild_calendar(
+    mut commands: Commands,
+    calendar: Single<Entity, With<Calendar>>,
+    asset_server: Res<AssetServer>,
+    thumbnails: Res<Thumbnails>,
+    year: Res<Year>,
+) {
+    let symbols = asset_server.load("NotoSansSymbols2-Regular.ttf");
+    commands
+        .entity(*calendar)
+        .despawn_descendants()
+        .with_children(|parent| build_ui_day_tiles(parent, year.0, symbols, &thumbnails));
+}
+
+fn update_theme_text_color(
+    mut buttons: Query<(&mut TextColor, &ThemeChange)>,
+    settings: Res<Settings>,
+) {
+    for (mut text_color, theme_change) in buttons.iter_mut() {
+        text_color.0 = if theme_change.0 == settings.theme {
+            SELECTED_COLOR
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+fn build_ui_header(parent: &mut ChildBuilder, year: u16, symbols: Handle<Font>) {
+    parent
+        .spawn((
+            Name::new("main_menu_header"),
+            Node {
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::End,
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    build_ui_years(parent, year);
+                    build_ui_input_sources(parent);
+                    build_ui_themes(parent);
+                });
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::End,
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    build_ui_title_card(parent, year);
+                    build_ui_star_count(parent, symbols);
+                });
+        });
+}
+
+fn build_ui_input_sources(parent: &mut ChildBuilder) {
+    parent
+        .spawn((
+            Name::new("main_menu_input_sources"),
+            Node {
+                column_gap: Val::Px(10.),
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(Text::new("Input:"));
+            for source in InputSource::ALL {
+                parent.spawn((
+                    Name::new(format!("main_menu_input_{}", source.name())),
+                    Text::new(source.name()),
+                    InputSourceChange(source),
+                ));
+            }
+        });
+}
+
+fn build_ui_years(parent: &mut ChildBuilder, year: u16) {
+    parent
+        .spawn((
+            Name::new("main_menu_years"),
+            Node {
+                column_gap: Val::Px(10.),
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(Text::new("Year:"));
+            for (event, _) in EVENTS {
+                parent.spawn((
+                    Name::new(format!("main_menu_year_{event}")),
+                    Text::new(event.to_string()),
+                    TextColor(if event == year {
+                        SELECTED_COLOR
+                    } else {
+                        Color::WHITE
+                    }),
+                    YearChange(event),
+                ));
+            }
+        });
+}
+
+fn build_ui_themes(parent: &mut ChildBuilder) {
+    parent
+        .spawn((
+            Name::new("main_menu_themes"),
+            Node {
+                column_gap: Val::Px(10.),
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(Text::new("Theme:"));
+            for theme in Theme::ALL {
+                parent.spawn((
+                    Name::new(format!("main_menu_theme_{}", theme.name())),
+                    Text::new(theme.name()),
+                    ThemeChange(theme),
+                ));
+            }
+        });
+}
+
+fn build_ui_title_card(parent: &mut ChildBuilder, year: u16) {
+    parent.spawn((
+        Name::new("main_menu_title_card"),
+        Text::new(format!("AoC {year}")),
+        TitleCard,
+        TextFont {
+            font_size: 64.,
+            ..Default::default()
+        },
+    ));
+}
+
+fn build_ui_star_count(parent: &mut ChildBuilder, symbols: Handle<Font>) {
     parent
         .spawn((
-            Name::new("main_menu_left"),
+            Name::new("main_menu_stars"),
             Node {
-                width: Val::Percent(50.),
-                left: Val::Px(0.),
+                column_gap: Val::Px(6.),
+                align_items: AlignItems::Center,
                 ..Default::default()
             },
         ))
-        .with_children(build_ui_options);
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("✭"),
+                TextFont {
+                    font: symbols,
+                    ..Default::default()
+                },
+                TextColor(STAR_COLOR),
+            ));
+            parent.spawn((Text::new("0/50"), StarCount));
+        });
+}
+
+/// Advent calendar of the days, a tile per day in rows of [`ROW_LENGTH`]
+fn build_ui_calendar(
+    parent: &mut ChildBuilder,
+    year: u16,
+    symbols: Handle<Font>,
+    thumbnails: &Thumbnails,
+) {
     parent
         .spawn((
-            Name::new("main_menu_right"),
+            Name::new("main_menu_calendar"),
             Node {
-                width: Val::Percent(50.),
-                right: Val::Px(0.),
-                justify_content: JustifyContent::End,
+                display: Display::Grid,
+                flex_grow: 1.,
+                grid_template_columns: RepeatedGridTrack::flex(ROW_LENGTH.into(), 1.),
+                grid_template_rows: RepeatedGridTrack::flex((25 / ROW_LENGTH).into(), 1.),
+                row_gap: Val::Px(8.),
+                column_gap: Val::Px(8.),
                 ..Default::default()
             },
+            Calendar,
         ))
-        .with_children(build_ui_title_card);
+        .with_children(|parent| build_ui_day_tiles(parent, year, symbols, thumbnails));
+}
+
+fn build_ui_day_tiles(
+    parent: &mut ChildBuilder,
+    year: u16,
+    symbols: Handle<Font>,
+    thumbnails: &Thumbnails,
+) {
+    for day in 1..=25 {
+        build_ui_day_tile(
+            parent,
+            (year, day),
+            thumbnails.0.get(&(year, day)).cloned(),
+            symbols.clone(),
+        );
+    }
 }
 
-fn build_ui_options(parent: &mut ChildBuilder) {
+fn build_ui_day_tile(
+    parent: &mut ChildBuilder,
+    (year, day): (u16, u8),
+    thumbnail: Option<Handle<Image>>,
+    symbols: Handle<Font>,
+) {
     parent
         .spawn((
-            Name::new("main_menu_options"),
+            Name::new(format!("main_menu_day_{day}")),
             Node {
-                top: Val::Px(100.),
-                left: Val::Px(25.),
-                flex_direction: FlexDirection::Row,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(6.)),
+                row_gap: Val::Px(4.),
+                border: UiRect::all(Val::Px(2.)),
+                overflow: Overflow::clip(),
                 ..Default::default()
             },
+            BorderColor(TILE_BORDER_COLOR),
+            BorderRadius::all(Val::Px(6.)),
+            BackgroundColor(TILE_BACKGROUND_COLOR),
+            Button,
+            SceneChange(Scene::Day { year, day }),
         ))
         .with_children(|parent| {
             parent
-                .spawn((
-                    Name::new("main_menu_day_1_through_10"),
-                    Node {
-                        min_width: Val::Px(100.),
-                        min_height: Val::Px(50.),
-                        flex_direction: FlexDirection::Column,
-                        ..Default::default()
-                    },
-                ))
+                .spawn(Node {
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                })
                 .with_children(|parent| {
-                    for i in 1..=10 {
-                        parent.spawn((
-                            Name::new(format!("main_menu_day_{i}")),
-                            Text::new(format!("Day {i}")),
-                            Node {
-                                padding: UiRect::all(Val::Px(3.)),
-                                ..Default::default()
-                            },
-                            Button,
-                            SceneChange(Scene::Day(i)),
-                        ));
-                    }
+                    parent.spawn((
+                        Text::new(format!("Day {day}")),
+                        TextFont {
+                            font_size: 18.,
+                            ..Default::default()
+                        },
+                        TileTitle(day),
+                    ));
+                    parent.spawn((
+                        Text::default(),
+                        TextFont {
+                            font_size: 12.,
+                            ..Default::default()
+                        },
+                        TileInput(day),
+                    ));
                 });
             parent
-                .spawn((
-                    Name::new("main_menu_day_11_through_20"),
-                    Node {
-                        min_width: Val::Px(100.),
-                        min_height: Val::Px(50.),
-                        flex_direction: FlexDirection::Column,
-                        ..Default::default()
-                    },
-                ))
+                .spawn(Node {
+                    column_gap: Val::Px(6.),
+                    ..Default::default()
+                })
                 .with_children(|parent| {
-                    for i in 11..=20 {
-                        parent.spawn((
-                            Name::new(format!("main_menu_day_{i}")),
-                            Text::new(format!("Day {i}")),
-                            Node {
-                                padding: UiRect::all(Val::Px(3.)),
-                                ..Default::default()
-                            },
-                            Button,
-                            SceneChange(Scene::Day(i)),
-                        ));
-                    }
-                });
-            parent
-                .spawn((
-                    Name::new("main_menu_day_21_through_25"),
-                    Node {
-                        min_width: Val::Px(100.),
-                        min_height: Val::Px(50.),
-                        flex_direction: FlexDirection::Column,
+                    let thumbnail_node = Node {
+                        width: Val::Px(96.),
+                        height: Val::Px(54.),
+                        flex_shrink: 0.,
                         ..Default::default()
-                    },
-                ))
-                .with_children(|parent| {
-                    for i in 21..=25 {
-                        parent.spawn((
-                            Name::new(format!("main_menu_day_{i}")),
-                            Text::new(format!("Day {i}")),
-                            Node {
-                                padding: UiRect::all(Val::Px(3.)),
-                                ..Default::default()
-                            },
-                            Button,
-                            SceneChange(Scene::Day(i)),
-                        ));
+                    };
+                    // Thumbnails are screenshots of the open day, the others are placeholders
+                    match thumbnail {
+                        Some(image) => {
+                            parent.spawn((thumbnail_node, ImageNode::new(image)));
+                        }
+                        None => {
+                            parent
+                                .spawn((
+                                    Node {
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..thumbnail_node
+                                    },
+                                    BackgroundColor(THUMBNAIL_PLACEHOLDER_COLOR),
+                                ))
+                                .with_child((
+                                    Text::new(THUMBNAIL_PLACEHOLDER),
+                                    TextFont {
+                                        font_size: 11.,
+                                        ..Default::default()
+                                    },
+                                    TextColor(DISABLED_COLOR),
+                                ));
+                        }
                     }
+                    parent
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            justify_content: JustifyContent::Center,
+                            row_gap: Val::Px(4.),
+                            min_width: Val::ZERO,
+                            overflow: Overflow::clip(),
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            for part in 1..=2 {
+                                build_ui_tile_answer(parent, day, part, symbols.clone());
+                            }
+                        });
                 });
         });
 }
 
-fn build_ui_title_card(parent: &mut ChildBuilder) {
-    parent.spawn((
-        Name::new("main_menu_title_card"),
-        Text::new("AoC 2024"),
-        TextFont {
-            font_size: 128.,
-            ..Default::default()
-        },
-        Node {
-            top: Val::Px(20.),
-            right: Val::Px(0.),
+fn build_ui_tile_answer(parent: &mut ChildBuilder, day: u8, part: usize, symbols: Handle<Font>) {
+    parent
+        .spawn(Node {
+            column_gap: Val::Px(4.),
+            align_items: AlignItems::Center,
             ..Default::default()
-        },
-    ));
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("✭"),
+                TextFont {
+                    font: symbols,
+                    font_size: 14.,
+                    ..Default::default()
+                },
+                TextColor(DISABLED_COLOR),
+                Star { day, part },
+            ));
+            parent.spawn((
+                Text::new("..."),
+                TextFont {
+                    font_size: 14.,
+                    ..Default::default()
+                },
+                TextLayout::new_with_no_wrap(),
+                TextColor(DISABLED_COLOR),
+                TileAnswer { day, part },
+            ));
+        });
 }
@@ -1,4 +1,7 @@
+mod answers;
+mod capture;
 mod components;
+mod compute;
 mod day01;
 mod day02;
 mod day03;
@@ -25,28 +28,50 @@ mod day23;
 mod day24;
 mod day25;
 mod days;
+mod grid_image;
 mod main_menu;
+mod perf;
+mod playback;
 mod resources;
+mod settings;
+mod shortcuts;
 mod states;
+#[cfg(test)]
+mod tests;
+mod thumbnails;
 
 use bevy::{
-    app::{PluginGroup, Startup},
-    asset::AssetServer,
+    app::{PluginGroup, Startup, Update},
+    asset::{io::AssetReaderError, AssetEvent, AssetLoadError, AssetServer, LoadState},
+    color::Color,
     prelude::{
-        AppExtStates, Button, Changed, Commands, Entity, NextState, Or, Query, Res, ResMut, State,
-        With,
+        in_state, resource_exists, AppExtStates, BuildChildren, Button, Changed, ChildBuild,
+        Commands, DespawnRecursiveExt, Entity, EventReader, IntoSystemConfigs, NextState, OnEnter,
+        Or, Query, Res, ResMut, State, Text, With,
     },
-    ui::{BackgroundColor, Interaction},
+    text::{TextColor, TextFont},
+    ui::{AlignItems, BackgroundColor, FlexDirection, Interaction, JustifyContent, Node, Val},
 };
-use states::{Part, UiState};
+use states::{InputState, Part, UiState};
 
-use crate::scroll_controls::{
-    BUTTON_BACKGROUND_COLOR, BUTTON_HOVERED_BACKGROUND_COLOR, BUTTON_SELECTED_BACKGROUND_COLOR,
+use crate::{
+    loader::{CustomSizes, InputDirectory, InputSource, ParseError, PuzzleSizes, RawInput},
+    scroll_controls::{
+        BUTTON_BACKGROUND_COLOR, BUTTON_HOVERED_BACKGROUND_COLOR, BUTTON_SELECTED_BACKGROUND_COLOR,
+    },
+};
+
+pub use self::{
+    capture::CaptureDirectory,
+    compute::Progress,
+    settings::{Settings, SettingsFile},
 };
 
 use self::{
-    components::{PartChange, SceneChange},
-    resources::FontHandles,
+    components::{InputSourceChange, PartChange, SceneChange},
+    compute::build_progress_bar,
+    days::{build_content, build_header},
+    resources::{FontHandles, GenericDay, InputError, Year},
 };
 
 type ButtonWithChangedInteractionQuery<'a, 'b> = Query<
@@ -56,10 +81,55 @@ type ButtonWithChangedInteractionQuery<'a, 'b> = Query<
     (
         With<Button>,
         Changed<Interaction>,
-        Or<(With<PartChange>, With<SceneChange>)>,
+        Or<(With<PartChange>, With<SceneChange>, With<InputSourceChange>)>,
     ),
 >;
 
+type Solver = fn(&RawInput, PuzzleSizes, &Progress) -> Result<[String; 2], ParseError>;
+
+/// Events with visualizations, with the solver of each of their days
+pub const EVENTS: [(u16, [Solver; 25]); 1] = [(
+    2024,
+    [
+        day01::solve,
+        day02::solve,
+        day03::solve,
+        day04::solve,
+        day05::solve,
+        day06::solve,
+        day07::solve,
+        day08::solve,
+        day09::solve,
+        day10::solve,
+        day11::solve,
+        day12::solve,
+        day13::solve,
+        day14::solve,
+        day15::solve,
+        day16::solve,
+        day17::solve,
+        day18::solve,
+        day19::solve,
+        day20::solve,
+        day21::solve,
+        day22::solve,
+        day23::solve,
+        day24::solve,
+        day25::solve,
+    ],
+)];
+
+/// Event selected when nothing else is
+pub const LATEST_YEAR: u16 = EVENTS[EVENTS.len() - 1].0;
+
+/// Solver of `day` of the `year` event, `None` when the event has no visualizations
+pub fn solver(year: u16, day: u8) -> Option<Solver> {
+    EVENTS
+        .iter()
+        .find(|(event, _)| *event == year)
+        .map(|(_, solvers)| solvers[usize::from(day - 1)])
+}
+
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
@@ -71,7 +141,20 @@ impl bevy::app::Plugin for Plugin {
             .add_sub_state::<states::InputState>()
             .add_sub_state::<states::UiState>();
 
-        app.add_systems(Startup, load_font);
+        app.init_resource::<InputSource>()
+            .init_resource::<InputDirectory>()
+            .init_resource::<CustomSizes>()
+            .init_resource::<Year>();
+
+        app.add_systems(Startup, (load_font, settings::load_settings));
+        app.add_systems(OnEnter(InputState::NotLoaded), build_waiting_for_input)
+            .add_systems(OnEnter(InputState::Computing), build_computing)
+            .add_systems(OnEnter(InputState::Failed), build_input_error)
+            .add_systems(
+                Update,
+                check_input_load_state.run_if(in_state(InputState::NotLoaded)),
+            )
+            .add_systems(Update, reload_input.run_if(resource_exists::<GenericDay>));
     }
 }
 
@@ -83,13 +166,167 @@ fn load_font(mut commands: Commands, asset_server: Res<AssetServer>) {
     });
 }
 
+fn build_waiting_for_input(
+    mut commands: Commands,
+    day_resource: Res<GenericDay>,
+    fonts: Res<FontHandles>,
+) {
+    let path = day_resource
+        .input
+        .path()
+        .map(|path| format!("assets/{path}"))
+        .unwrap_or_default();
+    build_input_status(
+        &mut commands,
+        &day_resource,
+        &fonts,
+        ("Waiting for input", Color::WHITE),
+        path,
+    );
+}
+
+fn build_computing(mut commands: Commands, day_resource: Res<GenericDay>, fonts: Res<FontHandles>) {
+    let path = day_resource
+        .input
+        .path()
+        .map(|path| format!("assets/{path}"))
+        .unwrap_or_default();
+    let status = build_input_status(
+        &mut commands,
+        &day_resource,
+        &fonts,
+        ("Solving the input", Color::WHITE),
+        path,
+    );
+    commands.entity(status).with_children(build_progress_bar);
+}
+
+fn check_input_load_state(
+    mut commands: Commands,
+    day_resource: Res<GenericDay>,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<InputState>>,
+) {
+    let Some(LoadState::Failed(error)) = asset_server.get_load_state(&day_resource.input) else {
+        return;
+    };
+
+    let path = day_resource
+        .input
+        .path()
+        .map(|path| format!("assets/{path}"))
+        .unwrap_or_default();
+    let message = match error.as_ref() {
+        AssetLoadError::AssetReaderError(AssetReaderError::NotFound(_)) => {
+            format!("input file not found: {path}")
+        }
+        error => format!("could not load {path}: {error}"),
+    };
+
+    commands.insert_resource(InputError::Load(message));
+    next_state.set(InputState::Failed);
+}
+
+fn reload_input(
+    day_resource: Res<GenericDay>,
+    mut asset_events: EventReader<AssetEvent<RawInput>>,
+    mut input_state: ResMut<NextState<InputState>>,
+    mut ui_state: ResMut<NextState<UiState>>,
+) {
+    if asset_events
+        .read()
+        .any(|event| event.is_modified(&day_resource.input))
+    {
+        bevy::log::info!("Input modified, rebuilding scene");
+        input_state.set(InputState::NotLoaded);
+        ui_state.set(UiState::NotLoaded);
+    }
+}
+
+fn build_input_error(
+    mut commands: Commands,
+    day_resource: Res<GenericDay>,
+    error: Res<InputError>,
+    fonts: Res<FontHandles>,
+) {
+    let (title, message) = match error.as_ref() {
+        InputError::Load(message) => ("Could not load the input", message.clone()),
+        InputError::Parse(error) => ("Could not parse the input", error.to_string()),
+    };
+    build_input_status(
+        &mut commands,
+        &day_resource,
+        &fonts,
+        (title, Color::srgb(1., 0.4, 0.4)),
+        message,
+    );
+}
+
+fn build_input_status(
+    commands: &mut Commands,
+    day_resource: &GenericDay,
+    fonts: &FontHandles,
+    (title, title_color): (&str, Color),
+    message: String,
+) -> Entity {
+    let header = build_header(commands, "input_status", false, false, fonts.font.clone());
+    let content = build_content(commands, "input_status");
+
+    let mut status = Entity::PLACEHOLDER;
+    commands.entity(content).with_children(|parent| {
+        status = parent
+            .spawn(Node {
+                width: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.),
+                ..Default::default()
+            })
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(title),
+                    TextFont {
+                        font: fonts.font.clone(),
+                        font_size: 24.,
+                        ..Default::default()
+                    },
+                    TextColor(title_color),
+                ));
+                parent.spawn((
+                    Text::new(message),
+                    TextFont {
+                        font: fonts.font.clone(),
+                        ..Default::default()
+                    },
+                ));
+            })
+            .id();
+    });
+
+    commands
+        .entity(day_resource.ui)
+        .despawn_descendants()
+        .add_children(&[header, content]);
+
+    status
+}
+
+#[allow(clippy::too_many_arguments)]
 fn state_button_interactions(
     mut buttons: ButtonWithChangedInteractionQuery,
     state_changes: Query<&SceneChange>,
     part_changes: Query<&PartChange>,
+    input_source_changes: Query<&InputSourceChange>,
     current_part: Res<State<Part>>,
+    current_scene: Res<State<states::Scene>>,
+    mut input_source: ResMut<InputSource>,
+    input_directory: Res<InputDirectory>,
+    mut day_resource: ResMut<GenericDay>,
+    asset_server: Res<AssetServer>,
     mut next_state: ResMut<NextState<states::Scene>>,
     mut part_next_state: ResMut<NextState<Part>>,
+    mut input_next_state: ResMut<NextState<InputState>>,
     mut ui_state: ResMut<NextState<UiState>>,
 ) {
     for (button, mut background_color, interaction) in buttons.iter_mut() {
@@ -101,6 +338,11 @@ fn state_button_interactions(
                         color = BUTTON_SELECTED_BACKGROUND_COLOR;
                     }
                 }
+                if let Ok(source_change) = input_source_changes.get(button) {
+                    if source_change.0 == *input_source {
+                        color = BUTTON_SELECTED_BACKGROUND_COLOR;
+                    }
+                }
                 background_color.0 = color;
             }
             Interaction::Hovered => background_color.0 = BUTTON_HOVERED_BACKGROUND_COLOR,
@@ -110,6 +352,14 @@ fn state_button_interactions(
                     next_state.set(state_change.0);
                 } else if let Ok(part_change) = part_changes.get(button) {
                     part_next_state.set(Part::from(*part_change));
+                } else if let Ok(source_change) = input_source_changes.get(button) {
+                    let states::Scene::Day { year, day } = *current_scene.get() else {
+                        continue;
+                    };
+                    *input_source = source_change.0;
+                    day_resource.input =
+                        asset_server.load(input_source.path(&input_directory, year, day));
+                    input_next_state.set(InputState::NotLoaded);
                 }
             }
         }
@@ -121,7 +371,16 @@ pub struct ScenesPluginGroup;
 impl PluginGroup for ScenesPluginGroup {
     fn build(self) -> bevy::app::PluginGroupBuilder {
         bevy::app::PluginGroupBuilder::start::<Self>()
+            .add(answers::Plugin)
             .add(main_menu::Plugin)
+            .add(compute::Plugin)
+            .add(playback::Plugin)
+            .add(grid_image::Plugin)
+            .add(perf::Plugin)
+            .add(shortcuts::Plugin)
+            .add(capture::Plugin)
+            .add(settings::Plugin)
+            .add(thumbnails::Plugin)
             .add(day01::Plugin)
             .add(day02::Plugin)
             .add(day03::Plugin)
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use bevy::{
+    app::{Last, Startup, Update},
+    color::{Alpha, Color},
+    core::Name,
+    input::{common_conditions::input_just_pressed, keyboard::KeyCode},
+    prelude::{
+        BuildChildren, Children, Commands, Component, HierarchyQueryExt, IntoSystemConfigs,
+        OnEnter, Query, ReflectComponent, Res, Single, Text, Visibility, With,
+    },
+    reflect::Reflect,
+    text::{TextColor, TextFont},
+    time::{Real, Time},
+    ui::{BackgroundColor, GlobalZIndex, Node, PositionType, UiRect, Val},
+};
+
+use super::{compute::ComputeTimes, resources::GenericDay, states::InputState};
+
+/// Weight of the last frame in the smoothed frame time
+const FRAME_TIME_SMOOTHING: f32 = 0.1;
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.register_type::<PerfMetrics>();
+
+        app.add_systems(Startup, build_overlay)
+            .add_systems(OnEnter(InputState::NotLoaded), reset_metrics)
+            .add_systems(OnEnter(InputState::Loaded), record_compute_times)
+            .add_systems(
+                Update,
+                toggle_overlay.run_if(input_just_pressed(KeyCode::F3)),
+            )
+            .add_systems(Last, (record_metrics, update_overlay).chain());
+    }
+}
+
+/// Timings of the open day.
+///
+/// Parsing and solving both parts are timed on the background task computing the input, see
+/// [`ComputeTimes`]. The metrics live on their own entity so they can be queried through the
+/// remote protocol of the `with_inspector` feature.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct PerfMetrics {
+    pub parse: Option<Duration>,
+    pub solve: [Option<Duration>; 2],
+    /// Entities under the UI root of the day
+    pub ui_entities: usize,
+    /// Smoothed duration of a frame
+    pub frame_time: Duration,
+}
+
+#[derive(Debug, Component)]
+struct PerfOverlay;
+
+#[derive(Debug, Component)]
+struct PerfOverlayText;
+
+fn build_overlay(mut commands: Commands) {
+    commands.spawn((Name::new("perf_metrics"), PerfMetrics::default()));
+
+    commands
+        .spawn((
+            Name::new("perf_overlay"),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(5.),
+                right: Val::Px(5.),
+                padding: UiRect::all(Val::Px(5.)),
+                ..Default::default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.7)),
+            GlobalZIndex(i32::MAX),
+            Visibility::Hidden,
+            PerfOverlay,
+        ))
+        .with_child((
+            Text::default(),
+            TextFont {
+                font_size: 14.,
+                ..Default::default()
+            },
+            TextColor(Color::WHITE),
+            PerfOverlayText,
+        ));
+}
+
+fn toggle_overlay(mut overlay: Single<&mut Visibility, With<PerfOverlay>>) {
+    **overlay = match **overlay {
+        Visibility::Hidden => Visibility::Inherited,
+        _ => Visibility::Hidden,
+    };
+}
+
+fn record_compute_times(mut metrics: Single<&mut PerfMetrics>, times: Option<Res<ComputeTimes>>) {
+    metrics.parse = times.as_ref().map(|times| times.parse);
+    metrics.solve = times.map_or([None; 2], |times| times.solve.map(Some));
+}
+
+fn reset_metrics(mut metrics: Single<&mut PerfMetrics>) {
+    metrics.parse = None;
+    metrics.solve = [None; 2];
+}
+
+fn record_metrics(
+    mut metrics: Single<&mut PerfMetrics>,
+    time: Res<Time<Real>>,
+    day: Option<Res<GenericDay>>,
+    children: Query<&Children>,
+) {
+    metrics.ui_entities = day.map_or(0, |day| children.iter_descendants(day.ui).count());
+    metrics.frame_time = metrics
+        .frame_time
+        .mul_f32(1. - FRAME_TIME_SMOOTHING)
+        .saturating_add(time.delta().mul_f32(FRAME_TIME_SMOOTHING));
+}
+
+fn update_overlay(
+    overlay: Single<&Visibility, With<PerfOverlay>>,
+    mut text: Single<&mut Text, With<PerfOverlayText>>,
+    metrics: Single<&PerfMetrics>,
+) {
+    if **overlay == Visibility::Hidden {
+        return;
+    }
+
+    let format = |duration: Option<Duration>| {
+        duration.map_or_else(
+            || String::from("-"),
+            |duration| format!("{:.1} ms", duration.as_secs_f64() * 1000.),
+        )
+    };
+    text.0 = format!(
+        "Parse: {}\nPart 1: {}\nPart 2: {}\nUI entities: {}\nFrame: {}",
+        format(metrics.parse),
+        format(metrics.solve[0]),
+        format(metrics.solve[1]),
+        metrics.ui_entities,
+        format(Some(metrics.frame_time)),
+    );
+}
@@ -0,0 +1,705 @@
+use std::time::Duration;
+
+use bevy::{
+    app::Update,
+    asset::Handle,
+    color::Color,
+    prelude::{
+        in_state, resource_changed, state_changed, Added, AppExtStates, BuildChildren, Button,
+        Changed, ChildBuild, ChildBuilder, Component, Condition, DetectChanges, IntoSystemConfigs,
+        Local, Mut, NextState, OnEnter, Query, Res, ResMut, Resource, Single, State, Text, With,
+    },
+    text::{Font, TextColor, TextFont},
+    time::Time,
+    ui::{
+        AlignItems, BackgroundColor, Interaction, Node, PositionType, RelativeCursorPosition,
+        UiRect, Val,
+    },
+    utils::{HashMap, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::scroll_controls::{
+    BUTTON_BACKGROUND_COLOR, BUTTON_HOVERED_BACKGROUND_COLOR, BUTTON_SELECTED_BACKGROUND_COLOR,
+};
+
+use super::{
+    days::button_node,
+    resources::FontHandles,
+    states::{PlaybackState, Scene, UiState},
+};
+
+const MIN_SPEED: f32 = 0.1;
+const MAX_SPEED: f32 = 100.;
+/// Fraction of the slider, on its right end, that selects [`Speed::Unlimited`]
+const UNLIMITED_SLIDER_SECTION: f32 = 0.1;
+/// Time a frame can spend stepping a simulation before yielding to rendering
+const FRAME_BUDGET: Duration = Duration::from_millis(12);
+const SLIDER_WIDTH: f32 = 150.;
+const SLIDER_THUMB_WIDTH: f32 = 8.;
+const TIMELINE_WIDTH: f32 = 300.;
+
+/// A simulation that the playback controls can advance one step at a time.
+pub trait Simulation: Send + Sync + 'static {
+    /// Whether [`Simulation::step_back`] can undo a step.
+    const REVERSIBLE: bool = false;
+
+    /// Steps taken per second at 1x speed.
+    fn steps_per_second(&self) -> f32 {
+        10.
+    }
+
+    /// Advances the simulation, returns `false` if it had already finished.
+    fn step(&mut self) -> bool;
+
+    /// Undoes the last step, returns `false` if it was already at the start.
+    fn step_back(&mut self) -> bool {
+        false
+    }
+
+    fn reset(&mut self);
+}
+
+/// A [`Simulation`] that can jump to any step of its timeline.
+pub trait Scrubbable: Simulation {
+    type Snapshot: Send + Sync + 'static;
+
+    /// Steps between the snapshots taken while the simulation runs.
+    const SNAPSHOT_INTERVAL: usize = 256;
+
+    /// Steps taken since the start.
+    fn position(&self) -> usize;
+
+    /// Steps until the simulation finishes, or an upper bound if it is not known ahead.
+    fn length(&self) -> usize;
+
+    fn is_finished(&self) -> bool {
+        self.position() >= self.length()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    fn restore(&mut self, snapshot: &Self::Snapshot);
+
+    /// Jumps straight to `position`, returns `false` if the simulation can only get
+    /// there by stepping.
+    fn seek(&mut self, _position: usize) -> bool {
+        false
+    }
+}
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_sub_state::<PlaybackState>()
+            .init_resource::<PlaybackSpeeds>();
+
+        app.add_systems(OnEnter(UiState::NotLoaded), pause)
+            .add_systems(
+                Update,
+                (
+                    controls_interaction,
+                    speed_slider_interaction,
+                    update_play_pause_color.run_if(state_changed::<PlaybackState>),
+                    update_speed_slider
+                        .run_if(resource_changed::<PlaybackSpeeds>.or(speed_slider_added)),
+                )
+                    .run_if(in_state(UiState::Loaded)),
+            );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Speed {
+    /// Multiplier over [`Simulation::steps_per_second`]
+    Multiplier(f32),
+    /// Steps for as long as the frame budget allows
+    Unlimited,
+}
+
+impl Default for Speed {
+    fn default() -> Self {
+        Self::Multiplier(1.)
+    }
+}
+
+impl Speed {
+    /// Maps a position along the slider, from 0 to 1, to a speed.
+    /// The multipliers are spread logarithmically.
+    fn from_slider(position: f32) -> Self {
+        let position = position.clamp(0., 1.);
+        if position > 1. - UNLIMITED_SLIDER_SECTION {
+            Self::Unlimited
+        } else {
+            let fraction = position / (1. - UNLIMITED_SLIDER_SECTION);
+            let multiplier = MIN_SPEED * (MAX_SPEED / MIN_SPEED).powf(fraction);
+            // Round to 2 significant digits so the label stays readable
+            let scale = 10f32.powf(1. - multiplier.log10().floor());
+            Self::Multiplier((multiplier * scale).round() / scale)
+        }
+    }
+
+    fn slider_position(self) -> f32 {
+        match self {
+            Self::Multiplier(multiplier) => {
+                (multiplier / MIN_SPEED).ln() / (MAX_SPEED / MIN_SPEED).ln()
+                    * (1. - UNLIMITED_SLIDER_SECTION)
+            }
+            Self::Unlimited => 1.,
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Self::Multiplier(multiplier) => format!("{multiplier}x"),
+            Self::Unlimited => String::from("Max"),
+        }
+    }
+}
+
+/// Playback speed of each scene
+#[derive(Debug, Default, Resource)]
+pub struct PlaybackSpeeds(pub HashMap<Scene, Speed>);
+
+impl PlaybackSpeeds {
+    pub fn get(&self, scene: Scene) -> Speed {
+        self.0.get(&scene).copied().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[require(Button)]
+pub enum PlaybackControl {
+    Reset,
+    StepBack,
+    Play,
+    Pause,
+    Step,
+}
+
+#[derive(Debug, Component)]
+#[require(Button, RelativeCursorPosition)]
+struct SpeedSlider;
+
+#[derive(Debug, Component)]
+struct SpeedSliderThumb;
+
+#[derive(Debug, Component)]
+struct SpeedLabel;
+
+#[derive(Component)]
+#[require(Button, RelativeCursorPosition)]
+struct Timeline<T: Scrubbable> {
+    /// Snapshots ordered by the position they were taken at
+    snapshots: Vec<(usize, T::Snapshot)>,
+    /// Position being seeked, when it can not be reached in a single frame
+    target: Option<usize>,
+    /// Length of the simulation, once it was seen finishing
+    end: Option<usize>,
+}
+
+impl<T: Scrubbable> Default for Timeline<T> {
+    fn default() -> Self {
+        Self {
+            snapshots: Vec::new(),
+            target: None,
+            end: None,
+        }
+    }
+}
+
+#[derive(Debug, Component)]
+struct TimelineThumb;
+
+#[derive(Debug, Component)]
+struct TimelineLabel;
+
+type PlaybackControlWithChangedInteractionQuery<'a, 'b> = Query<
+    'a,
+    'b,
+    (
+        &'static mut BackgroundColor,
+        &'static Interaction,
+        &'static PlaybackControl,
+    ),
+    (With<Button>, Changed<Interaction>),
+>;
+
+type PlaybackControlPressedQuery<'a, 'b> = Query<
+    'a,
+    'b,
+    (&'static Interaction, &'static PlaybackControl),
+    (With<Button>, Changed<Interaction>),
+>;
+
+/// Systems that drive a [`Simulation`] stored as a resource.
+pub fn resource_systems<T: Simulation + Resource>() -> bevy::ecs::schedule::SystemConfigs {
+    (
+        control_resource::<T>,
+        advance_resource::<T>.run_if(in_state(PlaybackState::Playing)),
+    )
+        .into_configs()
+}
+
+/// Systems that drive a [`Simulation`] stored in the component of a single entity.
+pub fn component_systems<T: Simulation + Component>() -> bevy::ecs::schedule::SystemConfigs {
+    (
+        control_component::<T>,
+        advance_component::<T>.run_if(in_state(PlaybackState::Playing)),
+    )
+        .into_configs()
+}
+
+/// [`resource_systems`] plus the timeline of a [`Scrubbable`] simulation.
+pub fn resource_timeline_systems<T: Scrubbable + Resource>() -> bevy::ecs::schedule::SystemConfigs {
+    (resource_systems::<T>(), timeline_resource::<T>).chain()
+}
+
+/// [`component_systems`] plus the timeline of a [`Scrubbable`] simulation.
+pub fn component_timeline_systems<T: Scrubbable + Component>() -> bevy::ecs::schedule::SystemConfigs
+{
+    (component_systems::<T>(), timeline_component::<T>).chain()
+}
+
+fn pause(mut next_state: ResMut<NextState<PlaybackState>>) {
+    next_state.set(PlaybackState::Paused);
+}
+
+fn controls_interaction(
+    mut controls: PlaybackControlWithChangedInteractionQuery,
+    state: Res<State<PlaybackState>>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    for (mut background_color, interaction, control) in controls.iter_mut() {
+        match interaction {
+            Interaction::None => background_color.0 = control_color(*control, *state.get()),
+            Interaction::Hovered => background_color.0 = BUTTON_HOVERED_BACKGROUND_COLOR,
+            Interaction::Pressed => match control {
+                PlaybackControl::Play => next_state.set(PlaybackState::Playing),
+                PlaybackControl::Pause => next_state.set(PlaybackState::Paused),
+                PlaybackControl::Reset | PlaybackControl::StepBack | PlaybackControl::Step => (),
+            },
+        }
+    }
+}
+
+fn update_play_pause_color(
+    mut controls: Query<(&mut BackgroundColor, &Interaction, &PlaybackControl)>,
+    state: Res<State<PlaybackState>>,
+) {
+    for (mut background_color, interaction, control) in controls.iter_mut() {
+        if *interaction == Interaction::None {
+            background_color.0 = control_color(*control, *state.get());
+        }
+    }
+}
+
+fn control_color(control: PlaybackControl, state: PlaybackState) -> Color {
+    match (control, state) {
+        (PlaybackControl::Play, PlaybackState::Playing)
+        | (PlaybackControl::Pause, PlaybackState::Paused) => BUTTON_SELECTED_BACKGROUND_COLOR,
+        _ => BUTTON_BACKGROUND_COLOR,
+    }
+}
+
+/// Keeps updating the speed while the slider is held, so it can be dragged
+fn speed_slider_interaction(
+    sliders: Query<(&Interaction, &RelativeCursorPosition), With<SpeedSlider>>,
+    scene: Res<State<Scene>>,
+    mut speeds: ResMut<PlaybackSpeeds>,
+) {
+    for (interaction, cursor) in sliders.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(position) = cursor.normalized else {
+            continue;
+        };
+
+        let speed = Speed::from_slider(position.x);
+        if speeds.get(*scene.get()) != speed {
+            speeds.0.insert(*scene.get(), speed);
+        }
+    }
+}
+
+fn speed_slider_added(sliders: Query<(), Added<SpeedSlider>>) -> bool {
+    !sliders.is_empty()
+}
+
+fn update_speed_slider(
+    mut thumbs: Query<&mut Node, With<SpeedSliderThumb>>,
+    mut labels: Query<&mut Text, With<SpeedLabel>>,
+    scene: Res<State<Scene>>,
+    speeds: Res<PlaybackSpeeds>,
+) {
+    let speed = speeds.get(*scene.get());
+    for mut thumb in thumbs.iter_mut() {
+        thumb.left = Val::Px(speed.slider_position() * (SLIDER_WIDTH - SLIDER_THUMB_WIDTH));
+    }
+    for mut label in labels.iter_mut() {
+        label.0 = speed.label();
+    }
+}
+
+fn control_resource<T: Simulation + Resource>(
+    controls: PlaybackControlPressedQuery,
+    mut simulation: ResMut<T>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    for (interaction, control) in controls.iter() {
+        if *interaction == Interaction::Pressed {
+            apply_control(*control, simulation.reborrow(), &mut next_state);
+        }
+    }
+}
+
+fn control_component<T: Simulation + Component>(
+    controls: PlaybackControlPressedQuery,
+    mut simulation: Single<&mut T>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    for (interaction, control) in controls.iter() {
+        if *interaction == Interaction::Pressed {
+            apply_control(*control, simulation.reborrow(), &mut next_state);
+        }
+    }
+}
+
+fn apply_control<T: Simulation>(
+    control: PlaybackControl,
+    mut simulation: Mut<T>,
+    next_state: &mut NextState<PlaybackState>,
+) {
+    match control {
+        PlaybackControl::Reset => simulation.reset(),
+        PlaybackControl::StepBack if T::REVERSIBLE => {
+            simulation.step_back();
+        }
+        PlaybackControl::Step => {
+            simulation.step();
+        }
+        _ => return,
+    }
+    next_state.set(PlaybackState::Paused);
+}
+
+fn advance_resource<T: Simulation + Resource>(
+    mut simulation: ResMut<T>,
+    scene: Res<State<Scene>>,
+    speeds: Res<PlaybackSpeeds>,
+    time: Res<Time>,
+    mut pending: Local<f32>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    let steps = due_steps(
+        &time,
+        speeds.get(*scene.get()),
+        simulation.steps_per_second(),
+        &mut pending,
+    );
+    advance(simulation.reborrow(), steps, &mut pending, &mut next_state);
+}
+
+fn advance_component<T: Simulation + Component>(
+    mut simulation: Single<&mut T>,
+    scene: Res<State<Scene>>,
+    speeds: Res<PlaybackSpeeds>,
+    time: Res<Time>,
+    mut pending: Local<f32>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    let steps = due_steps(
+        &time,
+        speeds.get(*scene.get()),
+        simulation.steps_per_second(),
+        &mut pending,
+    );
+    advance(simulation.reborrow(), steps, &mut pending, &mut next_state);
+}
+
+/// Steps that should be taken this frame, `None` if there is no limit
+fn due_steps(time: &Time, speed: Speed, steps_per_second: f32, pending: &mut f32) -> Option<usize> {
+    let Speed::Multiplier(multiplier) = speed else {
+        return None;
+    };
+    *pending += time.delta_secs() * multiplier * steps_per_second;
+    let steps = pending.floor();
+    *pending -= steps;
+    Some(steps as usize)
+}
+
+fn advance<T: Simulation>(
+    mut simulation: Mut<T>,
+    steps: Option<usize>,
+    pending: &mut f32,
+    next_state: &mut NextState<PlaybackState>,
+) {
+    // Avoid flagging the simulation as changed on frames without steps
+    if steps == Some(0) {
+        return;
+    }
+    let start = Instant::now();
+    let mut taken = 0;
+    while steps.is_none_or(|steps| taken < steps) {
+        if !simulation.step() {
+            next_state.set(PlaybackState::Paused);
+            break;
+        }
+        taken += 1;
+        if start.elapsed() > FRAME_BUDGET {
+            // Drop what did not fit instead of piling it up on the next frames
+            *pending = 0.;
+            break;
+        }
+    }
+}
+
+type TimelineQuery<'a, T> = (
+    &'a mut Timeline<T>,
+    &'a Interaction,
+    &'a RelativeCursorPosition,
+);
+
+fn timeline_resource<T: Scrubbable + Resource>(
+    mut simulation: ResMut<T>,
+    timeline: Single<TimelineQuery<T>>,
+    thumb: Single<&mut Node, With<TimelineThumb>>,
+    label: Single<&mut Text, With<TimelineLabel>>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    let (timeline, interaction, cursor) = timeline.into_inner();
+    drive_timeline(
+        simulation.reborrow(),
+        timeline,
+        (interaction, cursor),
+        (thumb.into_inner(), label.into_inner()),
+        &mut next_state,
+    );
+}
+
+fn timeline_component<T: Scrubbable + Component>(
+    mut simulation: Single<&mut T>,
+    timeline: Single<TimelineQuery<T>>,
+    thumb: Single<&mut Node, With<TimelineThumb>>,
+    label: Single<&mut Text, With<TimelineLabel>>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    let (timeline, interaction, cursor) = timeline.into_inner();
+    drive_timeline(
+        simulation.reborrow(),
+        timeline,
+        (interaction, cursor),
+        (thumb.into_inner(), label.into_inner()),
+        &mut next_state,
+    );
+}
+
+fn drive_timeline<T: Scrubbable>(
+    mut simulation: Mut<T>,
+    mut timeline: Mut<Timeline<T>>,
+    (interaction, cursor): (&Interaction, &RelativeCursorPosition),
+    (mut thumb, mut label): (Mut<Node>, Mut<Text>),
+    next_state: &mut NextState<PlaybackState>,
+) {
+    let length = timeline.end.unwrap_or_else(|| simulation.length()).max(1);
+
+    if *interaction == Interaction::Pressed {
+        if let Some(cursor) = cursor.normalized {
+            let target = (cursor.x.clamp(0., 1.) * length as f32).round() as usize;
+            if target != timeline.target.unwrap_or_else(|| simulation.position()) {
+                timeline.target = Some(target);
+                next_state.set(PlaybackState::Paused);
+            }
+        }
+    }
+
+    if let Some(target) = timeline.target {
+        seek(simulation.reborrow(), &mut timeline, target);
+    }
+
+    if simulation.is_changed() || timeline.is_added() {
+        record(&*simulation, &mut timeline);
+        if simulation.is_finished() {
+            timeline.end = Some(simulation.position());
+        }
+
+        let length = timeline.end.unwrap_or_else(|| simulation.length()).max(1);
+        let position = simulation.position();
+        thumb.left = Val::Px(
+            (position as f32 / length as f32).min(1.) * (TIMELINE_WIDTH - SLIDER_THUMB_WIDTH),
+        );
+        label.0 = format!("{position} / {length}");
+    }
+}
+
+/// Moves towards `target` from the closest snapshot, for as long as the frame budget allows
+fn seek<T: Scrubbable>(mut simulation: Mut<T>, timeline: &mut Timeline<T>, target: usize) {
+    if simulation.position() == target || simulation.seek(target) {
+        timeline.target = None;
+        return;
+    }
+
+    let position = simulation.position();
+    match timeline
+        .snapshots
+        .iter()
+        .rev()
+        .find(|(taken_at, _)| *taken_at <= target)
+    {
+        Some((taken_at, snapshot)) if target < position || *taken_at > position => {
+            simulation.restore(snapshot);
+        }
+        None if target < position => simulation.reset(),
+        _ => (),
+    }
+
+    let start = Instant::now();
+    while simulation.position() < target {
+        if !simulation.step() {
+            timeline.end = Some(simulation.position());
+            break;
+        }
+        record(&*simulation, timeline);
+        if start.elapsed() > FRAME_BUDGET {
+            return;
+        }
+    }
+    timeline.target = None;
+}
+
+fn record<T: Scrubbable>(simulation: &T, timeline: &mut Timeline<T>) {
+    let position = simulation.position();
+    let next_snapshot = timeline
+        .snapshots
+        .last()
+        .map_or(0, |(taken_at, _)| taken_at + T::SNAPSHOT_INTERVAL);
+    if position >= next_snapshot {
+        timeline.snapshots.push((position, simulation.snapshot()));
+    }
+}
+
+pub fn build_playback_controls<T: Simulation>(parent: &mut ChildBuilder, fonts: &FontHandles) {
+    let mut controls = vec![PlaybackControl::Reset];
+    if T::REVERSIBLE {
+        controls.push(PlaybackControl::StepBack);
+    }
+    controls.extend([
+        PlaybackControl::Play,
+        PlaybackControl::Pause,
+        PlaybackControl::Step,
+    ]);
+
+    for control in controls {
+        let symbol = match control {
+            PlaybackControl::Reset => "⏮",
+            PlaybackControl::StepBack => "⏪",
+            PlaybackControl::Play => "⏵",
+            PlaybackControl::Pause => "⏸",
+            PlaybackControl::Step => "⏩",
+        };
+        parent
+            .spawn((
+                button_node(),
+                control,
+                BackgroundColor(BUTTON_BACKGROUND_COLOR),
+            ))
+            .with_child((
+                Text::new(symbol),
+                TextFont {
+                    font: fonts.symbol2.clone(),
+                    ..Default::default()
+                },
+                TextColor(Color::BLACK),
+            ));
+    }
+}
+
+pub fn build_speed_slider(parent: &mut ChildBuilder, font: Handle<Font>) {
+    parent
+        .spawn(Node {
+            margin: UiRect::left(Val::Px(20.)),
+            column_gap: Val::Px(5.),
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Speed"),
+                TextFont {
+                    font: font.clone(),
+                    ..Default::default()
+                },
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(SLIDER_WIDTH),
+                        height: Val::Px(30.),
+                        ..Default::default()
+                    },
+                    BackgroundColor(BUTTON_BACKGROUND_COLOR),
+                    SpeedSlider,
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Px(SLIDER_THUMB_WIDTH),
+                        height: Val::Percent(100.),
+                        position_type: PositionType::Absolute,
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::BLACK),
+                    SpeedSliderThumb,
+                ));
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font,
+                    ..Default::default()
+                },
+                SpeedLabel,
+            ));
+        });
+}
+
+pub fn build_timeline<T: Scrubbable>(parent: &mut ChildBuilder, font: Handle<Font>) {
+    parent
+        .spawn(Node {
+            margin: UiRect::left(Val::Px(20.)),
+            column_gap: Val::Px(5.),
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(TIMELINE_WIDTH),
+                        height: Val::Px(30.),
+                        ..Default::default()
+                    },
+                    BackgroundColor(BUTTON_BACKGROUND_COLOR),
+                    Timeline::<T>::default(),
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Px(SLIDER_THUMB_WIDTH),
+                        height: Val::Percent(100.),
+                        position_type: PositionType::Absolute,
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::BLACK),
+                    TimelineThumb,
+                ));
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font,
+                    ..Default::default()
+                },
+                TimelineLabel,
+            ));
+        });
+}
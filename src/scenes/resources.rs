@@ -4,7 +4,9 @@ use bevy::{
     text::Font,
 };
 
-use crate::loader::RawInput;
+use crate::loader::{ParseError, RawInput};
+
+use super::LATEST_YEAR;
 
 #[derive(Debug, Resource)]
 pub struct GenericDay {
@@ -13,9 +15,25 @@ pub struct GenericDay {
     pub ui: Entity,
 }
 
+/// Event whose days are shown in the main menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct Year(pub u16);
+
+impl Default for Year {
+    fn default() -> Self {
+        Self(LATEST_YEAR)
+    }
+}
+
 #[derive(Debug, Resource)]
 pub struct FontHandles {
     pub font: Handle<Font>,
     pub symbol1: Handle<Font>,
     pub symbol2: Handle<Font>,
 }
+
+#[derive(Debug, Resource)]
+pub enum InputError {
+    Load(String),
+    Parse(ParseError),
+}
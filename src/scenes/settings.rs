@@ -0,0 +1,322 @@
+use std::{
+    collections::BTreeMap,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy::{
+    app::{AppExit, Last, Update},
+    color::Color,
+    prelude::{
+        on_event, resource_changed, resource_exists, state_changed, ClearColor, Commands,
+        Condition, Entity, EventReader, IntoSystemConfigs, NextState, Res, ResMut, Resource,
+        Single, State, With,
+    },
+    time::{Time, Timer, TimerMode},
+    window::{PrimaryWindow, Window, WindowResized},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::loader::{CustomSizes, InputDirectory, InputSource, PuzzleSizes};
+
+use super::{
+    playback::{PlaybackSpeeds, Speed},
+    resources::Year,
+    solver,
+    states::{Part, Scene},
+};
+
+/// Time the [`Settings`] have to stay unchanged before they are saved, resizing the window
+/// changes them on every frame
+const SAVE_DELAY: Duration = Duration::from_secs(1);
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<Settings>()
+            .init_resource::<SettingsSave>()
+            .insert_resource(ClearColor(Theme::default().clear_color()));
+
+        app.add_systems(
+            Update,
+            (
+                track_scene.run_if(state_changed::<Scene>),
+                track_year.run_if(resource_changed::<Year>),
+                track_part.run_if(state_changed::<Part>),
+                track_input_source.run_if(resource_changed::<InputSource>),
+                track_speeds.run_if(resource_changed::<PlaybackSpeeds>),
+                track_window_size.run_if(on_event::<WindowResized>),
+                apply_theme.run_if(resource_changed::<Settings>),
+                delay_save.run_if(resource_changed::<Settings>),
+                save_settings.run_if(resource_exists::<SettingsFile>),
+            )
+                .chain(),
+        )
+        // The last frame runs `Last` after the window is closed, but not `Update`
+        .add_systems(
+            Last,
+            save_settings_on_exit.run_if(resource_exists::<SettingsFile>.and(on_event::<AppExit>)),
+        );
+    }
+}
+
+/// Where the [`Settings`] are kept between runs, nothing is read or saved without it
+#[derive(Debug, Resource)]
+pub struct SettingsFile(pub PathBuf);
+
+impl Default for SettingsFile {
+    fn default() -> Self {
+        Self(PathBuf::from("settings.ron"))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    /// Background of the Advent of Code website
+    #[default]
+    Night,
+    Black,
+}
+
+impl Theme {
+    pub const ALL: [Self; 2] = [Self::Night, Self::Black];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Night => "Night",
+            Self::Black => "Black",
+        }
+    }
+
+    fn clear_color(self) -> Color {
+        match self {
+            Self::Night => Color::srgb_u8(0x0f, 0x0f, 0x23),
+            Self::Black => Color::BLACK,
+        }
+    }
+}
+
+/// Preferences restored on launch, saved to the [`SettingsFile`] once they stop changing and on exit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct Settings {
+    /// Event selected in the main menu
+    pub year: u16,
+    /// Day of the selected event that was open, `None` for the main menu
+    pub day: Option<u8>,
+    /// Part of the day that was open
+    pub part: Part,
+    /// Playback speed of each day by year and day, the days left at the default speed are
+    /// missing
+    pub speeds: BTreeMap<(u16, u8), Speed>,
+    pub theme: Theme,
+    pub input_source: InputSource,
+    /// Folder of the real and custom inputs, only changed by editing the file
+    pub input_directory: InputDirectory,
+    /// Sizes of the puzzles read from the custom inputs, only changed by editing the file
+    pub custom_sizes: PuzzleSizes,
+    /// Logical size of the primary window, `None` until it is resized
+    pub window_size: Option<(f32, f32)>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            year: Year::default().0,
+            day: None,
+            part: Part::default(),
+            speeds: BTreeMap::new(),
+            theme: Theme::default(),
+            input_source: InputSource::default(),
+            input_directory: InputDirectory::default(),
+            custom_sizes: PuzzleSizes::default(),
+            window_size: None,
+        }
+    }
+}
+
+impl Settings {
+    fn to_text(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Settings saved to `path`, the defaults when it is missing or invalid
+    pub fn read(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(text) => ron::from_str(&text).unwrap_or_else(|error| {
+                bevy::log::error!("{}: {error}", path.display());
+                Self::default()
+            }),
+            Err(error) if error.kind() == ErrorKind::NotFound => Self::default(),
+            Err(error) => {
+                bevy::log::error!("{}: {error}", path.display());
+                Self::default()
+            }
+        }
+    }
+}
+
+/// Reads the [`SettingsFile`] and reopens the day that was open when the app was closed
+pub fn load_settings(
+    mut commands: Commands,
+    settings_file: Option<Res<SettingsFile>>,
+    mut save: ResMut<SettingsSave>,
+    mut speeds: ResMut<PlaybackSpeeds>,
+    mut next_scene: ResMut<NextState<Scene>>,
+    mut next_part: ResMut<NextState<Part>>,
+    window: Option<Single<&mut Window, With<PrimaryWindow>>>,
+) {
+    let Some(settings_file) = settings_file else {
+        return;
+    };
+    let settings = Settings::read(&settings_file.0);
+
+    speeds.0 = settings
+        .speeds
+        .iter()
+        .map(|(&(year, day), speed)| (Scene::Day { year, day }, *speed))
+        .collect();
+    // Events without visualizations fall back to the latest one
+    let year = if solver(settings.year, 1).is_some() {
+        settings.year
+    } else {
+        Year::default().0
+    };
+    if let Some(day) = settings.day.filter(|day| (1..=25).contains(day)) {
+        next_scene.set(Scene::Day { year, day });
+        next_part.set(settings.part);
+    }
+    if let (Some(mut window), Some((width, height))) = (window, settings.window_size) {
+        window.resolution.set(width, height);
+    }
+    commands.insert_resource(Year(year));
+    commands.insert_resource(settings.input_source);
+    commands.insert_resource(settings.input_directory.clone());
+    commands.insert_resource(CustomSizes(settings.custom_sizes));
+    // Only the settings that differ from the file are saved back to it
+    save.saved = settings.to_text().ok();
+    commands.insert_resource(settings);
+}
+
+fn track_scene(scene: Res<State<Scene>>, mut settings: ResMut<Settings>) {
+    let day = match *scene.get() {
+        Scene::MainMenu => None,
+        Scene::Day { day, .. } => Some(day),
+    };
+    if settings.day != day {
+        settings.day = day;
+    }
+}
+
+fn track_year(year: Res<Year>, mut settings: ResMut<Settings>) {
+    if settings.year != year.0 {
+        settings.year = year.0;
+    }
+}
+
+fn track_part(part: Res<State<Part>>, mut settings: ResMut<Settings>) {
+    if settings.part != *part.get() {
+        settings.part = *part.get();
+    }
+}
+
+fn track_input_source(input_source: Res<InputSource>, mut settings: ResMut<Settings>) {
+    if settings.input_source != *input_source {
+        settings.input_source = *input_source;
+    }
+}
+
+fn track_speeds(speeds: Res<PlaybackSpeeds>, mut settings: ResMut<Settings>) {
+    let speeds: BTreeMap<_, _> = speeds
+        .0
+        .iter()
+        .filter_map(|(scene, speed)| match scene {
+            Scene::Day { year, day } if *speed != Speed::default() => Some(((*year, *day), *speed)),
+            _ => None,
+        })
+        .collect();
+    if settings.speeds != speeds {
+        settings.speeds = speeds;
+    }
+}
+
+fn track_window_size(
+    mut resized: EventReader<WindowResized>,
+    primary_window: Option<Single<Entity, With<PrimaryWindow>>>,
+    mut settings: ResMut<Settings>,
+) {
+    let Some(primary_window) = primary_window else {
+        return;
+    };
+    let size = resized
+        .read()
+        .filter(|event| event.window == *primary_window)
+        .last()
+        .map(|event| (event.width, event.height));
+    if size.is_some() && settings.window_size != size {
+        settings.window_size = size;
+    }
+}
+
+fn apply_theme(settings: Res<Settings>, mut clear_color: ResMut<ClearColor>) {
+    let color = settings.theme.clear_color();
+    if clear_color.0 != color {
+        clear_color.0 = color;
+    }
+}
+
+/// Delay before the changed [`Settings`] are saved, and the text last saved
+#[derive(Debug, Default, Resource)]
+pub struct SettingsSave {
+    /// `None` when there is nothing to save
+    delay: Option<Timer>,
+    saved: Option<String>,
+}
+
+fn delay_save(mut save: ResMut<SettingsSave>) {
+    save.delay = Some(Timer::new(SAVE_DELAY, TimerMode::Once));
+}
+
+fn save_settings(
+    settings: Res<Settings>,
+    settings_file: Res<SettingsFile>,
+    mut save: ResMut<SettingsSave>,
+    time: Res<Time>,
+) {
+    if let Some(delay) = save.delay.as_mut() {
+        if delay.tick(time.delta()).finished() {
+            write_settings(&settings, &settings_file, &mut save);
+        }
+    }
+}
+
+fn save_settings_on_exit(
+    settings: Res<Settings>,
+    settings_file: Res<SettingsFile>,
+    mut save: ResMut<SettingsSave>,
+) {
+    if save.delay.is_some() {
+        write_settings(&settings, &settings_file, &mut save);
+    }
+}
+
+/// Saves the `settings` when they differ from the text last saved
+fn write_settings(settings: &Settings, settings_file: &SettingsFile, save: &mut SettingsSave) {
+    save.delay = None;
+    let text = match settings.to_text() {
+        Ok(text) => text,
+        Err(error) => {
+            bevy::log::error!("{}: {error}", settings_file.0.display());
+            return;
+        }
+    };
+    if save.saved.as_ref() == Some(&text) {
+        return;
+    }
+    match std::fs::write(&settings_file.0, &text) {
+        Ok(()) => save.saved = Some(text),
+        Err(error) => bevy::log::error!("{}: {error}", settings_file.0.display()),
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::{
+    app::Update,
+    input::{common_conditions::input_just_pressed, keyboard::KeyCode},
+    prelude::{
+        any_with_component, state_exists, ButtonInput, Condition, IntoSystemConfigs, NextState,
+        Res, ResMut, State,
+    },
+};
+
+use crate::{
+    loader::{InputDirectory, InputSource},
+    scroll_controls::FocusedScrollWindow,
+};
+
+use super::{
+    components::PartChange,
+    playback::PlaybackControl,
+    states::{InputState, Part, PlaybackState, Scene, UiState},
+};
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            Update,
+            (
+                return_to_main_menu.run_if(input_just_pressed(KeyCode::Escape)),
+                switch_day,
+                switch_part.run_if(any_with_component::<PartChange>),
+                toggle_playback.run_if(
+                    input_just_pressed(KeyCode::Space).and(any_with_component::<PlaybackControl>),
+                ),
+            )
+                .run_if(state_exists::<Part>),
+        );
+    }
+}
+
+fn return_to_main_menu(mut next_state: ResMut<NextState<Scene>>) {
+    next_state.set(Scene::MainMenu);
+}
+
+/// Moves to the closest day with an input, unless the arrows are scrolling a window
+#[allow(clippy::too_many_arguments)]
+fn switch_day(
+    keys: Res<ButtonInput<KeyCode>>,
+    focused_window: Res<FocusedScrollWindow>,
+    scene: Res<State<Scene>>,
+    input_source: Res<InputSource>,
+    input_directory: Res<InputDirectory>,
+    mut next_state: ResMut<NextState<Scene>>,
+    mut part_next_state: ResMut<NextState<Part>>,
+    mut input_next_state: ResMut<NextState<InputState>>,
+    mut ui_next_state: ResMut<NextState<UiState>>,
+) {
+    if focused_window.0.is_some() {
+        return;
+    }
+    let Scene::Day { year, day } = *scene.get() else {
+        return;
+    };
+
+    let next_day = if keys.just_pressed(KeyCode::ArrowLeft) {
+        (1..day)
+            .rev()
+            .find(|day| input_source.exists(&input_directory, year, *day))
+    } else if keys.just_pressed(KeyCode::ArrowRight) {
+        (day + 1..=25).find(|day| input_source.exists(&input_directory, year, *day))
+    } else {
+        return;
+    };
+
+    if let Some(next_day) = next_day {
+        // The sub states are kept between days, so they have to start over explicitly
+        next_state.set(Scene::Day {
+            year,
+            day: next_day,
+        });
+        part_next_state.set(Part::Part1);
+        input_next_state.set(InputState::NotLoaded);
+        ui_next_state.set(UiState::NotLoaded);
+    }
+}
+
+fn switch_part(
+    keys: Res<ButtonInput<KeyCode>>,
+    part: Res<State<Part>>,
+    mut part_next_state: ResMut<NextState<Part>>,
+    mut ui_next_state: ResMut<NextState<UiState>>,
+) {
+    let next_part = if keys.just_pressed(KeyCode::Digit1) {
+        Part::Part1
+    } else if keys.just_pressed(KeyCode::Digit2) {
+        Part::Part2
+    } else {
+        return;
+    };
+
+    if next_part != *part.get() {
+        part_next_state.set(next_part);
+        ui_next_state.set(UiState::NotLoaded);
+    }
+}
+
+fn toggle_playback(
+    playback: Res<State<PlaybackState>>,
+    mut next_state: ResMut<NextState<PlaybackState>>,
+) {
+    next_state.set(match playback.get() {
+        PlaybackState::Playing => PlaybackState::Paused,
+        PlaybackState::Paused => PlaybackState::Playing,
+    });
+}
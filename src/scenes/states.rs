@@ -1,4 +1,5 @@
 use bevy::prelude::{ComputedStates, StateSet, States, SubStates};
+use serde::{Deserialize, Serialize};
 
 use super::components::PartChange;
 
@@ -6,11 +7,14 @@ use super::components::PartChange;
 pub enum Scene {
     #[default]
     MainMenu,
-    Day(u8),
+    Day {
+        year: u16,
+        day: u8,
+    },
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, SubStates)]
-#[source(Scene = Scene::Day(_))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, SubStates, Serialize, Deserialize)]
+#[source(Scene = Scene::Day { .. })]
 pub enum Part {
     #[default]
     Part1,
@@ -27,38 +31,53 @@ impl From<PartChange> for Part {
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, SubStates)]
-#[source(Scene = Scene::Day(_))]
+#[source(Scene = Scene::Day { .. })]
 pub enum InputState {
     #[default]
     NotLoaded,
+    /// Parsing and solving on a background task
+    Computing,
     Loaded,
+    Failed,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, SubStates)]
-#[source(Scene = Scene::Day(_))]
+#[source(Scene = Scene::Day { .. })]
 pub enum UiState {
     #[default]
     NotLoaded,
     Loaded,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, SubStates)]
+#[source(Scene = Scene::Day { .. })]
+pub enum PlaybackState {
+    Playing,
+    #[default]
+    Paused,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum VisualizationState<const N: u8> {
+pub enum VisualizationState<const YEAR: u16, const N: u8> {
     WaitingInput,
     WaitingUi,
     Ready,
 }
 
-impl<const N: u8> ComputedStates for VisualizationState<N> {
+impl<const YEAR: u16, const N: u8> ComputedStates for VisualizationState<YEAR, N> {
     type SourceStates = (Scene, InputState, UiState);
 
     fn compute(sources: Self::SourceStates) -> Option<Self> {
-        match sources {
-            (Scene::Day(day), InputState::NotLoaded, _) if day == N => Some(Self::WaitingInput),
-            (Scene::Day(day), InputState::Loaded, UiState::NotLoaded) if day == N => {
-                Some(Self::WaitingUi)
-            }
-            (Scene::Day(day), InputState::Loaded, UiState::Loaded) if day == N => Some(Self::Ready),
+        let (Scene::Day { year, day }, input, ui) = sources else {
+            return None;
+        };
+        if (year, day) != (YEAR, N) {
+            return None;
+        }
+        match (input, ui) {
+            (InputState::NotLoaded, _) => Some(Self::WaitingInput),
+            (InputState::Loaded, UiState::NotLoaded) => Some(Self::WaitingUi),
+            (InputState::Loaded, UiState::Loaded) => Some(Self::Ready),
             _ => None,
         }
     }
@@ -0,0 +1,704 @@
+//! Boots the scenes in an [`App`] without a window, opens each day on its example input
+//! and checks the UI it spawns.
+
+use std::{
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use bevy::{
+    app::{App, AppExit, PluginGroup, Update},
+    asset::{AssetApp, AssetPlugin, AssetServer, Assets, RenderAssetUsages},
+    color::Color,
+    core::Name,
+    gizmos::GizmoPlugin,
+    image::{CompressedImageFormats, Image, ImageSampler, ImageType},
+    input::InputPlugin,
+    log::LogPlugin,
+    math::Rect,
+    prelude::{
+        Added, Camera, Children, Entity, GlobalTransform, NextState, Query, Res, Resource, State,
+        Trigger, With, World,
+    },
+    render::{
+        camera::RenderTarget,
+        render_resource::{Extent3d, Shader, TextureDimension, TextureFormat, TextureUsages},
+        view::screenshot::{Screenshot, ScreenshotCaptured},
+    },
+    sprite::TextureAtlasLayout,
+    state::app::StatesPlugin,
+    text::Font,
+    time::TimeUpdateStrategy,
+    ui::{ComputedNode, Interaction},
+    window::{ExitCondition, PrimaryWindow, Window, WindowPlugin, WindowRef, WindowResized},
+    winit::WinitPlugin,
+    DefaultPlugins, MinimalPlugins,
+};
+
+use crate::{
+    loader::{AssetLoader, InputDirectory, InputSource, RawInput},
+    scroll_controls::PanZoom,
+    tools::Coord,
+};
+
+use super::{
+    answers::{Solution, Solutions},
+    grid_image::GridImage,
+    playback::{PlaybackSpeeds, Speed},
+    resources::{FontHandles, GenericDay, InputError},
+    settings::{Settings, SettingsFile},
+    states::{InputState, Part, Scene, UiState, VisualizationState},
+};
+
+/// Time a day gets to parse its input and build its UI
+const READY_TIMEOUT: Duration = Duration::from_secs(60);
+const GOLDEN_WIDTH: u32 = 1280;
+const GOLDEN_HEIGHT: u32 = 720;
+/// Difference on any channel above which a pixel does not match its golden image
+const GOLDEN_CHANNEL_TOLERANCE: u8 = 8;
+/// Fraction of the pixels that can differ from the golden image, for font rasterization
+const GOLDEN_PIXEL_TOLERANCE: f64 = 0.001;
+
+const READY: [fn(&World) -> bool; 25] = [
+    is_ready::<1>,
+    is_ready::<2>,
+    is_ready::<3>,
+    is_ready::<4>,
+    is_ready::<5>,
+    is_ready::<6>,
+    is_ready::<7>,
+    is_ready::<8>,
+    is_ready::<9>,
+    is_ready::<10>,
+    is_ready::<11>,
+    is_ready::<12>,
+    is_ready::<13>,
+    is_ready::<14>,
+    is_ready::<15>,
+    is_ready::<16>,
+    is_ready::<17>,
+    is_ready::<18>,
+    is_ready::<19>,
+    is_ready::<20>,
+    is_ready::<21>,
+    is_ready::<22>,
+    is_ready::<23>,
+    is_ready::<24>,
+    is_ready::<25>,
+];
+
+macro_rules! day_tests {
+    ($($name:ident: $day:literal,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+day_tests! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+    day19: 19,
+    day20: 20,
+    day21: 21,
+    day22: 22,
+    day23: 23,
+    day24: 24,
+    day25: 25,
+}
+
+/// Opens both parts of a day and checks the UI tree of each
+fn check_day(day: u8) {
+    let mut app = headless_app();
+
+    open_day(&mut app, day);
+    wait_until_ready(&mut app, day);
+    assert_ui_tree(app.world_mut(), day);
+
+    switch_part(&mut app, Part::Part2);
+    wait_until_ready(&mut app, day);
+    assert_ui_tree(app.world_mut(), day);
+
+    app.world_mut()
+        .resource_mut::<NextState<Scene>>()
+        .set(Scene::MainMenu);
+    app.update();
+    assert!(
+        app.world().get_resource::<GenericDay>().is_none(),
+        "day {day} was not cleaned up"
+    );
+}
+
+/// A settings file reopens the day and part that were open, reading the inputs from its input
+/// directory in a window of the saved size, and is saved when they change. Events without
+/// visualizations open the latest one instead
+#[test]
+fn settings_file() {
+    let path = std::env::temp_dir().join(format!("aoc2024_vis_{}.ron", std::process::id()));
+    std::fs::write(
+        &path,
+        "(year: 2015, day: Some(7), part: Part2, speeds: {(2024, 7): Multiplier(2.0)}, \
+         input_source: Real, input_directory: (\"examples\"), window_size: Some((800.0, 600.0)))",
+    )
+    .unwrap();
+
+    let mut app = headless_app_without_scenes();
+    // The save delay never elapses, the settings are only saved on exit
+    app.insert_resource(SettingsFile(path.clone()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+    add_scenes(&mut app);
+    wait_until_ready(&mut app, 7);
+    assert_eq!(*app.world().resource::<State<Part>>().get(), Part::Part2);
+    assert_eq!(
+        app.world().resource::<InputDirectory>().0,
+        String::from("examples")
+    );
+    let mut windows = app.world_mut().query::<&Window>();
+    let window = windows.single(app.world());
+    assert_eq!((window.width(), window.height()), (800., 600.));
+    assert_eq!(
+        app.world()
+            .resource::<PlaybackSpeeds>()
+            .get(Scene::Day { year: 2024, day: 7 }),
+        Speed::Multiplier(2.)
+    );
+
+    app.world_mut()
+        .resource_mut::<NextState<Scene>>()
+        .set(Scene::MainMenu);
+    app.update();
+    app.update();
+    assert!(std::fs::read_to_string(&path).unwrap().contains("2015"));
+    app.world_mut().send_event(AppExit::Success);
+    app.update();
+    let saved = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let settings: Settings = ron::from_str(&saved).unwrap();
+    assert_eq!(settings.year, 2024);
+    assert_eq!(settings.day, None);
+    assert_eq!(settings.part, Part::Part2);
+    assert_eq!(
+        settings.speeds.get(&(2024, 7)),
+        Some(&Speed::Multiplier(2.))
+    );
+    assert_eq!(settings.window_size, Some((800., 600.)));
+}
+
+/// Reloading the input clears a selected report, which may not exist in the new input
+#[test]
+fn day02_reload_clears_selection() {
+    use super::day02::inspector::SelectedReport;
+
+    let mut app = headless_app();
+    open_day(&mut app, 2);
+    wait_until_ready(&mut app, 2);
+    app.world_mut().resource_mut::<SelectedReport>().0 = Some(1000);
+
+    // Same transitions as a reload or an input source change
+    app.world_mut()
+        .resource_mut::<NextState<InputState>>()
+        .set(InputState::NotLoaded);
+    app.world_mut()
+        .resource_mut::<NextState<UiState>>()
+        .set(UiState::NotLoaded);
+    wait_until_ready(&mut app, 2);
+    app.update();
+    assert_eq!(app.world().resource::<SelectedReport>().0, None);
+}
+
+/// Going back to an input source reuses the solutions of its unchanged inputs
+#[test]
+fn cached_solutions() {
+    let mut app = headless_app();
+    let start = Instant::now();
+    while app
+        .world()
+        .resource::<Solutions>()
+        .solutions
+        .contains(&Solution::Pending)
+    {
+        assert!(
+            start.elapsed() < READY_TIMEOUT,
+            "examples not solved in time"
+        );
+        app.update();
+    }
+    let solutions = app.world().resource::<Solutions>().solutions.clone();
+    assert_eq!(solutions[0], Solution::Solved(["11".into(), "31".into()]));
+
+    *app.world_mut().resource_mut::<InputSource>() = InputSource::Real;
+    app.update();
+    *app.world_mut().resource_mut::<InputSource>() = InputSource::Example;
+    app.update();
+    assert_eq!(app.world().resource::<Solutions>().solutions, solutions);
+}
+
+fn headless_app() -> App {
+    let mut app = headless_app_without_scenes();
+    add_scenes(&mut app);
+
+    app
+}
+
+fn headless_app_without_scenes() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            watch_for_changes_override: Some(false),
+            ..Default::default()
+        },
+        StatesPlugin,
+        InputPlugin,
+    ))
+    .init_asset::<Font>()
+    .init_asset::<Image>()
+    .init_asset::<Shader>()
+    .init_asset::<TextureAtlasLayout>()
+    .add_plugins(GizmoPlugin);
+    // The scroll controls follow the cursor over the primary window, whose size is saved
+    app.world_mut().spawn((Window::default(), PrimaryWindow));
+    app.add_event::<WindowResized>();
+
+    app
+}
+
+fn add_scenes(app: &mut App) {
+    app.register_asset_loader(AssetLoader)
+        .init_asset::<RawInput>()
+        .add_plugins((super::Plugin, crate::scroll_controls::Plugin))
+        .insert_resource(InputSource::Example);
+    app.finish();
+    app.cleanup();
+    // Startup loads the fonts and opens the main menu
+    app.update();
+}
+
+fn is_ready<const N: u8>(world: &World) -> bool {
+    world
+        .get_resource::<State<VisualizationState<2024, N>>>()
+        .is_some_and(|state| *state.get() == VisualizationState::Ready)
+}
+
+/// Same transitions as the day shortcuts, the sub states are kept between days
+fn open_day(app: &mut App, day: u8) {
+    let world = app.world_mut();
+    world
+        .resource_mut::<NextState<Scene>>()
+        .set(Scene::Day { year: 2024, day });
+    world.resource_mut::<NextState<Part>>().set(Part::Part1);
+    world
+        .resource_mut::<NextState<InputState>>()
+        .set(InputState::NotLoaded);
+    world
+        .resource_mut::<NextState<UiState>>()
+        .set(UiState::NotLoaded);
+    app.update();
+}
+
+/// Same transitions as the part buttons
+fn switch_part(app: &mut App, part: Part) {
+    app.world_mut().resource_mut::<NextState<Part>>().set(part);
+    app.world_mut()
+        .resource_mut::<NextState<UiState>>()
+        .set(UiState::NotLoaded);
+    app.update();
+}
+
+fn wait_until_ready(app: &mut App, day: u8) {
+    let start = Instant::now();
+    loop {
+        app.update();
+        if READY[usize::from(day - 1)](app.world()) {
+            return;
+        }
+        if let Some(error) = app.world().get_resource::<InputError>() {
+            panic!("day {day} failed on its example input: {error:?}");
+        }
+        assert!(
+            start.elapsed() < READY_TIMEOUT,
+            "day {day} was not ready after {READY_TIMEOUT:?}"
+        );
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// The UI root holds a single header and content, and the content is not empty
+fn assert_ui_tree(world: &mut World, day: u8) {
+    let ui = world.resource::<GenericDay>().ui;
+    let header = format!("day{day}_header");
+    let content = format!("day{day}_content");
+
+    let roots = world
+        .get::<Children>(ui)
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    let root_names = roots
+        .iter()
+        .filter_map(|child| world.get::<Name>(*child))
+        .map(|name| name.as_str())
+        .collect::<Vec<_>>();
+    assert!(
+        root_names.contains(&header.as_str()),
+        "day {day}: no {header} in {root_names:?}"
+    );
+    assert!(
+        root_names.contains(&content.as_str()),
+        "day {day}: no {content} in {root_names:?}"
+    );
+
+    let mut names = world.query::<&Name>();
+    for name in [&header, &content] {
+        let count = names
+            .iter(world)
+            .filter(|entity_name| entity_name.as_str() == name)
+            .count();
+        assert_eq!(count, 1, "day {day}: {count} entities named {name}");
+    }
+
+    let content = roots
+        .iter()
+        .find(|child| {
+            world
+                .get::<Name>(**child)
+                .is_some_and(|name| name.as_str() == content)
+        })
+        .unwrap();
+    assert!(
+        world
+            .get::<Children>(*content)
+            .is_some_and(|children| !children.is_empty()),
+        "day {day}: empty content"
+    );
+}
+
+/// Renders both parts of every day and compares them with the images under `tests/golden`.
+///
+/// Skipped when no GPU or software rendering backend is found. Missing golden images fail like
+/// mismatches, setting `UPDATE_GOLDEN_IMAGES` records all of them. The frames of the failures are
+/// saved under `target/golden`, which only holds those of the last run.
+#[test]
+fn golden_images() {
+    let Some(mut app) = rendering_app() else {
+        eprintln!("No rendering backend found, skipping the golden images");
+        return;
+    };
+    let golden_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let failed_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/golden");
+    let update = std::env::var_os("UPDATE_GOLDEN_IMAGES").is_some();
+    match std::fs::remove_dir_all(&failed_directory) {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+            panic!("could not clear {}: {error}", failed_directory.display())
+        }
+        _ => (),
+    }
+
+    let mut failures = Vec::new();
+    for day in 1..=25 {
+        open_day(&mut app, day);
+        for part in [Part::Part1, Part::Part2] {
+            if part == Part::Part2 {
+                switch_part(&mut app, part);
+            }
+            wait_until_ready(&mut app, day);
+
+            let name = match part {
+                Part::Part1 => format!("day{day:02}_part1.png"),
+                Part::Part2 => format!("day{day:02}_part2.png"),
+            };
+            let frame = capture_frame(&mut app);
+            let golden = golden_directory.join(&name);
+            if let Err(error) = compare_golden(&frame, &golden, &failed_directory, update) {
+                failures.push(format!("{name}: {error}"));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// The trail heads of day 10 are hovered under the cursor once the map is zoomed in
+#[test]
+fn day10_hover_when_zoomed() {
+    use super::day10::components::Start;
+
+    let Some(mut app) = rendering_app() else {
+        eprintln!("No rendering backend found, skipping the zoomed hover");
+        return;
+    };
+    open_day(&mut app, 10);
+    wait_until_ready(&mut app, 10);
+    // The cursor is only followed on cameras rendering to a window
+    let mut cameras = app.world_mut().query::<&mut Camera>();
+    for mut camera in cameras.iter_mut(app.world_mut()) {
+        camera.target = RenderTarget::Window(WindowRef::Primary);
+    }
+    let mut viewports = app.world_mut().query::<&mut PanZoom>();
+    viewports.single_mut(app.world_mut()).zoom = 4.;
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let mut viewports = app
+        .world_mut()
+        .query_filtered::<(&ComputedNode, &GlobalTransform), With<PanZoom>>();
+    let (viewport, transform) = viewports.single(app.world());
+    let viewport = Rect::from_center_size(transform.translation().truncate(), viewport.size());
+    let mut starts = app
+        .world_mut()
+        .query::<(Entity, &ComputedNode, &GlobalTransform, &Start)>();
+    let (start, size, center) = starts
+        .iter(app.world())
+        .map(|(entity, node, transform, _)| {
+            (entity, node.size(), transform.translation().truncate())
+        })
+        .find(|(_, _, center)| viewport.contains(*center))
+        .expect("no trail head in the zoomed viewport");
+    assert!(
+        size.y > viewport.height() / 8. * 4. * 0.9,
+        "the map was not zoomed, trail heads are {size}"
+    );
+
+    let mut windows = app
+        .world_mut()
+        .query_filtered::<&mut Window, With<PrimaryWindow>>();
+    windows
+        .single_mut(app.world_mut())
+        .set_physical_cursor_position(Some(center.as_dvec2()));
+    app.update();
+    app.update();
+    assert_eq!(
+        app.world().get::<Interaction>(start),
+        Some(&Interaction::Hovered)
+    );
+}
+
+/// Cells painted once the grid is on screen reach its texture
+#[test]
+fn grid_image_updates() {
+    let Some(mut app) = rendering_app() else {
+        eprintln!("No rendering backend found, skipping the grid image updates");
+        return;
+    };
+    let red_pixels = |frame: Image| {
+        frame
+            .try_into_dynamic()
+            .unwrap()
+            .to_rgba8()
+            .pixels()
+            .filter(|pixel| pixel[0] > 250 && pixel[1] < 5 && pixel[2] < 5)
+            .count()
+    };
+    open_day(&mut app, 18);
+    wait_until_ready(&mut app, 18);
+    let before = red_pixels(capture_frame(&mut app));
+
+    let mut grids = app.world_mut().query::<&mut GridImage>();
+    let mut grid = grids.single_mut(app.world_mut());
+    // The 7x7 memory space of the example
+    for row in 0..7 {
+        for column in 0..7 {
+            grid.set(Coord::new(row, column), Color::srgb(1., 0., 0.));
+        }
+    }
+    let after = red_pixels(capture_frame(&mut app));
+
+    assert!(
+        after > before + 10_000,
+        "the painted cells were not drawn, {before} red pixels before and {after} after"
+    );
+}
+
+#[derive(Debug, Resource)]
+struct GoldenTarget(bevy::asset::Handle<Image>);
+
+/// App rendering every camera into an image, `None` if the renderer could not start
+fn rendering_app() -> Option<App> {
+    std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut app = App::new();
+        app.add_plugins(
+            DefaultPlugins
+                .build()
+                // Without winit the window has no surface, the cameras render to an image
+                .set(WindowPlugin {
+                    primary_window: Some(Window::default()),
+                    exit_condition: ExitCondition::DontExit,
+                    close_when_requested: false,
+                })
+                .set(AssetPlugin {
+                    watch_for_changes_override: Some(false),
+                    ..Default::default()
+                })
+                .disable::<WinitPlugin>()
+                .disable::<LogPlugin>(),
+        );
+
+        let mut target = Image::new_fill(
+            Extent3d {
+                width: GOLDEN_WIDTH,
+                height: GOLDEN_HEIGHT,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        target.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+            | TextureUsages::COPY_SRC
+            | TextureUsages::COPY_DST
+            | TextureUsages::RENDER_ATTACHMENT;
+        let target = app.world_mut().resource_mut::<Assets<Image>>().add(target);
+        // Frozen time keeps the scrolling and blinking days still between runs
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO))
+            .insert_resource(GoldenTarget(target))
+            .add_systems(Update, render_to_golden_target);
+        add_scenes(&mut app);
+
+        app
+    }))
+    .ok()
+}
+
+fn render_to_golden_target(
+    target: Res<GoldenTarget>,
+    mut cameras: Query<&mut Camera, Added<Camera>>,
+) {
+    for mut camera in cameras.iter_mut() {
+        camera.target = RenderTarget::Image(target.0.clone());
+    }
+}
+
+/// Waits for the fonts, lets the layout settle, then reads the rendered frame back
+fn capture_frame(app: &mut App) -> Image {
+    let start = Instant::now();
+    let fonts_loaded = |world: &World| {
+        let fonts = world.resource::<FontHandles>();
+        let asset_server = world.resource::<AssetServer>();
+        [&fonts.font, &fonts.symbol1, &fonts.symbol2]
+            .into_iter()
+            .all(|font| asset_server.is_loaded_with_dependencies(font))
+    };
+    while !fonts_loaded(app.world()) {
+        assert!(start.elapsed() < READY_TIMEOUT, "fonts were not loaded");
+        app.update();
+    }
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let frame = Arc::new(Mutex::new(None));
+    let captured = frame.clone();
+    let target = app.world().resource::<GoldenTarget>().0.clone();
+    app.world_mut().spawn(Screenshot::image(target)).observe(
+        move |trigger: Trigger<ScreenshotCaptured>| {
+            *captured.lock().unwrap() = Some(trigger.event().0.clone());
+        },
+    );
+
+    loop {
+        app.update();
+        if let Some(frame) = frame.lock().unwrap().take() {
+            return frame;
+        }
+        assert!(start.elapsed() < READY_TIMEOUT, "no frame was captured");
+    }
+}
+
+/// Compares `frame` with the golden image at `path`, saving it to `failed_directory` when it
+/// differs or the golden image is missing. Records it as the golden image instead with `update`.
+fn compare_golden(
+    frame: &Image,
+    path: &Path,
+    failed_directory: &Path,
+    update: bool,
+) -> Result<(), String> {
+    let actual = frame
+        .clone()
+        .try_into_dynamic()
+        .map_err(|error| error.to_string())?
+        .to_rgba8();
+    let save_actual = |directory: &Path| -> Result<PathBuf, String> {
+        let actual_path = directory.join(path.file_name().unwrap());
+        std::fs::create_dir_all(directory).map_err(|error| error.to_string())?;
+        actual
+            .save(&actual_path)
+            .map_err(|error| error.to_string())?;
+        Ok(actual_path)
+    };
+
+    if update {
+        save_actual(path.parent().unwrap())?;
+        eprintln!("Recorded {}", path.display());
+        return Ok(());
+    }
+    if !path.exists() {
+        let actual_path = save_actual(failed_directory)?;
+        return Err(format!(
+            "no golden image, rendered frame saved to {}, set UPDATE_GOLDEN_IMAGES=1 to record it",
+            actual_path.display()
+        ));
+    }
+
+    let golden = Image::from_buffer(
+        &std::fs::read(path).map_err(|error| error.to_string())?,
+        ImageType::Extension("png"),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+    .map_err(|error| error.to_string())?
+    .try_into_dynamic()
+    .map_err(|error| error.to_string())?
+    .to_rgba8();
+
+    if golden.dimensions() != actual.dimensions() {
+        return Err(format!(
+            "rendered {:?}, expected {:?}",
+            actual.dimensions(),
+            golden.dimensions()
+        ));
+    }
+    let different = golden
+        .pixels()
+        .zip(actual.pixels())
+        .filter(|(golden, actual)| {
+            golden
+                .0
+                .iter()
+                .zip(actual.0.iter())
+                .any(|(golden, actual)| golden.abs_diff(*actual) > GOLDEN_CHANNEL_TOLERANCE)
+        })
+        .count();
+    if different as f64 > f64::from(golden.width() * golden.height()) * GOLDEN_PIXEL_TOLERANCE {
+        let actual_path = save_actual(failed_directory)?;
+        return Err(format!(
+            "{different} pixels differ, rendered frame saved to {}",
+            actual_path.display()
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use bevy::{
+    app::Update,
+    asset::{Assets, Handle, RenderAssetUsages},
+    image::Image,
+    prelude::{
+        in_state, Commands, Condition, IntoSystemConfigs, Query, Res, ResMut, Resource, State,
+        Trigger, With,
+    },
+    render::view::screenshot::{Screenshot, ScreenshotCaptured},
+    time::common_conditions::on_timer,
+    utils::HashMap,
+};
+
+use super::states::{Scene, UiState};
+
+/// Bounds of a thumbnail, screenshots are scaled down to fit while keeping their aspect ratio
+const THUMBNAIL_SIZE: (u32, u32) = (160, 90);
+/// Time spent in a day between two thumbnails
+const THUMBNAIL_INTERVAL: Duration = Duration::from_secs(2);
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<Thumbnails>();
+
+        app.add_systems(
+            Update,
+            capture_thumbnail.run_if(in_state(UiState::Loaded).and(on_timer(THUMBNAIL_INTERVAL))),
+        );
+    }
+}
+
+/// Latest thumbnail of each day visited since launch by year and day, updated in place so images
+/// showing it stay live
+#[derive(Debug, Default, Resource)]
+pub struct Thumbnails(pub HashMap<(u16, u8), Handle<Image>>);
+
+fn capture_thumbnail(
+    mut commands: Commands,
+    scene: Res<State<Scene>>,
+    screenshots: Query<(), With<Screenshot>>,
+) {
+    let Scene::Day { year, day } = *scene.get() else {
+        return;
+    };
+    // Only one screenshot of a window is taken per frame, leave it to the capture shortcuts
+    if !screenshots.is_empty() {
+        return;
+    }
+
+    commands.spawn(Screenshot::primary_window()).observe(
+        move |trigger: Trigger<ScreenshotCaptured>,
+              mut images: ResMut<Assets<Image>>,
+              mut thumbnails: ResMut<Thumbnails>| {
+            let Ok(screenshot) = trigger.event().0.clone().try_into_dynamic() else {
+                return;
+            };
+            let thumbnail = Image::from_dynamic(
+                screenshot.thumbnail(THUMBNAIL_SIZE.0, THUMBNAIL_SIZE.1),
+                true,
+                RenderAssetUsages::RENDER_WORLD,
+            );
+
+            match thumbnails.0.get(&(year, day)) {
+                Some(handle) => images.insert(handle.id(), thumbnail),
+                None => {
+                    thumbnails.0.insert((year, day), images.add(thumbnail));
+                }
+            }
+        },
+    );
+}
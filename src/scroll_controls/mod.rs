@@ -1,26 +1,76 @@
+mod pan_zoom;
+mod scrollbar;
 pub mod ui;
 
 use bevy::{
     app::Update,
     color::Color,
+    input::{
+        common_conditions::{input_just_pressed, input_just_released},
+        keyboard::KeyCode,
+        mouse::{MouseButton, MouseScrollUnit, MouseWheel},
+    },
+    math::Vec2,
     prelude::{
-        Button, Commands, Component, Entity, Event, OnAdd, Parent, Query, Res, Trigger, With,
+        resource_changed, Button, ButtonInput, Commands, Component, Condition, Entity, Event,
+        EventReader, IntoSystemConfigs, OnAdd, Parent, Query, Res, ResMut, Resource, Single,
+        Trigger, With, Without,
     },
     time::Time,
-    ui::{BackgroundColor, Interaction, Node, ScrollPosition},
+    ui::{
+        BackgroundColor, ComputedNode, Interaction, Node, Outline, RelativeCursorPosition,
+        ScrollPosition, Val,
+    },
+    window::{PrimaryWindow, Window},
 };
 
+use self::scrollbar::{ScrollAxis, Scrollbar, ScrollbarThumb};
+
+pub use self::pan_zoom::PanZoom;
+
 pub const BUTTON_BACKGROUND_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
 pub const BUTTON_HOVERED_BACKGROUND_COLOR: Color = Color::srgb(0.8, 0.8, 0.9);
 pub const BUTTON_SELECTED_BACKGROUND_COLOR: Color = Color::srgb(0.8, 0.9, 0.8);
 
+const KEYBOARD_SCROLL_SPEED: f32 = 500.;
+/// Pixels scrolled by each line of a mouse wheel
+const WHEEL_LINE_HEIGHT: f32 = 20.;
+
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_event::<ScrollControlHovered>();
 
-        app.add_systems(Update, scroll_control_hovered);
+        app.init_resource::<FocusedScrollWindow>()
+            .init_resource::<ScrollDrag>();
+
+        app.add_systems(
+            Update,
+            (
+                scroll_control_hovered,
+                focus_clicked_window.run_if(input_just_pressed(MouseButton::Left)),
+                cycle_focused_window.run_if(input_just_pressed(KeyCode::Tab)),
+                scroll_focused_window,
+                scroll_to_edge
+                    .run_if(input_just_pressed(KeyCode::Home).or(input_just_pressed(KeyCode::End))),
+                scroll_on_wheel,
+                (
+                    start_drag.run_if(input_just_pressed(MouseButton::Left)),
+                    drag,
+                    end_drag.run_if(input_just_released(MouseButton::Left)),
+                )
+                    .chain(),
+                update_focus_outline.run_if(resource_changed::<FocusedScrollWindow>),
+                (
+                    scrollbar::spawn_scrollbars,
+                    scrollbar::update_scrollbars,
+                    pan_zoom::zoom_on_wheel,
+                    pan_zoom::apply_zoom,
+                )
+                    .chain(),
+            ),
+        );
 
         app.add_observer(attatch_observer_to_window);
     }
@@ -34,10 +84,36 @@ pub struct ScrollControl {
     pub target: Entity,
 }
 
-#[derive(Debug, Component)]
-#[require(Node)]
+#[derive(Debug, Default, Component)]
+#[require(Node, RelativeCursorPosition)]
 pub struct ScrollWindow;
 
+/// [`ScrollWindow`] that is scrolled by the arrow keys
+#[derive(Debug, Default, Resource)]
+pub struct FocusedScrollWindow(pub Option<Entity>);
+
+#[derive(Debug, Default, Resource)]
+struct ScrollDrag(Option<Drag>);
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    window: Entity,
+    /// Scrollbar whose thumb is being dragged, or `None` when dragging the content
+    scrollbar: Option<Entity>,
+    cursor: Vec2,
+}
+
+type HoveredWindowQuery<'a, 'b> = Query<
+    'a,
+    'b,
+    (
+        Entity,
+        &'static RelativeCursorPosition,
+        &'static ComputedNode,
+    ),
+    With<ScrollWindow>,
+>;
+
 #[derive(Debug, Component)]
 struct ScrollControlHovered(ScrollControl);
 
@@ -85,3 +161,228 @@ fn scroll_on_control_hovered(
     window.offset_x += offset.horizontal * time.delta_secs();
     window.offset_y += offset.vertical * time.delta_secs();
 }
+
+/// Innermost window under the cursor
+fn hovered_window(windows: &HoveredWindowQuery) -> Option<Entity> {
+    windows
+        .iter()
+        .filter(|(_, cursor, _)| cursor.mouse_over())
+        .min_by(|(_, _, a), (_, _, b)| {
+            let (a, b) = (a.size(), b.size());
+            (a.x * a.y).total_cmp(&(b.x * b.y))
+        })
+        .map(|(window, _, _)| window)
+}
+
+fn scroll_on_wheel(
+    mut wheel_events: EventReader<MouseWheel>,
+    hovered: HoveredWindowQuery,
+    mut windows: Query<&mut ScrollPosition, (With<ScrollWindow>, Without<PanZoom>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let delta = wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => Vec2::new(event.x, event.y) * WHEEL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => Vec2::new(event.x, event.y),
+        })
+        .sum::<Vec2>();
+    if delta == Vec2::ZERO {
+        return;
+    }
+    let Some(mut position) =
+        hovered_window(&hovered).and_then(|window| windows.get_mut(window).ok())
+    else {
+        return;
+    };
+
+    // Wheels only scroll vertically, Shift turns them sideways
+    let delta = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        Vec2::new(delta.y, delta.x)
+    } else {
+        delta
+    };
+    position.offset_x -= delta.x;
+    position.offset_y -= delta.y;
+}
+
+fn start_drag(
+    hovered: HoveredWindowQuery,
+    buttons: Query<&Interaction, With<Button>>,
+    thumbs: Query<(&Interaction, &Parent), With<ScrollbarThumb>>,
+    scrollbars: Query<&Scrollbar>,
+    pan_zooms: Query<(), With<PanZoom>>,
+    primary_window: Single<&Window, With<PrimaryWindow>>,
+    mut scroll_drag: ResMut<ScrollDrag>,
+) {
+    let Some(cursor) = primary_window.cursor_position() else {
+        return;
+    };
+
+    let thumb = thumbs
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .find_map(|(_, parent)| Some((parent.get(), scrollbars.get(parent.get()).ok()?)));
+    scroll_drag.0 = if let Some((scrollbar_entity, scrollbar)) = thumb {
+        Some(Drag {
+            window: scrollbar.window,
+            scrollbar: Some(scrollbar_entity),
+            cursor,
+        })
+    } else {
+        // Let buttons inside of the window handle the click, except for the tiles of a
+        // zoomed canvas that only react to hovering
+        let pressed_button = buttons
+            .iter()
+            .any(|interaction| *interaction == Interaction::Pressed);
+        hovered_window(&hovered)
+            .filter(|window| !pressed_button || pan_zooms.contains(*window))
+            .map(|window| Drag {
+                window,
+                scrollbar: None,
+                cursor,
+            })
+    };
+}
+
+fn drag(
+    mut scroll_drag: ResMut<ScrollDrag>,
+    mut windows: Query<&mut ScrollPosition, With<ScrollWindow>>,
+    scrollbars: Query<&Scrollbar>,
+    primary_window: Single<&Window, With<PrimaryWindow>>,
+) {
+    let Some(drag) = scroll_drag.0.as_mut() else {
+        return;
+    };
+    let Some(cursor) = primary_window.cursor_position() else {
+        return;
+    };
+    let delta = cursor - drag.cursor;
+    if delta == Vec2::ZERO {
+        return;
+    }
+    drag.cursor = cursor;
+
+    let Ok(mut position) = windows.get_mut(drag.window) else {
+        scroll_drag.0 = None;
+        return;
+    };
+    match drag
+        .scrollbar
+        .and_then(|scrollbar| scrollbars.get(scrollbar).ok())
+    {
+        Some(scrollbar) => match scrollbar.axis {
+            ScrollAxis::Horizontal => position.offset_x += delta.x * scrollbar.scroll_per_pixel,
+            ScrollAxis::Vertical => position.offset_y += delta.y * scrollbar.scroll_per_pixel,
+        },
+        // The content follows the cursor
+        None => {
+            position.offset_x -= delta.x;
+            position.offset_y -= delta.y;
+        }
+    }
+}
+
+fn end_drag(mut scroll_drag: ResMut<ScrollDrag>) {
+    scroll_drag.0 = None;
+}
+
+/// Home and End jump to the top or bottom of the focused window, or of the hovered one
+fn scroll_to_edge(
+    hovered: HoveredWindowQuery,
+    mut windows: Query<&mut ScrollPosition, With<ScrollWindow>>,
+    focused: Res<FocusedScrollWindow>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let Some(mut position) = focused
+        .0
+        .or_else(|| hovered_window(&hovered))
+        .and_then(|window| windows.get_mut(window).ok())
+    else {
+        return;
+    };
+    // The scrollbars clamp the offset to the end of the content
+    position.offset_y = if keys.just_pressed(KeyCode::Home) {
+        0.
+    } else {
+        f32::MAX
+    };
+}
+
+fn focus_clicked_window(
+    windows: Query<(Entity, &RelativeCursorPosition), With<ScrollWindow>>,
+    mut focused: ResMut<FocusedScrollWindow>,
+) {
+    let clicked = windows
+        .iter()
+        .find(|(_, cursor)| cursor.mouse_over())
+        .map(|(window, _)| window);
+    if focused.0 != clicked {
+        focused.0 = clicked;
+    }
+}
+
+/// Moves the focus through every window, and then out of them
+fn cycle_focused_window(
+    windows: Query<Entity, With<ScrollWindow>>,
+    mut focused: ResMut<FocusedScrollWindow>,
+) {
+    let mut windows = windows.iter().collect::<Vec<_>>();
+    windows.sort();
+    focused.0 = match focused.0 {
+        None => windows.first().copied(),
+        Some(current) => windows
+            .iter()
+            .skip_while(|window| **window != current)
+            .nth(1)
+            .copied(),
+    };
+}
+
+fn scroll_focused_window(
+    mut windows: Query<&mut ScrollPosition, With<ScrollWindow>>,
+    mut focused: ResMut<FocusedScrollWindow>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+) {
+    let Some(window) = focused.0 else {
+        return;
+    };
+    let Ok(mut position) = windows.get_mut(window) else {
+        // The window was despawned with its scene
+        focused.0 = None;
+        return;
+    };
+
+    let axis = |negative, positive| {
+        (f32::from(u8::from(keys.pressed(positive))) - f32::from(u8::from(keys.pressed(negative))))
+            * KEYBOARD_SCROLL_SPEED
+            * time.delta_secs()
+    };
+    let (horizontal, vertical) = (
+        axis(KeyCode::ArrowLeft, KeyCode::ArrowRight),
+        axis(KeyCode::ArrowUp, KeyCode::ArrowDown),
+    );
+    if horizontal != 0. || vertical != 0. {
+        position.offset_x += horizontal;
+        position.offset_y += vertical;
+    }
+}
+
+fn update_focus_outline(
+    mut commands: Commands,
+    windows: Query<Entity, With<ScrollWindow>>,
+    focused: Res<FocusedScrollWindow>,
+) {
+    for window in windows.iter() {
+        if focused.0 == Some(window) {
+            commands.entity(window).insert(Outline::new(
+                Val::Px(2.),
+                Val::ZERO,
+                BUTTON_HOVERED_BACKGROUND_COLOR,
+            ));
+        } else {
+            commands.entity(window).remove::<Outline>();
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    math::Vec2,
+    prelude::{Changed, Children, Component, EventReader, Query, Without},
+    ui::{ComputedNode, Node, RelativeCursorPosition, ScrollPosition, Val},
+};
+
+use super::{
+    hovered_window, scrollbar::Scrollbar, HoveredWindowQuery, ScrollWindow, WHEEL_LINE_HEIGHT,
+};
+
+const MAX_ZOOM: f32 = 32.;
+/// Zoom multiplier for each line of a mouse wheel
+const ZOOM_PER_LINE: f32 = 1.1;
+
+/// Viewport whose children are zoomed with the mouse wheel.
+///
+/// The children are canvases sized with a percentage of the viewport height and an aspect
+/// ratio, zooming grows their height so the hover interactions of their tiles keep working.
+/// Panning is the dragging of any other [`ScrollWindow`].
+#[derive(Debug, Component)]
+#[require(ScrollWindow)]
+pub struct PanZoom {
+    pub zoom: f32,
+}
+
+impl Default for PanZoom {
+    fn default() -> Self {
+        Self { zoom: 1. }
+    }
+}
+
+pub fn zoom_on_wheel(
+    mut wheel_events: EventReader<MouseWheel>,
+    hovered: HoveredWindowQuery,
+    mut viewports: Query<(
+        &mut PanZoom,
+        &mut ScrollPosition,
+        &RelativeCursorPosition,
+        &ComputedNode,
+    )>,
+) {
+    let lines = wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / WHEEL_LINE_HEIGHT,
+        })
+        .sum::<f32>();
+    if lines == 0. {
+        return;
+    }
+    let Some((mut pan_zoom, mut position, cursor, node)) =
+        hovered_window(&hovered).and_then(|window| viewports.get_mut(window).ok())
+    else {
+        return;
+    };
+
+    let zoom = (pan_zoom.zoom * ZOOM_PER_LINE.powf(lines)).clamp(1., MAX_ZOOM);
+    if zoom == pan_zoom.zoom {
+        return;
+    }
+
+    // Keeps the point under the cursor in place, the layout clamps the offset to the new size
+    let cursor =
+        cursor.normalized.unwrap_or(Vec2::splat(0.5)) * node.size() * node.inverse_scale_factor();
+    let offset = Vec2::new(position.offset_x, position.offset_y);
+    let offset = ((offset + cursor) * zoom / pan_zoom.zoom - cursor).max(Vec2::ZERO);
+    position.offset_x = offset.x;
+    position.offset_y = offset.y;
+    pan_zoom.zoom = zoom;
+}
+
+pub fn apply_zoom(
+    viewports: Query<(&PanZoom, &Children), Changed<PanZoom>>,
+    mut canvases: Query<&mut Node, Without<Scrollbar>>,
+) {
+    for (pan_zoom, children) in viewports.iter() {
+        for child in children.iter() {
+            let Ok(mut canvas) = canvases.get_mut(*child) else {
+                continue;
+            };
+            let height = Val::Percent(100. * pan_zoom.zoom);
+            if canvas.height != height {
+                canvas.height = height;
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use bevy::{
+    math::Vec2,
+    prelude::{
+        BuildChildren, Button, ChildBuild, Children, Commands, Component, DetectChangesMut, Entity,
+        Query, Transform, Visibility, With, Without, World,
+    },
+    ui::{BackgroundColor, ComputedNode, Node, PositionType, ScrollPosition, Val, ZIndex},
+    utils::{HashMap, HashSet},
+};
+
+use super::{ScrollWindow, BUTTON_BACKGROUND_COLOR};
+
+const SCROLLBAR_WIDTH: f32 = 8.;
+const MIN_THUMB_LENGTH: f32 = 16.;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAxis {
+    Horizontal,
+    Vertical,
+}
+
+/// Track of a scrollbar, spawned as a child of its [`ScrollWindow`]
+#[derive(Debug, Component)]
+#[require(Node)]
+pub struct Scrollbar {
+    pub window: Entity,
+    pub axis: ScrollAxis,
+    /// Distance the content scrolls when the thumb moves by one pixel
+    pub scroll_per_pixel: f32,
+}
+
+#[derive(Debug, Component)]
+#[require(Button)]
+pub struct ScrollbarThumb;
+
+/// Visible and content sizes of a [`ScrollWindow`], in logical pixels
+struct WindowExtent {
+    visible: Vec2,
+    content: Vec2,
+    offset: Vec2,
+}
+
+/// Spawns the scrollbars of new windows, and of windows whose children were despawned
+pub fn spawn_scrollbars(
+    mut commands: Commands,
+    windows: Query<Entity, With<ScrollWindow>>,
+    scrollbars: Query<&Scrollbar>,
+) {
+    let with_scrollbars = scrollbars
+        .iter()
+        .map(|scrollbar| scrollbar.window)
+        .collect::<HashSet<_>>();
+
+    for window in windows
+        .iter()
+        .filter(|window| !with_scrollbars.contains(window))
+    {
+        // The window can be despawned by another system before the commands are applied
+        commands.queue(move |world: &mut World| {
+            let Ok(mut window_entity) = world.get_entity_mut(window) else {
+                return;
+            };
+            window_entity.with_children(|parent| {
+                for axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+                    parent
+                        .spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                ..Default::default()
+                            },
+                            Scrollbar {
+                                window,
+                                axis,
+                                scroll_per_pixel: 0.,
+                            },
+                            Visibility::Hidden,
+                            ZIndex(1),
+                        ))
+                        .with_child((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                ..Default::default()
+                            },
+                            BackgroundColor(BUTTON_BACKGROUND_COLOR),
+                            ScrollbarThumb,
+                        ));
+                }
+            });
+        });
+    }
+}
+
+pub fn update_scrollbars(
+    mut windows: Query<(Entity, &ComputedNode, &mut ScrollPosition, &Children), With<ScrollWindow>>,
+    contents: Query<(&ComputedNode, &Transform), Without<Scrollbar>>,
+    mut scrollbars: Query<(&mut Scrollbar, &mut Node, &mut Visibility, &Children)>,
+    mut thumbs: Query<&mut Node, (With<ScrollbarThumb>, Without<Scrollbar>)>,
+) {
+    let mut extents = HashMap::new();
+    for (window, node, mut scroll_position, children) in windows.iter_mut() {
+        let scale = node.inverse_scale_factor();
+        let visible = node.size() * scale;
+        let offset = Vec2::new(scroll_position.offset_x, scroll_position.offset_y);
+
+        // Children are laid out relative to the center of the window, and already
+        // moved by the scroll offset
+        let content = children
+            .iter()
+            .filter_map(|child| contents.get(*child).ok())
+            .map(|(child, transform)| {
+                (transform.translation.truncate() + child.size() / 2.) * scale + visible / 2.
+            })
+            .fold(visible, |content, child_end| {
+                content.max(child_end + offset)
+            });
+
+        let clamped = offset.clamp(Vec2::ZERO, content - visible);
+        if clamped != offset {
+            scroll_position.offset_x = clamped.x;
+            scroll_position.offset_y = clamped.y;
+        }
+
+        extents.insert(
+            window,
+            WindowExtent {
+                visible,
+                content,
+                offset: clamped,
+            },
+        );
+    }
+
+    for (mut scrollbar, mut track, mut visibility, children) in scrollbars.iter_mut() {
+        let Some(extent) = extents.get(&scrollbar.window) else {
+            continue;
+        };
+        let (visible, content, offset) = match scrollbar.axis {
+            ScrollAxis::Horizontal => (extent.visible.x, extent.content.x, extent.offset.x),
+            ScrollAxis::Vertical => (extent.visible.y, extent.content.y, extent.offset.y),
+        };
+        let max_offset = content - visible;
+
+        visibility.set_if_neq(if max_offset < 1. {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
+
+        // The track is positioned against the scroll offset to stay in place
+        let thickness = Val::Px(SCROLLBAR_WIDTH);
+        track.set_if_neq(match scrollbar.axis {
+            ScrollAxis::Horizontal => Node {
+                left: Val::Px(extent.offset.x),
+                bottom: Val::Px(-extent.offset.y),
+                width: Val::Px(visible),
+                height: thickness,
+                ..track.clone()
+            },
+            ScrollAxis::Vertical => Node {
+                top: Val::Px(extent.offset.y),
+                right: Val::Px(-extent.offset.x),
+                width: thickness,
+                height: Val::Px(visible),
+                ..track.clone()
+            },
+        });
+
+        let thumb_length = (visible / content * visible)
+            .max(MIN_THUMB_LENGTH)
+            .min(visible);
+        let thumb_position = if max_offset > 0. {
+            offset / max_offset * (visible - thumb_length)
+        } else {
+            0.
+        };
+        scrollbar.scroll_per_pixel = if visible > thumb_length {
+            max_offset / (visible - thumb_length)
+        } else {
+            0.
+        };
+
+        for child in children.iter() {
+            let Ok(mut thumb) = thumbs.get_mut(*child) else {
+                continue;
+            };
+            thumb.set_if_neq(match scrollbar.axis {
+                ScrollAxis::Horizontal => Node {
+                    left: Val::Px(thumb_position),
+                    width: Val::Px(thumb_length),
+                    height: Val::Percent(100.),
+                    ..thumb.clone()
+                },
+                ScrollAxis::Vertical => Node {
+                    top: Val::Px(thumb_position),
+                    width: Val::Percent(100.),
+                    height: Val::Px(thumb_length),
+                    ..thumb.clone()
+                },
+            });
+        }
+    }
+}
@@ -99,3 +99,41 @@ impl Iterator for Convolution<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Convolution;
+
+    #[test]
+    fn windows_follow_the_grid() {
+        let mut rng = fastrand::Rng::with_seed(12);
+
+        for _ in 0..200 {
+            let (height, width) = (rng.usize(1..10), rng.usize(1..10));
+            let grid = (0..height)
+                .map(|_| (0..width).map(|_| rng.u8(0..8)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let slices = grid.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+            let windows = Convolution::new(&slices).collect::<Vec<_>>();
+            assert_eq!(windows.len(), height * width);
+
+            for (index, (window, line, column)) in windows.into_iter().enumerate() {
+                assert_eq!((line, column), (index / width, index % width));
+                for (row, cells) in window.into_iter().enumerate() {
+                    for (col, cell) in cells.into_iter().enumerate() {
+                        let expected = (line + row)
+                            .checked_sub(1)
+                            .and_then(|line| grid.get(line))
+                            .and_then(|line| {
+                                (column + col).checked_sub(1).and_then(|c| line.get(c))
+                            })
+                            .copied()
+                            .unwrap_or(u8::MAX);
+                        assert_eq!(cell, expected);
+                    }
+                }
+            }
+        }
+    }
+}
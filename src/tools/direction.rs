@@ -52,3 +52,57 @@ impl Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Coord, Direction};
+
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    #[test]
+    fn turns() {
+        for direction in DIRECTIONS {
+            let turned = (0..4).fold(direction, |direction, _| direction.turn_right());
+            assert_eq!(turned, direction);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(
+                direction.turn_right().turn_right(),
+                direction.turn_left().turn_left()
+            );
+        }
+    }
+
+    #[test]
+    fn step_stays_in_bounds() {
+        let mut rng = fastrand::Rng::with_seed(7);
+
+        for _ in 0..1000 {
+            let bounds = Coord::new(rng.usize(1..20), rng.usize(1..20));
+            let coord = Coord::new(rng.usize(0..bounds.row), rng.usize(0..bounds.column));
+            let direction = DIRECTIONS[rng.usize(0..4)];
+
+            match direction.step(coord, bounds) {
+                Some(next) => {
+                    assert!(next.row < bounds.row && next.column < bounds.column);
+                    assert_eq!(next, direction.step_unchecked(coord));
+                    let back = direction.turn_right().turn_right();
+                    assert_eq!(back.step(next, bounds), Some(coord));
+                }
+                None => {
+                    let on_edge = match direction {
+                        Direction::North => coord.row == 0,
+                        Direction::South => coord.row + 1 == bounds.row,
+                        Direction::East => coord.column + 1 == bounds.column,
+                        Direction::West => coord.column == 0,
+                    };
+                    assert!(on_edge, "{direction:?} from {coord:?} in {bounds:?}");
+                }
+            }
+        }
+    }
+}
@@ -232,3 +232,28 @@ impl<'a> Maze<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::Vec2d;
+
+    use super::Maze;
+
+    fn solve(maze: &[u8]) -> (usize, usize) {
+        let mut data = maze.to_vec();
+        let maze = Maze::parse(&mut data, 1000);
+        let (mut tiles, paths) = maze.calculate_tile_scores();
+        let tiles = Vec2d::new(tiles.as_mut_slice(), maze.width(), maze.height());
+
+        (tiles[maze.end()], paths.len())
+    }
+
+    #[test]
+    fn calculate_tile_scores() {
+        assert_eq!(solve(b"#####\n#..E#\n#S#.#\n#####\n"), (2003, 4));
+        assert_eq!(
+            solve(include_bytes!("../../assets/examples/2024/day16.txt")),
+            (7036, 45)
+        );
+    }
+}
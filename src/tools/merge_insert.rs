@@ -67,3 +67,64 @@ impl DerefMut for MergeInsert {
         &mut self.data
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{MergeInsert, MergeInsertNode};
+
+    fn find(parents: &mut [usize], index: usize) -> usize {
+        if parents[index] != index {
+            parents[index] = find(parents, parents[index]);
+        }
+        parents[index]
+    }
+
+    #[test]
+    fn merges_keep_the_sums_of_each_group() {
+        let mut rng = fastrand::Rng::with_seed(3);
+
+        for _ in 0..200 {
+            let (height, width) = (rng.usize(1..6), rng.usize(1..6));
+            let cells = height * width;
+            let values = (0..cells)
+                .map(|_| (rng.u64(0..100), rng.u64(0..100)))
+                .collect::<Vec<_>>();
+
+            let mut merge_insert = MergeInsert::default();
+            for (index, value) in values.iter().enumerate() {
+                merge_insert.insert((index / width, index % width), *value);
+            }
+
+            let mut parents = (0..cells).collect::<Vec<_>>();
+            for _ in 0..rng.usize(0..cells * 2) {
+                let (l, r) = (rng.usize(0..cells), rng.usize(0..cells));
+                merge_insert.merge((l / width, l % width), (r / width, r % width));
+                let (l, r) = (find(&mut parents, l), find(&mut parents, r));
+                parents[l] = r;
+            }
+
+            let mut expected = vec![(0, 0); cells];
+            for (index, value) in values.iter().enumerate() {
+                let root = find(&mut parents, index);
+                expected[root].0 += value.0;
+                expected[root].1 += value.1;
+            }
+            let mut expected = (0..cells)
+                .filter(|index| find(&mut parents, *index) == *index)
+                .map(|index| expected[index])
+                .collect::<Vec<_>>();
+            expected.sort();
+
+            let mut sums = merge_insert
+                .values()
+                .filter_map(|node| match node {
+                    MergeInsertNode::Root(value) => Some(value.get()),
+                    MergeInsertNode::Indirection(..) => None,
+                })
+                .collect::<Vec<_>>();
+            sums.sort();
+
+            assert_eq!(sums, expected);
+        }
+    }
+}
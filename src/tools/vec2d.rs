@@ -40,3 +40,29 @@ impl<T> IndexMut<Coord> for Vec2d<'_, T> {
         &mut self.data[index.row * self.width + index.column]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::Coord;
+
+    use super::Vec2d;
+
+    #[test]
+    fn indexes_rows_of_the_backing_slice() {
+        let mut rng = fastrand::Rng::with_seed(5);
+
+        for _ in 0..200 {
+            let (height, width) = (rng.usize(1..20), rng.usize(1..20));
+            let mut data = (0..height * width).collect::<Vec<_>>();
+            let mut vec2d = Vec2d::new(data.as_mut_slice(), width, height);
+            assert_eq!((vec2d.width(), vec2d.height()), (width, height));
+
+            let coord = Coord::new(rng.usize(0..height), rng.usize(0..width));
+            assert_eq!(vec2d[coord], coord.row * width + coord.column);
+
+            vec2d[coord] = usize::MAX;
+            assert_eq!(data[coord.row * width + coord.column], usize::MAX);
+            assert_eq!(data.iter().filter(|cell| **cell == usize::MAX).count(), 1);
+        }
+    }
+}